@@ -387,3 +387,124 @@ impl std::fmt::Display for Instruction {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use solx_yul::yul::parser::statement::expression::function_call::name::Name as YulName;
+
+    use super::Instruction;
+
+    /// The EVM opcodes up to Cancun with their Yul builtins and stack input and output sizes.
+    const OPCODES: [(&str, &str, usize, usize); 83] = [
+        ("STOP", "stop", 0, 0),
+        ("ADD", "add", 2, 1),
+        ("MUL", "mul", 2, 1),
+        ("SUB", "sub", 2, 1),
+        ("DIV", "div", 2, 1),
+        ("SDIV", "sdiv", 2, 1),
+        ("MOD", "mod", 2, 1),
+        ("SMOD", "smod", 2, 1),
+        ("ADDMOD", "addmod", 3, 1),
+        ("MULMOD", "mulmod", 3, 1),
+        ("EXP", "exp", 2, 1),
+        ("SIGNEXTEND", "signextend", 2, 1),
+        ("LT", "lt", 2, 1),
+        ("GT", "gt", 2, 1),
+        ("SLT", "slt", 2, 1),
+        ("SGT", "sgt", 2, 1),
+        ("EQ", "eq", 2, 1),
+        ("ISZERO", "iszero", 1, 1),
+        ("AND", "and", 2, 1),
+        ("OR", "or", 2, 1),
+        ("XOR", "xor", 2, 1),
+        ("NOT", "not", 1, 1),
+        ("BYTE", "byte", 2, 1),
+        ("SHL", "shl", 2, 1),
+        ("SHR", "shr", 2, 1),
+        ("SAR", "sar", 2, 1),
+        ("SHA3", "keccak256", 2, 1),
+        ("KECCAK256", "keccak256", 2, 1),
+        ("ADDRESS", "address", 0, 1),
+        ("BALANCE", "balance", 1, 1),
+        ("ORIGIN", "origin", 0, 1),
+        ("CALLER", "caller", 0, 1),
+        ("CALLVALUE", "callvalue", 0, 1),
+        ("CALLDATALOAD", "calldataload", 1, 1),
+        ("CALLDATASIZE", "calldatasize", 0, 1),
+        ("CALLDATACOPY", "calldatacopy", 3, 0),
+        ("CODESIZE", "codesize", 0, 1),
+        ("CODECOPY", "codecopy", 3, 0),
+        ("GASPRICE", "gasprice", 0, 1),
+        ("EXTCODESIZE", "extcodesize", 1, 1),
+        ("EXTCODECOPY", "extcodecopy", 4, 0),
+        ("RETURNDATASIZE", "returndatasize", 0, 1),
+        ("RETURNDATACOPY", "returndatacopy", 3, 0),
+        ("EXTCODEHASH", "extcodehash", 1, 1),
+        ("BLOCKHASH", "blockhash", 1, 1),
+        ("COINBASE", "coinbase", 0, 1),
+        ("TIMESTAMP", "timestamp", 0, 1),
+        ("NUMBER", "number", 0, 1),
+        ("DIFFICULTY", "difficulty", 0, 1),
+        ("PREVRANDAO", "prevrandao", 0, 1),
+        ("GASLIMIT", "gaslimit", 0, 1),
+        ("CHAINID", "chainid", 0, 1),
+        ("SELFBALANCE", "selfbalance", 0, 1),
+        ("BASEFEE", "basefee", 0, 1),
+        ("BLOBHASH", "blobhash", 1, 1),
+        ("BLOBBASEFEE", "blobbasefee", 0, 1),
+        ("POP", "pop", 1, 0),
+        ("MLOAD", "mload", 1, 1),
+        ("MSTORE", "mstore", 2, 0),
+        ("MSTORE8", "mstore8", 2, 0),
+        ("SLOAD", "sload", 1, 1),
+        ("SSTORE", "sstore", 2, 0),
+        ("PC", "pc", 0, 1),
+        ("MSIZE", "msize", 0, 1),
+        ("GAS", "gas", 0, 1),
+        ("TLOAD", "tload", 1, 1),
+        ("TSTORE", "tstore", 2, 0),
+        ("MCOPY", "mcopy", 3, 0),
+        ("LOG0", "log0", 2, 0),
+        ("LOG1", "log1", 3, 0),
+        ("LOG2", "log2", 4, 0),
+        ("LOG3", "log3", 5, 0),
+        ("LOG4", "log4", 6, 0),
+        ("CREATE", "create", 3, 1),
+        ("CALL", "call", 7, 1),
+        ("CALLCODE", "callcode", 7, 1),
+        ("RETURN", "return", 2, 0),
+        ("DELEGATECALL", "delegatecall", 6, 1),
+        ("CREATE2", "create2", 4, 1),
+        ("STATICCALL", "staticcall", 6, 1),
+        ("REVERT", "revert", 2, 0),
+        ("INVALID", "invalid", 0, 0),
+        ("SELFDESTRUCT", "selfdestruct", 1, 0),
+    ];
+
+    #[test]
+    fn yul_builtin_parity() {
+        let version = semver::Version::new(0, 8, 30);
+        for (name, builtin, input_size, output_size) in OPCODES.into_iter() {
+            let instruction: Instruction = serde_json::from_value(serde_json::json!({
+                "name": name,
+                "begin": 0,
+                "end": 0,
+            }))
+            .unwrap_or_else(|error| panic!("Instruction `{name}` is unknown: {error}"));
+            assert_eq!(
+                instruction.input_size(&version),
+                input_size,
+                "Instruction `{name}` input size mismatch"
+            );
+            assert_eq!(
+                instruction.output_size(),
+                output_size,
+                "Instruction `{name}` output size mismatch"
+            );
+            assert!(
+                !matches!(YulName::from(builtin), YulName::UserDefined(_)),
+                "Instruction `{name}` has no Yul builtin `{builtin}`"
+            );
+        }
+    }
+}
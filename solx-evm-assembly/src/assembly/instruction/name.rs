@@ -380,6 +380,15 @@ pub enum Name {
     },
 }
 
+impl Name {
+    ///
+    /// Returns the EVM version that introduced the instruction, if it is not available in all of them.
+    ///
+    pub fn evm_version(&self) -> Option<era_compiler_common::EVMVersion> {
+        solx_yul::evm_version::required(self.to_string().as_str())
+    }
+}
+
 impl std::fmt::Display for Name {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -15,7 +15,10 @@ use rayon::iter::ParallelIterator;
 use era_compiler_llvm_context::IContext;
 
 use crate::ethereal_ir::entry_link::EntryLink;
+use crate::ethereal_ir::limits::Limits as EtherealIRLimits;
+use crate::ethereal_ir::limits::Violation as EtherealIRLimitViolation;
 use crate::ethereal_ir::EtherealIR;
+use crate::extra_metadata::recursive_function::RecursiveFunction as ExtraMetadataRecursiveFunction;
 use crate::extra_metadata::ExtraMetadata;
 
 use self::data::Data;
@@ -44,8 +47,23 @@ pub struct Assembly {
     #[serde(default, skip_serializing_if = "HashSet::is_empty")]
     pub factory_dependencies: HashSet<String>,
     /// The EVM legacy assembly extra metadata.
-    #[serde(skip_serializing_if = "Option::is_none")]
+    ///
+    /// It can be passed as `extraMetadata` along with EVM assembly input.
+    #[serde(alias = "extraMetadata", skip_serializing_if = "Option::is_none")]
     pub extra_metadata: Option<ExtraMetadata>,
+    /// The Ethereal IR traversal limits.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ethir_limits: Option<EtherealIRLimits>,
+    /// Whether to recover the internal functions from the `solc` jump annotations.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub recover_internal_functions: bool,
+    /// The function names resolved from the Solidity AST, keyed by the function entry tags.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub function_names: BTreeMap<usize, String>,
+
+    /// The Ethereal IR, built once and reused by the text output, function debug data, and LLVM IR.
+    #[serde(skip)]
+    pub ethereal_ir: Option<EtherealIR>,
 }
 
 impl Assembly {
@@ -108,6 +126,130 @@ impl Assembly {
         }
     }
 
+    ///
+    /// Returns the code segment of the assembly.
+    ///
+    /// Only deploy code assemblies contain runtime code.
+    ///
+    pub fn code_segment(&self) -> era_compiler_common::CodeSegment {
+        if self.runtime_code().is_ok() {
+            era_compiler_common::CodeSegment::Deploy
+        } else {
+            era_compiler_common::CodeSegment::Runtime
+        }
+    }
+
+    ///
+    /// Returns the Ethereal IR of the assembly, building it on the first call.
+    ///
+    pub fn ethereal_ir(&mut self, solc_version: semver::Version) -> anyhow::Result<&EtherealIR> {
+        if self.ethereal_ir.is_none() {
+            self.ethereal_ir = Some(self.build_ethereal_ir(solc_version)?);
+        }
+        Ok(self.ethereal_ir.as_ref().expect("Always exists"))
+    }
+
+    ///
+    /// Returns the internal functions recovered from the `solc` jump annotations.
+    ///
+    /// The recovered functions are not in the `solc` extra metadata, so they must be added to it
+    /// to be reported in the function debug data.
+    ///
+    pub fn internal_functions(
+        &mut self,
+        solc_version: semver::Version,
+    ) -> anyhow::Result<Vec<ExtraMetadataRecursiveFunction>> {
+        Ok(self.ethereal_ir(solc_version)?.internal_functions.clone())
+    }
+
+    ///
+    /// Builds the Ethereal IR of the assembly.
+    ///
+    /// The Ethereal IR of deploy code also contains the runtime code blocks.
+    /// The violations of the Ethereal IR limits are reported with the contract path.
+    ///
+    fn build_ethereal_ir(&self, solc_version: semver::Version) -> anyhow::Result<EtherealIR> {
+        let code_segment = self.code_segment();
+        let instructions = self
+            .code
+            .as_deref()
+            .ok_or_else(|| anyhow::anyhow!("{code_segment} code instructions not found"))?;
+        let mut blocks = EtherealIR::get_blocks(solc_version.clone(), code_segment, instructions)?;
+        if let era_compiler_common::CodeSegment::Deploy = code_segment {
+            let runtime_code_instructions = self
+                .runtime_code()?
+                .code
+                .as_deref()
+                .ok_or_else(|| anyhow::anyhow!("Runtime code instructions not found"))?;
+            blocks.extend(EtherealIR::get_blocks(
+                solc_version.clone(),
+                era_compiler_common::CodeSegment::Runtime,
+                runtime_code_instructions,
+            )?);
+        }
+
+        let mut function_names = self.function_block_names(code_segment);
+        if let era_compiler_common::CodeSegment::Deploy = code_segment {
+            function_names.extend(
+                self.runtime_code()?
+                    .function_block_names(era_compiler_common::CodeSegment::Runtime),
+            );
+        }
+
+        EtherealIR::new(
+            solc_version,
+            self.extra_metadata.clone().unwrap_or_default(),
+            self.recover_internal_functions,
+            &function_names,
+            Some(code_segment),
+            blocks,
+            &self.ethir_limits.unwrap_or_default(),
+        )
+        .map_err(|error| {
+            if error.is::<EtherealIRLimitViolation>() {
+                anyhow::anyhow!(
+                    "Contract `{}`: {error}\nThe limits can be raised, or the contract can be recompiled via Yul IR with the pipeline fallback.",
+                    self.full_path.as_deref().unwrap_or_default(),
+                )
+            } else {
+                error
+            }
+        })
+    }
+
+    ///
+    /// Resolves the function names from the source locations of their definitions.
+    ///
+    /// `solc` assigns the location of a function definition to the function entry tag, so the
+    /// `function_definitions` are keyed by the source index, begin, and end of the definition.
+    ///
+    pub fn resolve_function_names(
+        &mut self,
+        function_definitions: &BTreeMap<(isize, isize, isize), String>,
+    ) {
+        if let era_compiler_common::CodeSegment::Deploy = self.code_segment() {
+            if let Ok(runtime_code) = self.runtime_code_mut() {
+                runtime_code.resolve_function_names(function_definitions);
+            }
+        }
+
+        self.function_names = self
+            .code
+            .iter()
+            .flatten()
+            .filter(|instruction| matches!(instruction.name, InstructionName::Tag))
+            .filter_map(|instruction| {
+                let tag = instruction.value.as_deref()?.parse::<usize>().ok()?;
+                let name = function_definitions.get(&(
+                    instruction.source?,
+                    instruction.begin,
+                    instruction.end,
+                ))?;
+                Some((tag, name.to_owned()))
+            })
+            .collect();
+    }
+
     ///
     /// Get the list of unlinked deployable libraries.
     ///
@@ -129,6 +271,27 @@ impl Assembly {
         unlinked_libraries
     }
 
+    ///
+    /// Returns the resolved function names keyed by the blocks of the `code_segment`.
+    ///
+    fn function_block_names(
+        &self,
+        code_segment: era_compiler_common::CodeSegment,
+    ) -> BTreeMap<era_compiler_llvm_context::BlockKey, String> {
+        self.function_names
+            .iter()
+            .map(|(tag, name)| {
+                (
+                    era_compiler_llvm_context::BlockKey::new(
+                        code_segment,
+                        num::BigUint::from(*tag),
+                    ),
+                    name.to_owned(),
+                )
+            })
+            .collect()
+    }
+
     ///
     /// Get the list of EVM dependencies.
     ///
@@ -358,64 +521,26 @@ impl era_compiler_llvm_context::EVMWriteLLVM for Assembly {
         Ok(())
     }
 
-    fn into_llvm(self, context: &mut era_compiler_llvm_context::EVMContext) -> anyhow::Result<()> {
-        let full_path = self.full_path().to_owned();
-
-        let (code_segment, blocks) = if let Ok(runtime_code) = self.runtime_code() {
-            if let Some(debug_config) = context.debug_config() {
-                debug_config.dump_evmla(full_path.as_str(), self.to_string().as_str())?;
-            }
-
-            let deploy_code_blocks = EtherealIR::get_blocks(
-                context.evmla().expect("Always exists").version.to_owned(),
-                era_compiler_common::CodeSegment::Deploy,
-                self.code
-                    .as_deref()
-                    .ok_or_else(|| anyhow::anyhow!("Deploy code instructions not found"))?,
-            )?;
-
-            let runtime_code_instructions = runtime_code
-                .code
-                .as_ref()
-                .ok_or_else(|| anyhow::anyhow!("Runtime code instructions not found"))?;
-            let runtime_code_blocks = EtherealIR::get_blocks(
-                context.evmla().expect("Always exists").version.to_owned(),
-                era_compiler_common::CodeSegment::Runtime,
-                runtime_code_instructions.as_slice(),
-            )?;
+    fn into_llvm(
+        mut self,
+        context: &mut era_compiler_llvm_context::EVMContext,
+    ) -> anyhow::Result<()> {
+        let code_segment = self.code_segment();
+        let mut path = self.full_path().to_owned();
+        if let era_compiler_common::CodeSegment::Runtime = code_segment {
+            path.push_str(format!(".{}", code_segment).as_str());
+        }
 
-            let mut blocks = deploy_code_blocks;
-            blocks.extend(runtime_code_blocks);
-            (era_compiler_common::CodeSegment::Deploy, blocks)
-        } else {
-            if let Some(debug_config) = context.debug_config() {
-                debug_config.dump_evmla(
-                    format!("{full_path}.{}", era_compiler_common::CodeSegment::Runtime).as_str(),
-                    self.to_string().as_str(),
-                )?;
+        if let Some(debug_config) = context.debug_config() {
+            debug_config.dump_evmla(path.as_str(), self.to_string().as_str())?;
+        }
+        let mut ethereal_ir = match self.ethereal_ir.take() {
+            Some(ethereal_ir) => ethereal_ir,
+            None => {
+                self.build_ethereal_ir(context.evmla().expect("Always exists").version.to_owned())?
             }
-
-            let blocks = EtherealIR::get_blocks(
-                context.evmla().expect("Always exists").version.to_owned(),
-                era_compiler_common::CodeSegment::Runtime,
-                self.code
-                    .as_deref()
-                    .ok_or_else(|| anyhow::anyhow!("Deploy code instructions not found"))?,
-            )?;
-            (era_compiler_common::CodeSegment::Runtime, blocks)
         };
-
-        let mut ethereal_ir = EtherealIR::new(
-            context.evmla().expect("Always exists").version.to_owned(),
-            self.extra_metadata.unwrap_or_default(),
-            Some(code_segment),
-            blocks,
-        )?;
         if let Some(debug_config) = context.debug_config() {
-            let mut path = full_path.to_owned();
-            if let era_compiler_common::CodeSegment::Runtime = code_segment {
-                path.push_str(format!(".{}", code_segment).as_str());
-            }
             debug_config.dump_ethir(path.as_str(), ethereal_ir.to_string().as_str())?;
         }
         ethereal_ir.declare(context)?;
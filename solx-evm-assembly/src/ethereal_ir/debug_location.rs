@@ -0,0 +1,65 @@
+//!
+//! The Ethereal IR debug location.
+//!
+
+///
+/// The Ethereal IR debug location.
+///
+/// Identifies the EVM legacy assembly instruction an LLVM instruction has been generated from.
+/// It is attached to LLVM IR as a debug location, where the line is the 1-based instruction
+/// index in the code segment, and the column identifies the code segment.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DebugLocation {
+    /// The code segment of the instruction.
+    pub code_segment: era_compiler_common::CodeSegment,
+    /// The instruction index in the code segment.
+    pub instruction_index: usize,
+}
+
+impl DebugLocation {
+    /// The debug location column of the deploy code instructions.
+    pub const COLUMN_DEPLOY_CODE: u32 = 1;
+    /// The debug location column of the runtime code instructions.
+    pub const COLUMN_RUNTIME_CODE: u32 = 2;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(code_segment: era_compiler_common::CodeSegment, instruction_index: usize) -> Self {
+        Self {
+            code_segment,
+            instruction_index,
+        }
+    }
+
+    ///
+    /// Decodes the debug location from the LLVM line and column.
+    ///
+    pub fn try_from_line_column(line: u32, column: u32) -> Option<Self> {
+        let instruction_index = (line as usize).checked_sub(1)?;
+        let code_segment = match column {
+            Self::COLUMN_DEPLOY_CODE => era_compiler_common::CodeSegment::Deploy,
+            Self::COLUMN_RUNTIME_CODE => era_compiler_common::CodeSegment::Runtime,
+            _ => return None,
+        };
+        Some(Self::new(code_segment, instruction_index))
+    }
+
+    ///
+    /// Returns the LLVM debug location line.
+    ///
+    pub fn line(&self) -> u32 {
+        (self.instruction_index + 1) as u32
+    }
+
+    ///
+    /// Returns the LLVM debug location column.
+    ///
+    pub fn column(&self) -> u32 {
+        match self.code_segment {
+            era_compiler_common::CodeSegment::Deploy => Self::COLUMN_DEPLOY_CODE,
+            era_compiler_common::CodeSegment::Runtime => Self::COLUMN_RUNTIME_CODE,
+        }
+    }
+}
@@ -11,6 +11,7 @@ use era_compiler_llvm_context::IEVMLAFunction;
 
 use crate::assembly::instruction::name::Name as InstructionName;
 use crate::assembly::instruction::Instruction;
+use crate::ethereal_ir::debug_location::DebugLocation;
 
 use self::stack::element::Element as StackElement;
 use self::stack::Stack;
@@ -22,6 +23,8 @@ use self::stack::Stack;
 pub struct Element {
     /// The instruction.
     pub instruction: Instruction,
+    /// The debug location of the original instruction.
+    pub debug_location: DebugLocation,
     /// The stack data.
     pub stack: Stack,
     /// The stack input.
@@ -34,12 +37,17 @@ impl Element {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(solc_version: semver::Version, instruction: Instruction) -> Self {
+    pub fn new(
+        solc_version: semver::Version,
+        instruction: Instruction,
+        debug_location: DebugLocation,
+    ) -> Self {
         let input_size = instruction.input_size(&solc_version);
         let output_size = instruction.output_size();
 
         Self {
             instruction,
+            debug_location,
             stack: Stack::new(),
             stack_input: Stack::with_capacity(input_size),
             stack_output: Stack::with_capacity(output_size),
@@ -78,6 +86,12 @@ impl era_compiler_llvm_context::EVMWriteLLVM for Element {
         mut self,
         context: &mut era_compiler_llvm_context::EVMContext,
     ) -> anyhow::Result<()> {
+        context.set_debug_location(
+            self.debug_location.line(),
+            self.debug_location.column(),
+            None,
+        )?;
+
         let mut original = self.instruction.value.clone();
 
         let result = match self.instruction.name.clone() {
@@ -1065,8 +1079,10 @@ impl era_compiler_llvm_context::EVMWriteLLVM for Element {
                     .map(Some)
             }
             InstructionName::BLOBHASH => {
-                let _arguments = self.pop_arguments_llvm(context)?;
-                anyhow::bail!("The `BLOBHASH` instruction is not supported");
+                let arguments = self.pop_arguments_llvm(context)?;
+                let index = arguments[0].into_int_value();
+
+                era_compiler_llvm_context::evm_contract_context::blob_hash(context, index).map(Some)
             }
             InstructionName::DIFFICULTY | InstructionName::PREVRANDAO => {
                 era_compiler_llvm_context::evm_contract_context::difficulty(context).map(Some)
@@ -1078,7 +1094,7 @@ impl era_compiler_llvm_context::EVMWriteLLVM for Element {
                 era_compiler_llvm_context::evm_contract_context::basefee(context).map(Some)
             }
             InstructionName::BLOBBASEFEE => {
-                anyhow::bail!("The `BLOBBASEFEE` instruction is not supported");
+                era_compiler_llvm_context::evm_contract_context::blob_base_fee(context).map(Some)
             }
             InstructionName::MSIZE => {
                 era_compiler_llvm_context::evm_contract_context::msize(context).map(Some)
@@ -10,6 +10,7 @@ use num::Zero;
 
 use crate::assembly::instruction::name::Name as InstructionName;
 use crate::assembly::instruction::Instruction;
+use crate::ethereal_ir::debug_location::DebugLocation;
 
 use self::element::stack::Stack as ElementStack;
 use self::element::Element;
@@ -44,10 +45,13 @@ impl Block {
     ///
     /// Assembles a block from the sequence of instructions.
     ///
+    /// `offset` is the index of the first instruction of `slice` in the code segment.
+    ///
     pub fn try_from_instructions(
         solc_version: semver::Version,
         code_segment: era_compiler_common::CodeSegment,
         slice: &[Instruction],
+        offset: usize,
     ) -> anyhow::Result<(Self, usize)> {
         let mut cursor = 0;
 
@@ -78,7 +82,11 @@ impl Block {
         let mut dead_code = false;
         while cursor < slice.len() {
             if !dead_code {
-                let element: Element = Element::new(solc_version.clone(), slice[cursor].to_owned());
+                let element: Element = Element::new(
+                    solc_version.clone(),
+                    slice[cursor].to_owned(),
+                    DebugLocation::new(code_segment, offset + cursor),
+                );
                 block.elements.push(element);
             }
 
@@ -130,26 +138,33 @@ impl era_compiler_llvm_context::EVMWriteLLVM for Block {
 
 impl std::fmt::Display for Block {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        writeln!(
+        write!(
             f,
-            "block_{}/{}: {}",
+            "    block {}/{} 0x{}",
             self.key,
             self.instance.unwrap_or_default(),
-            if self.predecessors.is_empty() {
-                "".to_owned()
-            } else {
-                format!(
-                    "(predecessors: {})",
-                    self.predecessors
-                        .iter()
-                        .map(|(key, instance)| format!("{}/{}", key, instance))
-                        .collect::<Vec<String>>()
-                        .join(", ")
-                )
-            },
+            self.initial_stack
+                .hash()
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect::<String>(),
         )?;
+        if !self.predecessors.is_empty() {
+            let mut predecessors = self.predecessors.iter().collect::<Vec<_>>();
+            predecessors.sort();
+            write!(
+                f,
+                " <- {}",
+                predecessors
+                    .into_iter()
+                    .map(|(key, instance)| format!("{key}/{instance}"))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            )?;
+        }
+        writeln!(f)?;
         for element in self.elements.iter() {
-            writeln!(f, "    {element}")?;
+            writeln!(f, "        {}", element.to_string().trim_end())?;
         }
         Ok(())
     }
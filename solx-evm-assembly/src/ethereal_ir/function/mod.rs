@@ -29,6 +29,8 @@ use crate::assembly::instruction::name::Name as InstructionName;
 use crate::assembly::instruction::Instruction;
 use crate::ethereal_ir::function::block::element::stack::element::Element;
 use crate::ethereal_ir::function::block::element::stack::Stack;
+use crate::ethereal_ir::limits::Limits;
+use crate::ethereal_ir::limits::Violation;
 use crate::ethereal_ir::EtherealIR;
 use crate::extra_metadata::recursive_function::RecursiveFunction as ExtraMetadataRecursiveFunction;
 use crate::extra_metadata::ExtraMetadata;
@@ -90,12 +92,16 @@ impl Function {
     ///
     /// Runs the function block traversal.
     ///
+    /// `elements` is the number of block elements in all functions traversed so far.
+    ///
     pub fn traverse(
         &mut self,
         blocks: &HashMap<era_compiler_llvm_context::BlockKey, Block>,
         functions: &mut BTreeMap<era_compiler_llvm_context::BlockKey, Self>,
         extra_metadata: &ExtraMetadata,
         visited_functions: &mut BTreeSet<VisitedElement>,
+        limits: &Limits,
+        elements: &mut usize,
     ) -> anyhow::Result<()> {
         let mut visited_blocks = BTreeSet::new();
 
@@ -115,6 +121,8 @@ impl Function {
                         functions,
                         extra_metadata,
                         visited_functions,
+                        limits,
+                        elements,
                         &mut visited_blocks,
                         QueueElement::new(
                             era_compiler_llvm_context::BlockKey::new(
@@ -147,6 +155,8 @@ impl Function {
                     functions,
                     extra_metadata,
                     visited_functions,
+                    limits,
+                    elements,
                     &mut visited_blocks,
                     QueueElement::new(block_key.to_owned(), None, stack),
                 )?;
@@ -167,6 +177,8 @@ impl Function {
         functions: &mut BTreeMap<era_compiler_llvm_context::BlockKey, Self>,
         extra_metadata: &ExtraMetadata,
         visited_functions: &mut BTreeSet<VisitedElement>,
+        limits: &Limits,
+        elements: &mut usize,
         visited_blocks: &mut BTreeSet<VisitedElement>,
         mut queue_element: QueueElement,
     ) -> anyhow::Result<()> {
@@ -180,6 +192,27 @@ impl Function {
             .ok_or_else(|| {
                 anyhow::anyhow!("Undeclared destination block {}", queue_element.block_key)
             })?;
+        if let Some(instances) = self.blocks.get(&queue_element.block_key) {
+            let stack_hash = queue_element.stack.hash();
+            if instances.len() >= limits.max_block_instances
+                && instances
+                    .iter()
+                    .all(|instance| instance.initial_stack.hash() != stack_hash)
+            {
+                return Err(Violation::BlockInstances {
+                    function: self.name.to_owned(),
+                    block_key: queue_element.block_key,
+                    limit: limits.max_block_instances,
+                    stack_states: instances
+                        .iter()
+                        .take(Limits::REPORTED_STACK_STATES - 1)
+                        .map(|instance| instance.initial_stack.to_string())
+                        .chain(std::iter::once(queue_element.stack.to_string()))
+                        .collect(),
+                }
+                .into());
+            }
+        }
         block.initial_stack = queue_element.stack.clone();
         let block = self.insert_block(block);
         block.stack = block.initial_stack.clone();
@@ -198,11 +231,13 @@ impl Function {
         for block_element in block.elements.iter_mut() {
             block_size += 1;
 
-            if Self::handle_instruction(
+            if let Err(error) = Self::handle_instruction(
                 blocks,
                 functions,
                 extra_metadata,
                 visited_functions,
+                limits,
+                elements,
                 block.key.code_segment,
                 block.instance.unwrap_or_default(),
                 &mut block.stack,
@@ -210,9 +245,11 @@ impl Function {
                 &version,
                 &mut queue,
                 &mut queue_element,
-            )
-            .is_err()
-            {
+            ) {
+                if error.is::<Violation>() {
+                    return Err(error);
+                }
+
                 block_element.instruction = Instruction::invalid(&block_element.instruction);
                 block_element.stack = block.stack.clone();
                 break;
@@ -220,12 +257,23 @@ impl Function {
         }
         block.elements.truncate(block_size);
 
+        *elements += block_size;
+        if *elements > limits.max_elements {
+            return Err(Violation::Elements {
+                function: self.name.to_owned(),
+                limit: limits.max_elements,
+            }
+            .into());
+        }
+
         for element in queue.into_iter() {
             self.consume_block(
                 blocks,
                 functions,
                 extra_metadata,
                 visited_functions,
+                limits,
+                elements,
                 visited_blocks,
                 element,
             )?;
@@ -239,12 +287,15 @@ impl Function {
     ///
     /// The blocks with an invalid stack state are considered being partially unreachable, and
     /// the invalid part is truncated after terminating with an `INVALID` instruction.
+    /// The limit violations of the recursive function traversals stop the whole traversal.
     ///
     fn handle_instruction(
         blocks: &HashMap<era_compiler_llvm_context::BlockKey, Block>,
         functions: &mut BTreeMap<era_compiler_llvm_context::BlockKey, Self>,
         extra_metadata: &ExtraMetadata,
         visited_functions: &mut BTreeSet<VisitedElement>,
+        limits: &Limits,
+        elements: &mut usize,
         code_segment: era_compiler_common::CodeSegment,
         instance: usize,
         block_stack: &mut Stack,
@@ -307,6 +358,8 @@ impl Function {
                         functions,
                         extra_metadata,
                         visited_functions,
+                        limits,
+                        elements,
                         block_key,
                         block_stack,
                         block_element,
@@ -1036,6 +1089,8 @@ impl Function {
         functions: &mut BTreeMap<era_compiler_llvm_context::BlockKey, Self>,
         extra_metadata: &ExtraMetadata,
         visited_functions: &mut BTreeSet<VisitedElement>,
+        limits: &Limits,
+        elements: &mut usize,
         block_key: era_compiler_llvm_context::BlockKey,
         block_stack: &mut Stack,
         block_element: &mut BlockElement,
@@ -1074,7 +1129,14 @@ impl Function {
                 ),
             );
             visited_functions.insert(visited_element);
-            function.traverse(blocks, functions, extra_metadata, visited_functions)?;
+            function.traverse(
+                blocks,
+                functions,
+                extra_metadata,
+                visited_functions,
+                limits,
+                elements,
+            )?;
             functions.insert(block_key.clone(), function);
         }
 
@@ -1327,7 +1389,7 @@ impl std::fmt::Display for Function {
                 ..
             } => writeln!(
                 f,
-                "function {}({}) -> {} {{",
+                "recursive function {}({}) -> {} {{",
                 self.name, input_size, output_size
             ),
         }?;
@@ -0,0 +1,125 @@
+//!
+//! The Ethereal IR traversal limits.
+//!
+
+///
+/// The Ethereal IR traversal limits.
+///
+/// Blocks are duplicated for each distinct initial stack state, so without these limits the
+/// traversal of pathological code may take too much time and memory.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Limits {
+    /// The maximum number of instances of a block in a function.
+    #[serde(default = "Limits::default_max_block_instances")]
+    pub max_block_instances: usize,
+    /// The maximum number of block elements in all functions.
+    #[serde(default = "Limits::default_max_elements")]
+    pub max_elements: usize,
+}
+
+impl Limits {
+    /// The default maximum number of instances of a block in a function.
+    pub const DEFAULT_MAX_BLOCK_INSTANCES: usize = 2048;
+
+    /// The default maximum number of block elements in all functions.
+    pub const DEFAULT_MAX_ELEMENTS: usize = 2_000_000;
+
+    /// The maximum number of initial stack states reported on a violation.
+    pub const REPORTED_STACK_STATES: usize = 4;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(max_block_instances: usize, max_elements: usize) -> Self {
+        Self {
+            max_block_instances,
+            max_elements,
+        }
+    }
+
+    ///
+    /// The default maximum number of instances of a block in a function.
+    ///
+    fn default_max_block_instances() -> usize {
+        Self::DEFAULT_MAX_BLOCK_INSTANCES
+    }
+
+    ///
+    /// The default maximum number of block elements in all functions.
+    ///
+    fn default_max_elements() -> usize {
+        Self::DEFAULT_MAX_ELEMENTS
+    }
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self::new(
+            Self::DEFAULT_MAX_BLOCK_INSTANCES,
+            Self::DEFAULT_MAX_ELEMENTS,
+        )
+    }
+}
+
+///
+/// The Ethereal IR traversal limit violation.
+///
+/// It is a distinct error type, as the other traversal errors only make the affected blocks
+/// unreachable, whereas the violations must stop the traversal.
+///
+#[derive(Debug, Clone)]
+pub enum Violation {
+    /// A block has too many instances.
+    BlockInstances {
+        /// The function name.
+        function: String,
+        /// The block key.
+        block_key: era_compiler_llvm_context::BlockKey,
+        /// The limit.
+        limit: usize,
+        /// Some of the initial stack states of the block instances.
+        stack_states: Vec<String>,
+    },
+    /// There are too many block elements.
+    Elements {
+        /// The function name.
+        function: String,
+        /// The limit.
+        limit: usize,
+    },
+}
+
+impl Violation {
+    /// The pattern of violation messages, matched against the lowercase letters of errors.
+    pub const PATTERN: &'static str = "etherealirlimit";
+}
+
+impl std::error::Error for Violation {}
+
+impl std::fmt::Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BlockInstances {
+                function,
+                block_key,
+                limit,
+                stack_states,
+            } => {
+                write!(
+                    f,
+                    "The Ethereal IR limit of {limit} block instances is exceeded by block `{block_key}` in function `{function}`. Some of its initial stack states:"
+                )?;
+                for stack_state in stack_states.iter() {
+                    write!(f, "\n    {stack_state}")?;
+                }
+                Ok(())
+            }
+            Self::Elements { function, limit } => write!(
+                f,
+                "The Ethereal IR limit of {limit} elements is exceeded in function `{function}`."
+            ),
+        }
+    }
+}
@@ -2,21 +2,31 @@
 //! The Ethereal IR of the EVM bytecode.
 //!
 
+pub mod debug_location;
 pub mod entry_link;
 pub mod function;
+pub mod limits;
+pub mod text;
 
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 use std::collections::HashMap;
 
+use num::ToPrimitive;
+
 use era_compiler_llvm_context::IContext;
 
+use crate::assembly::instruction::name::Name as InstructionName;
 use crate::assembly::instruction::Instruction;
+use crate::extra_metadata::recursive_function::RecursiveFunction as ExtraMetadataRecursiveFunction;
 use crate::extra_metadata::ExtraMetadata;
 
+use self::function::block::element::stack::element::Element as StackElement;
+use self::function::block::element::stack::Stack;
 use self::function::block::Block;
 use self::function::r#type::Type as FunctionType;
 use self::function::Function;
+use self::limits::Limits;
 
 ///
 /// Ethereal IR of EVM bytecode.
@@ -30,45 +40,77 @@ use self::function::Function;
 /// each of initial states of the stack. LLVM IR supports only static control flow, so the
 /// stack state must be known all the way throughout the program.
 ///
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct EtherealIR {
-    /// The all-inlined function.
+    /// The entry function.
     pub entry_function: Function,
-    /// The recursive functions.
+    /// The recursive and recovered internal functions.
     pub recursive_functions: BTreeMap<era_compiler_llvm_context::BlockKey, Function>,
+    /// The internal functions recovered from the `solc` jump annotations.
+    pub internal_functions: Vec<ExtraMetadataRecursiveFunction>,
 }
 
 impl EtherealIR {
     /// The default entry function name.
     pub const DEFAULT_ENTRY_FUNCTION_NAME: &'static str = "main";
 
+    /// The name of the recovered internal functions whose definitions are unknown.
+    pub const INTERNAL_FUNCTION_NAME: &'static str = "internal";
+
+    /// The `solc` annotation of jumps into internal functions.
+    pub const JUMP_INTO_FUNCTION: &'static str = "[in]";
+
+    /// The `solc` annotation of jumps out of internal functions.
+    pub const JUMP_OUT_OF_FUNCTION: &'static str = "[out]";
+
     /// The blocks hashmap initial capacity.
     pub const BLOCKS_HASHMAP_DEFAULT_CAPACITY: usize = 64;
 
     ///
     /// Assembles a sequence of functions from the sequence of instructions.
     ///
+    /// The blocks are traversed with all internal functions inlined first. Then the internal
+    /// functions are recovered from the `solc` jump annotations of the inlined code, and the blocks
+    /// are traversed again with these functions outlined like the recursive ones, so the inlining
+    /// decisions are left to LLVM. The recovered functions are named after their definitions in
+    /// `function_names`, if any. The recovery is skipped unless `recover_internal_functions` is set.
+    ///
+    /// Each traversal is stopped with a [`limits::Violation`] if it exceeds the `limits`.
+    ///
     pub fn new(
         solc_version: semver::Version,
         extra_metadata: ExtraMetadata,
+        recover_internal_functions: bool,
+        function_names: &BTreeMap<era_compiler_llvm_context::BlockKey, String>,
         code_segment: Option<era_compiler_common::CodeSegment>,
         blocks: HashMap<era_compiler_llvm_context::BlockKey, Block>,
+        limits: &Limits,
     ) -> anyhow::Result<Self> {
-        let mut entry_function =
-            Function::new(solc_version, code_segment, FunctionType::new_initial());
-        let mut recursive_functions = BTreeMap::new();
-        let mut visited_functions = BTreeSet::new();
-        entry_function.traverse(
-            &blocks,
-            &mut recursive_functions,
+        let ethereal_ir = Self::traverse(
+            solc_version.clone(),
             &extra_metadata,
-            &mut visited_functions,
+            code_segment,
+            &blocks,
+            limits,
         )?;
+        if !recover_internal_functions {
+            return Ok(ethereal_ir);
+        }
 
-        Ok(Self {
-            entry_function,
-            recursive_functions,
-        })
+        let internal_functions =
+            ethereal_ir.recover_internal_functions(&extra_metadata, function_names);
+        if internal_functions.is_empty() {
+            return Ok(ethereal_ir);
+        }
+
+        let mut extra_metadata = extra_metadata;
+        extra_metadata
+            .recursive_functions
+            .extend(internal_functions.clone());
+        let mut ethereal_ir =
+            Self::traverse(solc_version, &extra_metadata, code_segment, &blocks, limits)?;
+        ethereal_ir.internal_functions = internal_functions;
+        Ok(ethereal_ir)
     }
 
     ///
@@ -87,6 +129,7 @@ impl EtherealIR {
                 solc_version.clone(),
                 code_segment,
                 &instructions[offset..],
+                offset,
             )?;
             blocks.insert(
                 era_compiler_llvm_context::BlockKey::new(code_segment, block.key.tag.clone()),
@@ -97,6 +140,210 @@ impl EtherealIR {
 
         Ok(blocks)
     }
+
+    ///
+    /// Traverses the blocks starting from the entry function.
+    ///
+    fn traverse(
+        solc_version: semver::Version,
+        extra_metadata: &ExtraMetadata,
+        code_segment: Option<era_compiler_common::CodeSegment>,
+        blocks: &HashMap<era_compiler_llvm_context::BlockKey, Block>,
+        limits: &Limits,
+    ) -> anyhow::Result<Self> {
+        let mut entry_function =
+            Function::new(solc_version, code_segment, FunctionType::new_initial());
+        let mut recursive_functions = BTreeMap::new();
+        let mut visited_functions = BTreeSet::new();
+        let mut elements = 0;
+        entry_function.traverse(
+            blocks,
+            &mut recursive_functions,
+            extra_metadata,
+            &mut visited_functions,
+            limits,
+            &mut elements,
+        )?;
+
+        Ok(Self {
+            entry_function,
+            recursive_functions,
+            internal_functions: vec![],
+        })
+    }
+
+    ///
+    /// Recovers the internal functions from the `solc` jump annotations.
+    ///
+    /// The return address of a call is the topmost tag on the caller stack that is jumped to out
+    /// of a function. A function is only recovered if all its calls pass the same number of
+    /// arguments, none of which are tags, all its returns leave the same number of values, and
+    /// its entry block is never reached other than by jumping into it.
+    ///
+    fn recover_internal_functions(
+        &self,
+        extra_metadata: &ExtraMetadata,
+        function_names: &BTreeMap<era_compiler_llvm_context::BlockKey, String>,
+    ) -> Vec<ExtraMetadataRecursiveFunction> {
+        let functions: Vec<&Function> = std::iter::once(&self.entry_function)
+            .chain(self.recursive_functions.values())
+            .collect();
+
+        let mut calls: BTreeMap<era_compiler_llvm_context::BlockKey, Vec<&Stack>> = BTreeMap::new();
+        let mut returns: BTreeMap<era_compiler_llvm_context::BlockKey, Vec<&Stack>> =
+            BTreeMap::new();
+        for block in functions
+            .iter()
+            .flat_map(|function| function.blocks.values().flatten())
+        {
+            for element in block.elements.iter() {
+                let annotation = match element.instruction {
+                    Instruction {
+                        name: InstructionName::JUMP,
+                        value: Some(ref annotation),
+                        ..
+                    } => annotation.as_str(),
+                    _ => continue,
+                };
+                let tag = match element.stack_input.elements.first() {
+                    Some(StackElement::Tag(tag)) if tag <= &num::BigUint::from(u32::MAX) => tag,
+                    _ => continue,
+                };
+                let block_key = era_compiler_llvm_context::BlockKey::new(
+                    block.key.code_segment,
+                    tag.to_owned(),
+                );
+                match annotation {
+                    Self::JUMP_INTO_FUNCTION => {
+                        calls.entry(block_key).or_default().push(&element.stack)
+                    }
+                    Self::JUMP_OUT_OF_FUNCTION => {
+                        returns.entry(block_key).or_default().push(&element.stack)
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let mut internal_functions = Vec::new();
+        'functions: for (block_key, call_stacks) in calls.iter() {
+            if extra_metadata
+                .get(block_key.code_segment, &block_key.tag)
+                .is_some()
+                || !Self::is_only_jumped_into(functions.as_slice(), block_key)
+            {
+                continue;
+            }
+
+            let mut input_sizes = BTreeSet::new();
+            let mut output_sizes = BTreeSet::new();
+            for call_stack in call_stacks.iter() {
+                let (return_address_offset, return_stacks) =
+                    match call_stack.elements.iter().enumerate().rev().find_map(
+                        |(offset, element)| match element {
+                            StackElement::Tag(tag) => returns
+                                .get(&era_compiler_llvm_context::BlockKey::new(
+                                    block_key.code_segment,
+                                    tag.to_owned(),
+                                ))
+                                .map(|return_stacks| (offset, return_stacks)),
+                            _ => None,
+                        },
+                    ) {
+                        Some(return_address) => return_address,
+                        None => continue 'functions,
+                    };
+
+                let arguments = &call_stack.elements[return_address_offset + 1..];
+                if arguments.iter().any(|element| {
+                    matches!(
+                        element,
+                        StackElement::Tag(_) | StackElement::ReturnAddress(_)
+                    )
+                }) {
+                    continue 'functions;
+                }
+                input_sizes.insert(arguments.len());
+
+                let caller_stack_hash = Stack::new_with_elements(
+                    call_stack.elements[..return_address_offset].to_owned(),
+                )
+                .hash();
+                for return_stack in return_stacks.iter() {
+                    if return_stack.len() < return_address_offset
+                        || Stack::new_with_elements(
+                            return_stack.elements[..return_address_offset].to_owned(),
+                        )
+                        .hash()
+                            != caller_stack_hash
+                    {
+                        continue;
+                    }
+                    output_sizes.insert(return_stack.len() - return_address_offset);
+                }
+            }
+            if input_sizes.len() != 1 || output_sizes.len() != 1 {
+                continue;
+            }
+
+            let tag = match block_key.tag.to_usize() {
+                Some(tag) => tag,
+                None => continue,
+            };
+            let (creation_tag, runtime_tag) = match block_key.code_segment {
+                era_compiler_common::CodeSegment::Deploy => (Some(tag), None),
+                era_compiler_common::CodeSegment::Runtime => (None, Some(tag)),
+            };
+            internal_functions.push(ExtraMetadataRecursiveFunction {
+                name: function_names
+                    .get(block_key)
+                    .cloned()
+                    .unwrap_or_else(|| Self::INTERNAL_FUNCTION_NAME.to_owned()),
+                creation_tag,
+                runtime_tag,
+                input_size: input_sizes.into_iter().next().expect("Always exists"),
+                output_size: output_sizes.into_iter().next().expect("Always exists"),
+            });
+        }
+        internal_functions
+    }
+
+    ///
+    /// Checks whether all instances of the block are only reached by jumps into function.
+    ///
+    fn is_only_jumped_into(
+        functions: &[&Function],
+        block_key: &era_compiler_llvm_context::BlockKey,
+    ) -> bool {
+        functions.iter().all(|function| {
+            function
+                .blocks
+                .get(block_key)
+                .into_iter()
+                .flatten()
+                .all(|block| {
+                    !block.predecessors.is_empty()
+                        && block.predecessors.iter().all(|(key, instance)| {
+                            function
+                                .blocks
+                                .get(key)
+                                .and_then(|blocks| blocks.get(*instance))
+                                .and_then(|block| block.elements.last())
+                                .map(|element| {
+                                    matches!(
+                                        element.instruction,
+                                        Instruction {
+                                            name: InstructionName::JUMP,
+                                            value: Some(ref annotation),
+                                            ..
+                                        } if annotation == Self::JUMP_INTO_FUNCTION
+                                    )
+                                })
+                                .unwrap_or_default()
+                        })
+                })
+        })
+    }
 }
 
 impl era_compiler_llvm_context::EVMWriteLLVM for EtherealIR {
@@ -137,3 +384,211 @@ impl std::fmt::Display for EtherealIR {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::assembly::instruction::Instruction;
+    use crate::ethereal_ir::function::r#type::Type as FunctionType;
+    use crate::ethereal_ir::limits::Limits;
+    use crate::ethereal_ir::limits::Violation;
+    use crate::ethereal_ir::EtherealIR;
+    use crate::extra_metadata::ExtraMetadata;
+
+    fn instruction(name: &str, value: Option<&str>) -> Instruction {
+        serde_json::from_value(serde_json::json!({
+            "name": name,
+            "value": value,
+            "begin": 0,
+            "end": 0,
+        }))
+        .expect("Always valid")
+    }
+
+    fn ethereal_ir(
+        instructions: &[Instruction],
+        recover_internal_functions: bool,
+        function_names: &BTreeMap<era_compiler_llvm_context::BlockKey, String>,
+        limits: &Limits,
+    ) -> anyhow::Result<EtherealIR> {
+        let solc_version = semver::Version::new(0, 8, 30);
+        let code_segment = era_compiler_common::CodeSegment::Runtime;
+        let blocks = EtherealIR::get_blocks(solc_version.clone(), code_segment, instructions)?;
+        EtherealIR::new(
+            solc_version,
+            ExtraMetadata::default(),
+            recover_internal_functions,
+            function_names,
+            Some(code_segment),
+            blocks,
+            limits,
+        )
+    }
+
+    #[test]
+    fn internal_function_outlined() {
+        let function_names = BTreeMap::from([(
+            era_compiler_llvm_context::BlockKey::new(
+                era_compiler_common::CodeSegment::Runtime,
+                num::BigUint::from(10u32),
+            ),
+            "fun_increment_12".to_owned(),
+        )]);
+        let ethereal_ir = ethereal_ir(
+            &[
+                instruction("PUSH [tag]", Some("1")),
+                instruction("PUSH", Some("5")),
+                instruction("PUSH [tag]", Some("10")),
+                instruction("JUMP", Some("[in]")),
+                instruction("tag", Some("1")),
+                instruction("PUSH [tag]", Some("2")),
+                instruction("PUSH", Some("7")),
+                instruction("PUSH [tag]", Some("10")),
+                instruction("JUMP", Some("[in]")),
+                instruction("tag", Some("2")),
+                instruction("STOP", None),
+                instruction("tag", Some("10")),
+                instruction("PUSH", Some("1")),
+                instruction("ADD", None),
+                instruction("SWAP1", None),
+                instruction("JUMP", Some("[out]")),
+            ],
+            true,
+            &function_names,
+            &Limits::default(),
+        )
+        .expect("Always valid");
+
+        assert_eq!(ethereal_ir.internal_functions.len(), 1);
+        assert_eq!(ethereal_ir.internal_functions[0].name, "fun_increment_12");
+        assert_eq!(ethereal_ir.internal_functions[0].runtime_tag, Some(10));
+        assert_eq!(ethereal_ir.recursive_functions.len(), 1);
+        let function = ethereal_ir
+            .recursive_functions
+            .values()
+            .next()
+            .expect("Always exists");
+        assert!(function.name.starts_with("fun_increment_12"));
+        assert!(matches!(
+            function.r#type,
+            FunctionType::Recursive {
+                input_size: 1,
+                output_size: 1,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn internal_function_recovery_disabled() {
+        let ethereal_ir = ethereal_ir(
+            &[
+                instruction("PUSH [tag]", Some("1")),
+                instruction("PUSH", Some("5")),
+                instruction("PUSH [tag]", Some("10")),
+                instruction("JUMP", Some("[in]")),
+                instruction("tag", Some("1")),
+                instruction("STOP", None),
+                instruction("tag", Some("10")),
+                instruction("PUSH", Some("1")),
+                instruction("ADD", None),
+                instruction("SWAP1", None),
+                instruction("JUMP", Some("[out]")),
+            ],
+            false,
+            &BTreeMap::new(),
+            &Limits::default(),
+        )
+        .expect("Always valid");
+
+        assert!(ethereal_ir.internal_functions.is_empty());
+        assert!(ethereal_ir.recursive_functions.is_empty());
+    }
+
+    #[test]
+    fn function_pointer_argument_inlined() {
+        let ethereal_ir = ethereal_ir(
+            &[
+                instruction("PUSH [tag]", Some("1")),
+                instruction("PUSH [tag]", Some("20")),
+                instruction("PUSH [tag]", Some("10")),
+                instruction("JUMP", Some("[in]")),
+                instruction("tag", Some("1")),
+                instruction("STOP", None),
+                instruction("tag", Some("10")),
+                instruction("PUSH [tag]", Some("11")),
+                instruction("SWAP1", None),
+                instruction("JUMP", Some("[in]")),
+                instruction("tag", Some("11")),
+                instruction("JUMP", Some("[out]")),
+                instruction("tag", Some("20")),
+                instruction("JUMP", Some("[out]")),
+            ],
+            true,
+            &BTreeMap::new(),
+            &Limits::default(),
+        )
+        .expect("Always valid");
+
+        assert!(
+            ethereal_ir
+                .recursive_functions
+                .keys()
+                .all(|block_key| block_key.tag != num::BigUint::from(10u32)),
+            "The function taking a function pointer must stay inlined"
+        );
+    }
+
+    #[test]
+    fn block_instances_limit() {
+        let result = ethereal_ir(
+            &[
+                instruction("PUSH [tag]", Some("1")),
+                instruction("PUSH [tag]", Some("2")),
+                instruction("JUMP", None),
+                instruction("tag", Some("1")),
+                instruction("PUSH [tag]", Some("3")),
+                instruction("PUSH [tag]", Some("2")),
+                instruction("JUMP", None),
+                instruction("tag", Some("2")),
+                instruction("JUMP", None),
+                instruction("tag", Some("3")),
+                instruction("STOP", None),
+            ],
+            false,
+            &BTreeMap::new(),
+            &Limits::new(1, Limits::DEFAULT_MAX_ELEMENTS),
+        );
+
+        match result.map_err(|error| error.downcast::<Violation>()) {
+            Err(Ok(Violation::BlockInstances { stack_states, .. })) => {
+                assert_eq!(stack_states.len(), 2)
+            }
+            _ => panic!("The block instances limit violation is not reported"),
+        }
+    }
+
+    #[test]
+    fn elements_limit() {
+        let result = ethereal_ir(
+            &[
+                instruction("PUSH", Some("1")),
+                instruction("PUSH", Some("2")),
+                instruction("ADD", None),
+                instruction("STOP", None),
+            ],
+            false,
+            &BTreeMap::new(),
+            &Limits::new(Limits::DEFAULT_MAX_BLOCK_INSTANCES, 2),
+        );
+
+        assert!(
+            matches!(
+                result.map_err(|error| error.downcast::<Violation>()),
+                Err(Ok(Violation::Elements { .. }))
+            ),
+            "The elements limit violation is not reported"
+        );
+    }
+}
@@ -0,0 +1,76 @@
+//!
+//! The Ethereal IR text format block.
+//!
+
+///
+/// The Ethereal IR text format block.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Block {
+    /// The block key.
+    pub key: String,
+    /// The block instance, unique for each initial stack state of the block.
+    pub instance: usize,
+    /// The hexadecimal hash of the initial stack state, prefixed with `0x`.
+    pub initial_stack_hash: String,
+    /// The predecessor block keys and instances.
+    pub predecessors: Vec<(String, usize)>,
+    /// The block elements.
+    pub elements: Vec<String>,
+}
+
+impl Block {
+    /// The block header prefix.
+    pub const PREFIX: &'static str = "    block ";
+
+    /// The predecessor list separator.
+    pub const PREDECESSORS_SEPARATOR: &'static str = " <- ";
+
+    /// The element line prefix.
+    pub const ELEMENT_PREFIX: &'static str = "        ";
+
+    ///
+    /// Parses the block header line, without the prefix.
+    ///
+    pub fn try_from_header(header: &str) -> anyhow::Result<Self> {
+        let (header, predecessors) = match header.split_once(Self::PREDECESSORS_SEPARATOR) {
+            Some((header, predecessors)) => (
+                header,
+                predecessors
+                    .split(", ")
+                    .map(Self::parse_reference)
+                    .collect::<anyhow::Result<Vec<(String, usize)>>>()?,
+            ),
+            None => (header, vec![]),
+        };
+
+        let (reference, initial_stack_hash) = header
+            .split_once(' ')
+            .ok_or_else(|| anyhow::anyhow!("Block initial stack hash is missing"))?;
+        if !initial_stack_hash.starts_with("0x") {
+            anyhow::bail!("Invalid block initial stack hash `{initial_stack_hash}`");
+        }
+        let (key, instance) = Self::parse_reference(reference)?;
+
+        Ok(Self {
+            key,
+            instance,
+            initial_stack_hash: initial_stack_hash.to_owned(),
+            predecessors,
+            elements: vec![],
+        })
+    }
+
+    ///
+    /// Parses a block reference in the `<key>/<instance>` format.
+    ///
+    fn parse_reference(reference: &str) -> anyhow::Result<(String, usize)> {
+        let (key, instance) = reference
+            .rsplit_once('/')
+            .ok_or_else(|| anyhow::anyhow!("Invalid block reference `{reference}`"))?;
+        let instance = instance
+            .parse()
+            .map_err(|error| anyhow::anyhow!("Invalid block instance `{instance}`: {error}"))?;
+        Ok((key.to_owned(), instance))
+    }
+}
@@ -0,0 +1,80 @@
+//!
+//! The Ethereal IR text format function.
+//!
+
+use crate::ethereal_ir::text::block::Block;
+
+///
+/// The Ethereal IR text format function.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Function {
+    /// The function name.
+    pub name: String,
+    /// The stack input and output sizes. Only set for recursive functions.
+    pub recursive: Option<(usize, usize)>,
+    /// The function stack size.
+    pub stack_usage: usize,
+    /// The function blocks.
+    pub blocks: Vec<Block>,
+}
+
+impl Function {
+    /// The function header prefix.
+    pub const PREFIX: &'static str = "function ";
+
+    /// The recursive function header prefix.
+    pub const RECURSIVE_PREFIX: &'static str = "recursive function ";
+
+    /// The function header suffix.
+    pub const HEADER_SUFFIX: &'static str = " {";
+
+    /// The stack usage line prefix.
+    pub const STACK_USAGE_PREFIX: &'static str = "    stack_usage: ";
+
+    /// The function footer.
+    pub const FOOTER: &'static str = "}";
+
+    ///
+    /// Parses the function header line.
+    ///
+    pub fn try_from_header(header: &str) -> anyhow::Result<Self> {
+        let (signature, is_recursive) = match header.strip_prefix(Self::RECURSIVE_PREFIX) {
+            Some(signature) => (signature, true),
+            None => (
+                header
+                    .strip_prefix(Self::PREFIX)
+                    .ok_or_else(|| anyhow::anyhow!("Invalid function header `{header}`"))?,
+                false,
+            ),
+        };
+        let signature = signature
+            .strip_suffix(Self::HEADER_SUFFIX)
+            .ok_or_else(|| anyhow::anyhow!("Function body is not opened in `{header}`"))?;
+
+        let (name, recursive) = if is_recursive {
+            let (name, sizes) = signature
+                .split_once('(')
+                .ok_or_else(|| anyhow::anyhow!("Recursive function input size is missing"))?;
+            let (input_size, output_size) = sizes
+                .split_once(") -> ")
+                .ok_or_else(|| anyhow::anyhow!("Recursive function output size is missing"))?;
+            let input_size = input_size.parse().map_err(|error| {
+                anyhow::anyhow!("Invalid function input size `{input_size}`: {error}")
+            })?;
+            let output_size = output_size.parse().map_err(|error| {
+                anyhow::anyhow!("Invalid function output size `{output_size}`: {error}")
+            })?;
+            (name, Some((input_size, output_size)))
+        } else {
+            (signature, None)
+        };
+
+        Ok(Self {
+            name: name.to_owned(),
+            recursive,
+            stack_usage: 0,
+            blocks: vec![],
+        })
+    }
+}
@@ -0,0 +1,207 @@
+//!
+//! The Ethereal IR text format.
+//!
+
+pub mod block;
+pub mod function;
+
+use std::str::FromStr;
+
+use self::block::Block;
+use self::function::Function;
+
+///
+/// The Ethereal IR text format.
+///
+/// The format is produced by the `Display` implementation of the Ethereal IR, and consists of
+/// the entry function followed by the recursive functions. The internal functions recovered from
+/// the `solc` jump annotations are emitted as recursive functions as well:
+///
+/// ```text
+/// function <name> {
+///     stack_usage: <size>
+///     block <key>/<instance> 0x<initial stack hash> [<- <key>/<instance>, ...]
+///         <instruction> [ <stack> ] [- <input>] [+ <output>]
+/// }
+///
+/// recursive function <name>(<input size>) -> <output size> {
+///     ...
+/// }
+/// ```
+///
+/// Each block is emitted once per instance, that is, per distinct initial stack state.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Text {
+    /// The functions, starting with the entry function.
+    pub functions: Vec<Function>,
+}
+
+impl FromStr for Text {
+    type Err = anyhow::Error;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut functions = Vec::new();
+        let mut function: Option<Function> = None;
+
+        for (index, line) in input.lines().enumerate() {
+            let line_number = index + 1;
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            if line == Function::FOOTER {
+                functions.push(
+                    function.take().ok_or_else(|| {
+                        anyhow::anyhow!("Line {line_number}: no function to close")
+                    })?,
+                );
+                continue;
+            }
+
+            let Some(current) = function.as_mut() else {
+                function = Some(
+                    Function::try_from_header(line)
+                        .map_err(|error| anyhow::anyhow!("Line {line_number}: {error}"))?,
+                );
+                continue;
+            };
+            if let Some(stack_usage) = line.strip_prefix(Function::STACK_USAGE_PREFIX) {
+                current.stack_usage = stack_usage.parse().map_err(|error| {
+                    anyhow::anyhow!(
+                        "Line {line_number}: invalid stack usage `{stack_usage}`: {error}"
+                    )
+                })?;
+            } else if let Some(header) = line.strip_prefix(Block::PREFIX) {
+                current.blocks.push(
+                    Block::try_from_header(header)
+                        .map_err(|error| anyhow::anyhow!("Line {line_number}: {error}"))?,
+                );
+            } else if let Some(element) = line.strip_prefix(Block::ELEMENT_PREFIX) {
+                current
+                    .blocks
+                    .last_mut()
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("Line {line_number}: element outside of a block")
+                    })?
+                    .elements
+                    .push(element.to_owned());
+            } else {
+                anyhow::bail!("Line {line_number}: unexpected `{line}`");
+            }
+        }
+
+        if let Some(function) = function {
+            anyhow::bail!("Function `{}` is not closed", function.name);
+        }
+
+        Ok(Self { functions })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::assembly::instruction::Instruction;
+    use crate::ethereal_ir::limits::Limits;
+    use crate::ethereal_ir::EtherealIR;
+    use crate::extra_metadata::ExtraMetadata;
+
+    use super::Text;
+
+    fn instruction(name: &str, value: Option<&str>) -> Instruction {
+        serde_json::from_value(serde_json::json!({
+            "name": name,
+            "value": value,
+            "begin": 0,
+            "end": 0,
+        }))
+        .expect("Always valid")
+    }
+
+    #[test]
+    fn round_trip() {
+        let solc_version = semver::Version::new(0, 8, 30);
+        let code_segment = era_compiler_common::CodeSegment::Runtime;
+        let instructions = vec![
+            instruction("PUSH [tag]", Some("1")),
+            instruction("PUSH [tag]", Some("2")),
+            instruction("JUMP", None),
+            instruction("tag", Some("1")),
+            instruction("PUSH [tag]", Some("3")),
+            instruction("PUSH [tag]", Some("2")),
+            instruction("JUMP", None),
+            instruction("tag", Some("2")),
+            instruction("JUMP", None),
+            instruction("tag", Some("3")),
+            instruction("STOP", None),
+        ];
+        let blocks =
+            EtherealIR::get_blocks(solc_version.clone(), code_segment, instructions.as_slice())
+                .expect("Always valid");
+        let ethereal_ir = EtherealIR::new(
+            solc_version,
+            ExtraMetadata::default(),
+            false,
+            &BTreeMap::new(),
+            Some(code_segment),
+            blocks,
+            &Limits::default(),
+        )
+        .expect("Always valid");
+
+        let text: Text = ethereal_ir.to_string().parse().expect("Always valid");
+        assert_eq!(text.functions.len(), 1);
+        let function = &text.functions[0];
+        assert_eq!(function.name, EtherealIR::DEFAULT_ENTRY_FUNCTION_NAME);
+        assert_eq!(function.recursive, None);
+        assert_eq!(function.stack_usage, ethereal_ir.entry_function.stack_size);
+
+        let expected = ethereal_ir
+            .entry_function
+            .blocks
+            .values()
+            .flatten()
+            .map(|block| {
+                (
+                    block.key.to_string(),
+                    block.instance.unwrap_or_default(),
+                    format!(
+                        "0x{}",
+                        block
+                            .initial_stack
+                            .hash()
+                            .iter()
+                            .map(|byte| format!("{byte:02x}"))
+                            .collect::<String>()
+                    ),
+                    block.elements.len(),
+                )
+            })
+            .collect::<Vec<_>>();
+        let actual = function
+            .blocks
+            .iter()
+            .map(|block| {
+                (
+                    block.key.to_owned(),
+                    block.instance,
+                    block.initial_stack_hash.to_owned(),
+                    block.elements.len(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(actual, expected);
+        assert!(
+            function.blocks.iter().any(|block| block.instance == 1),
+            "The block duplicated for another initial stack state is missing"
+        );
+    }
+
+    #[test]
+    fn unclosed_function() {
+        let result = "function main {\n    stack_usage: 0\n".parse::<Text>();
+        assert!(result.is_err(), "An unclosed function must be rejected");
+    }
+}
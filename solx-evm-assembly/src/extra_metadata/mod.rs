@@ -4,6 +4,8 @@
 
 pub mod recursive_function;
 
+use std::collections::BTreeMap;
+
 use self::recursive_function::RecursiveFunction;
 
 ///
@@ -51,4 +53,30 @@ impl ExtraMetadata {
 
         None
     }
+
+    ///
+    /// Returns the recursive functions of the specified code segment, keyed by the names of
+    /// their Ethereal IR counterparts.
+    ///
+    pub fn get_functions(
+        &self,
+        code_segment: era_compiler_common::CodeSegment,
+    ) -> BTreeMap<String, &RecursiveFunction> {
+        let mut functions = BTreeMap::new();
+        for function in self.recursive_functions.iter() {
+            let tag = match code_segment {
+                era_compiler_common::CodeSegment::Deploy => function.creation_tag,
+                era_compiler_common::CodeSegment::Runtime => function.runtime_tag,
+            };
+            let tag = match tag {
+                Some(tag) => tag,
+                None => continue,
+            };
+
+            let block_key =
+                era_compiler_llvm_context::BlockKey::new(code_segment, num::BigUint::from(tag));
+            functions.insert(format!("{}_{block_key}", function.name), function);
+        }
+        functions
+    }
 }
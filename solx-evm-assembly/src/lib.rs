@@ -14,5 +14,8 @@ pub mod ethereal_ir;
 pub mod extra_metadata;
 
 pub use crate::assembly::Assembly;
+pub use crate::ethereal_ir::debug_location::DebugLocation;
+pub use crate::ethereal_ir::limits::Limits as EtherealIRLimits;
+pub use crate::ethereal_ir::limits::Violation as EtherealIRLimitViolation;
 pub use crate::extra_metadata::recursive_function::RecursiveFunction as ExtraMetadataRecursiveFunction;
 pub use crate::extra_metadata::ExtraMetadata;
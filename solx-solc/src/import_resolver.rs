@@ -0,0 +1,92 @@
+//!
+//! The `solc` import resolver.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::panic::AssertUnwindSafe;
+
+///
+/// The `solc` import resolver.
+///
+/// Serves the sources that are imported but not provided in the standard JSON input, for
+/// instance, from a virtual filesystem, a package cache or a database.
+///
+pub trait ImportResolver {
+    ///
+    /// Returns the contents of the source at `path`, as it is written in the import directive
+    /// after applying remappings.
+    ///
+    fn resolve(&self, path: &str) -> anyhow::Result<String>;
+}
+
+impl ImportResolver for HashMap<String, String> {
+    fn resolve(&self, path: &str) -> anyhow::Result<String> {
+        self.get(path)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Source `{path}` not found"))
+    }
+}
+
+impl ImportResolver for BTreeMap<String, String> {
+    fn resolve(&self, path: &str) -> anyhow::Result<String> {
+        self.get(path)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Source `{path}` not found"))
+    }
+}
+
+/// The `solc` callback kind of source file requests.
+pub const CALLBACK_KIND_SOURCE: &str = "source";
+
+///
+/// The `solc` read callback that forwards source file requests to the resolver.
+///
+/// `context` must point to a `&dyn ImportResolver` that outlives the `solidity_compile` call.
+/// The results are allocated with `solidity_alloc`, as `solc` takes their ownership.
+/// Resolver panics must not unwind across the FFI boundary, so they are caught and reported
+/// to `solc` as errors.
+///
+pub(crate) unsafe extern "C" fn callback(
+    context: *mut ::libc::c_void,
+    kind: *const ::libc::c_char,
+    data: *const ::libc::c_char,
+    contents: *mut *mut ::libc::c_char,
+    error: *mut *mut ::libc::c_char,
+) {
+    let resolver = &*(context as *const &dyn ImportResolver);
+    let kind = CStr::from_ptr(kind).to_string_lossy();
+    let data = CStr::from_ptr(data).to_string_lossy();
+
+    let result = match kind.as_ref() {
+        CALLBACK_KIND_SOURCE => {
+            std::panic::catch_unwind(AssertUnwindSafe(|| resolver.resolve(data.as_ref())))
+                .unwrap_or_else(|payload| {
+                    let message = payload
+                        .downcast_ref::<&str>()
+                        .map(|message| message.to_string())
+                        .or_else(|| payload.downcast_ref::<String>().cloned())
+                        .unwrap_or_default();
+                    Err(anyhow::anyhow!(
+                        "Import resolver panicked on `{data}`: {message}"
+                    ))
+                })
+        }
+        kind => Err(anyhow::anyhow!("Unsupported callback kind `{kind}`")),
+    };
+    match result {
+        Ok(result) => *contents = allocate(result.as_str()),
+        Err(result) => *error = allocate(result.to_string().as_str()),
+    }
+}
+
+///
+/// Copies the string to a null-terminated buffer allocated by `solc`.
+///
+unsafe fn allocate(string: &str) -> *mut ::libc::c_char {
+    let buffer = crate::solidity_alloc(string.len() + 1);
+    std::ptr::copy_nonoverlapping(string.as_ptr(), buffer as *mut u8, string.len());
+    *buffer.add(string.len()) = 0;
+    buffer
+}
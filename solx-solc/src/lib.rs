@@ -9,6 +9,10 @@
 #![allow(clippy::should_implement_trait)]
 #![allow(clippy::result_large_err)]
 
+pub mod import_resolver;
+
+pub use self::import_resolver::ImportResolver;
+
 use std::ffi::CStr;
 use std::ffi::CString;
 use std::path::PathBuf;
@@ -48,6 +52,13 @@ extern "C" {
         error_pointer: *mut *mut ::libc::c_char,
     ) -> *const std::os::raw::c_char;
 
+    ///
+    /// Allocate memory that is owned and freed by the Solidity compiler.
+    ///
+    /// Used for the results returned from the read callback.
+    ///
+    fn solidity_alloc(size: usize) -> *mut ::libc::c_char;
+
     ///
     /// Get the Solidity compiler version.
     ///
@@ -68,6 +79,10 @@ impl Compiler {
     ///
     /// Metadata is always requested in order to calculate the metadata hash even if not requested in the `output_selection`.
     /// EVM assembly or Yul is always selected in order to compile the Solidity code.
+    /// Storage layout is requested for ethdebug, as it describes the storage variable locations.
+    ///
+    /// If `import_resolver` is set, the imports missing from the input are requested from it
+    /// instead of the filesystem, and `use_import_callback` is ignored.
     ///
     pub fn standard_json(
         &self,
@@ -77,10 +92,18 @@ impl Compiler {
         base_path: Option<String>,
         include_paths: Vec<String>,
         allow_paths: Option<String>,
+        import_resolver: Option<&dyn ImportResolver>,
     ) -> anyhow::Result<solx_standard_json::Output> {
         let original_output_selection = input_json.settings.output_selection.to_owned();
+        let output_ethdebug = input_json
+            .settings
+            .output_selection
+            .is_ethdebug_set_for_any();
         input_json.settings.output_selection.normalize();
-        input_json.settings.output_selection.retain_solc();
+        input_json
+            .settings
+            .output_selection
+            .retain_solc(input_json.settings.via_ir);
         input_json
             .settings
             .output_selection
@@ -89,6 +112,19 @@ impl Compiler {
             .settings
             .output_selection
             .set_selector(input_json.settings.via_ir.into());
+        if output_ethdebug {
+            input_json
+                .settings
+                .output_selection
+                .set_selector(solx_standard_json::InputSelector::StorageLayout);
+        }
+        if !input_json.settings.via_ir
+            && input_json.settings.recover_internal_functions
+            && input_json.language == solx_standard_json::InputLanguage::Solidity
+        {
+            // The AST is used to name the internal functions recovered from the EVM assembly.
+            input_json.settings.output_selection.set_ast_for_all();
+        }
 
         let original_optimizer = input_json.settings.optimizer.to_owned();
         input_json.settings.optimizer.mode = None;
@@ -134,7 +170,13 @@ impl Compiler {
         let mut error_message = std::ptr::null_mut();
         let error_pointer = &mut error_message;
         let output_string = unsafe {
-            let output_pointer = if use_import_callback {
+            let output_pointer = if let Some(import_resolver) = import_resolver.as_ref() {
+                solidity_compile(
+                    input_c_string.as_ptr(),
+                    import_resolver::callback as *const ::libc::c_void,
+                    import_resolver as *const &dyn ImportResolver as *const ::libc::c_void,
+                )
+            } else if use_import_callback {
                 solidity_compile_default_callback(
                     input_c_string.as_ptr(),
                     base_path,
@@ -188,6 +230,7 @@ impl Compiler {
         &self,
         paths: &[PathBuf],
         libraries: era_compiler_common::Libraries,
+        evm_version: Option<era_compiler_common::EVMVersion>,
         messages: &mut Vec<solx_standard_json::OutputError>,
     ) -> anyhow::Result<solx_standard_json::Output> {
         let mut solc_input = solx_standard_json::Input::from_yul_paths(
@@ -198,6 +241,7 @@ impl Compiler {
             solx_standard_json::InputMetadata::default(),
             vec![],
         );
+        solc_input.settings.evm_version = evm_version;
         self.validate_yul_standard_json(&mut solc_input, messages)
     }
 
@@ -213,7 +257,8 @@ impl Compiler {
             .settings
             .output_selection
             .set_selector(solx_standard_json::InputSelector::Yul);
-        let solc_output = self.standard_json(solc_input, messages, true, None, vec![], None)?;
+        let solc_output =
+            self.standard_json(solc_input, messages, true, None, vec![], None, None)?;
         Ok(solc_output)
     }
 
@@ -244,4 +244,62 @@ impl Input {
             ),
         }
     }
+
+    ///
+    /// A shortcut constructor from paths to EVM assembly JSON files.
+    ///
+    pub fn from_evm_assembly_paths(
+        paths: &[PathBuf],
+        libraries: era_compiler_common::Libraries,
+        optimizer: InputSettingsOptimizer,
+        output_selection: &InputSettingsSelection,
+        metadata: InputSettingsMetadata,
+        llvm_options: Vec<String>,
+    ) -> Self {
+        let sources = paths
+            .iter()
+            .map(|path| {
+                (
+                    path.to_string_lossy().to_string(),
+                    Source::from(path.as_path()),
+                )
+            })
+            .collect();
+
+        Self::from_evm_assembly_sources(
+            sources,
+            libraries,
+            optimizer,
+            output_selection,
+            metadata,
+            llvm_options,
+        )
+    }
+
+    ///
+    /// A shortcut constructor from EVM assembly JSON.
+    ///
+    pub fn from_evm_assembly_sources(
+        sources: BTreeMap<String, Source>,
+        libraries: era_compiler_common::Libraries,
+        optimizer: InputSettingsOptimizer,
+        output_selection: &InputSettingsSelection,
+        metadata: InputSettingsMetadata,
+        llvm_options: Vec<String>,
+    ) -> Self {
+        Self {
+            language: Language::EVMAssembly,
+            sources,
+            settings: Settings::new(
+                optimizer,
+                libraries,
+                BTreeSet::new(),
+                None,
+                false,
+                output_selection.to_owned(),
+                metadata,
+                llvm_options,
+            ),
+        }
+    }
 }
@@ -7,6 +7,7 @@ pub mod optimizer;
 pub mod selection;
 
 use std::collections::BTreeSet;
+use std::path::PathBuf;
 
 use self::metadata::Metadata;
 use self::optimizer::Optimizer;
@@ -42,6 +43,15 @@ pub struct Settings {
         skip_serializing_if = "Settings::is_via_ir_default"
     )]
     pub via_ir: bool,
+    /// Whether to recompile contracts that fail with stack-too-deep errors via the other codegen.
+    #[serde(default, skip_serializing)]
+    pub pipeline_fallback: bool,
+    /// The Ethereal IR traversal limits.
+    #[serde(default, skip_serializing)]
+    pub ethir_limits: solx_evm_assembly::EtherealIRLimits,
+    /// Whether to recover the internal functions from the EVM assembly.
+    #[serde(default, skip_serializing)]
+    pub recover_internal_functions: bool,
 
     /// The output selection filters.
     #[serde(default, skip_serializing_if = "Selection::is_empty")]
@@ -53,6 +63,9 @@ pub struct Settings {
     /// The extra LLVM options.
     #[serde(default, skip_serializing)]
     pub llvm_options: Vec<String>,
+    /// The compilation cache directory.
+    #[serde(default, skip_serializing)]
+    pub cache_directory: Option<PathBuf>,
 }
 
 impl Settings {
@@ -83,10 +96,14 @@ impl Settings {
 
             evm_version,
             via_ir,
+            pipeline_fallback: false,
+            ethir_limits: solx_evm_assembly::EtherealIRLimits::default(),
+            recover_internal_functions: false,
 
             output_selection,
             metadata,
             llvm_options,
+            cache_directory: None,
         }
     }
 
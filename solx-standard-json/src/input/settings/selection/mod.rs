@@ -132,6 +132,18 @@ impl Selection {
         }
     }
 
+    ///
+    /// Adds the AST to the output selection of all files.
+    ///
+    pub fn set_ast_for_all(&mut self) {
+        self.inner
+            .entry(Self::WILDCARD.to_owned())
+            .or_default()
+            .entry(Self::ANY_CONTRACT.to_owned())
+            .or_default()
+            .insert(Selector::AST);
+    }
+
     ///
     /// Normalizes the selection by converting multi-item selectors into single-item selectors.
     ///
@@ -149,10 +161,10 @@ impl Selection {
     ///
     /// Retains only the selectors that request data from `solc`.
     ///
-    pub fn retain_solc(&mut self) {
+    pub fn retain_solc(&mut self, via_ir: bool) {
         for file in self.inner.values_mut() {
             for contract in file.values_mut() {
-                contract.retain(Selector::is_received_from_solc);
+                contract.retain(|selector| selector.is_received_from_solc(via_ir));
             }
         }
     }
@@ -177,6 +189,24 @@ impl Selection {
         false
     }
 
+    ///
+    /// Checks if the ethdebug data is requested for at least one contract.
+    ///
+    /// The ethdebug selectors are never implied by wildcards, as the format is experimental.
+    ///
+    pub fn is_ethdebug_set_for_any(&self) -> bool {
+        for file in self.inner.values() {
+            for contract in file.values() {
+                if contract.contains(&Selector::BytecodeEthdebug)
+                    || contract.contains(&Selector::RuntimeBytecodeEthdebug)
+                {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
     ///
     /// Whether the selection is empty.
     ///
@@ -52,6 +52,15 @@ pub enum Selector {
     /// The deploy LLVM assembly.
     #[serde(rename = "evm.bytecode.llvmAssembly")]
     BytecodeLLVMAssembly,
+    /// The deploy LLVM IR.
+    #[serde(rename = "evm.bytecode.llvmIr")]
+    BytecodeLLVMIR,
+    /// The deploy optimized LLVM IR.
+    #[serde(rename = "evm.bytecode.llvmIrOptimized")]
+    BytecodeLLVMIROptimized,
+    /// The deploy Ethereal IR.
+    #[serde(rename = "evm.bytecode.ethir")]
+    BytecodeEthIR,
     /// The deploy bytecode opcodes.
     #[serde(rename = "evm.bytecode.opcodes")]
     BytecodeOpcodes,
@@ -67,6 +76,9 @@ pub enum Selector {
     /// The deploy bytecode generated sources
     #[serde(rename = "evm.bytecode.generatedSources")]
     BytecodeGeneratedSources,
+    /// The deploy bytecode ethdebug program.
+    #[serde(rename = "evm.bytecode.ethdebug")]
+    BytecodeEthdebug,
     /// The runtime bytecode.
     #[serde(rename = "evm.deployedBytecode")]
     RuntimeBytecode,
@@ -76,6 +88,15 @@ pub enum Selector {
     /// The runtime LLVM assembly.
     #[serde(rename = "evm.deployedBytecode.llvmAssembly")]
     RuntimeBytecodeLLVMAssembly,
+    /// The runtime LLVM IR.
+    #[serde(rename = "evm.deployedBytecode.llvmIr")]
+    RuntimeBytecodeLLVMIR,
+    /// The runtime optimized LLVM IR.
+    #[serde(rename = "evm.deployedBytecode.llvmIrOptimized")]
+    RuntimeBytecodeLLVMIROptimized,
+    /// The runtime Ethereal IR.
+    #[serde(rename = "evm.deployedBytecode.ethir")]
+    RuntimeBytecodeEthIR,
     /// The runtime bytecode opcodes.
     #[serde(rename = "evm.deployedBytecode.opcodes")]
     RuntimeBytecodeOpcodes,
@@ -94,6 +115,9 @@ pub enum Selector {
     /// The runtime bytecode generated sources
     #[serde(rename = "evm.deployedBytecode.generatedSources")]
     RuntimeBytecodeGeneratedSources,
+    /// The runtime bytecode ethdebug program.
+    #[serde(rename = "evm.deployedBytecode.ethdebug")]
+    RuntimeBytecodeEthdebug,
 
     /// The wildcard variant that selects everything.
     #[serde(rename = "*")]
@@ -104,31 +128,49 @@ impl Selector {
     ///
     /// Whether the data source is `solc`.
     ///
-    pub fn is_received_from_solc(&self) -> bool {
+    /// Generated sources are only received from `solc` with the EVM assembly codegen.
+    /// With the IR codegen, `solc` does not produce bytecode, so they are extracted from the optimized IR.
+    ///
+    pub fn is_received_from_solc(&self, via_ir: bool) -> bool {
+        if let Self::BytecodeGeneratedSources | Self::RuntimeBytecodeGeneratedSources = self {
+            return !via_ir;
+        }
+
         !matches!(
             self,
             Self::EVM
                 | Self::Bytecode
                 | Self::BytecodeObject
                 | Self::BytecodeLLVMAssembly
+                | Self::BytecodeLLVMIR
+                | Self::BytecodeLLVMIROptimized
+                | Self::BytecodeEthIR
+                | Self::BytecodeOpcodes
                 | Self::BytecodeLinkReferences
                 | Self::BytecodeSourceMap
-                | Self::BytecodeGeneratedSources
                 | Self::BytecodeFunctionDebugData
+                | Self::BytecodeEthdebug
                 | Self::RuntimeBytecode
                 | Self::RuntimeBytecodeObject
                 | Self::RuntimeBytecodeLLVMAssembly
+                | Self::RuntimeBytecodeLLVMIR
+                | Self::RuntimeBytecodeLLVMIROptimized
+                | Self::RuntimeBytecodeEthIR
+                | Self::RuntimeBytecodeOpcodes
                 | Self::RuntimeBytecodeSourceMap
-                | Self::RuntimeBytecodeGeneratedSources
                 | Self::RuntimeBytecodeFunctionDebugData
                 | Self::RuntimeBytecodeLinkReferences
                 | Self::RuntimeBytecodeImmutableReferences
+                | Self::RuntimeBytecodeEthdebug
         )
     }
 
     ///
     /// Converts a multi-item selector into a group of single-item selectors.
     ///
+    /// The LLVM IR and Ethereal IR selectors are never implied by wildcards, as the IRs are large
+    /// and expensive to produce.
+    ///
     pub fn into_single_selectors(self) -> Vec<Self> {
         match self {
             Self::EVM => vec![
@@ -2,10 +2,13 @@
 //! The `solc --standard-json` output contract EVM bytecode.
 //!
 
+pub mod immutable_reference;
 pub mod link_reference;
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 
+use self::immutable_reference::ImmutableReference;
 use self::link_reference::LinkReference;
 
 ///
@@ -20,6 +23,15 @@ pub struct Bytecode {
     /// Text assembly from LLVM.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub llvm_assembly: Option<String>,
+    /// LLVM IR before optimization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub llvm_ir: Option<String>,
+    /// LLVM IR after optimization.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub llvm_ir_optimized: Option<String>,
+    /// Ethereal IR. Only produced by the EVM assembly codegen.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ethir: Option<String>,
     /// Link references placeholder.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub link_references: Option<BTreeMap<String, BTreeMap<String, Vec<LinkReference>>>>,
@@ -36,9 +48,12 @@ pub struct Bytecode {
     /// Function debug data placeholder.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub function_debug_data: Option<BTreeMap<String, serde_json::Value>>,
-    /// Immutable generated_sources placeholder.
+    /// Immutable references, keyed by the immutable AST identifiers.
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub immutable_references: Option<serde_json::Value>,
+    pub immutable_references: Option<BTreeMap<String, Vec<ImmutableReference>>>,
+    /// The ethdebug program.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ethdebug: Option<serde_json::Value>,
 }
 
 impl Bytecode {
@@ -48,13 +63,17 @@ impl Bytecode {
     pub fn new(
         object: Option<String>,
         llvm_assembly: Option<String>,
+        llvm_ir: Option<String>,
+        llvm_ir_optimized: Option<String>,
+        ethir: Option<String>,
         unlinked_symbols: Option<BTreeMap<String, Vec<u64>>>,
 
         opcodes: Option<String>,
         source_map: Option<String>,
         generated_sources: Option<Vec<serde_json::Value>>,
         function_debug_data: Option<BTreeMap<String, serde_json::Value>>,
-        immutable_references: Option<serde_json::Value>,
+        immutables: Option<BTreeMap<String, BTreeSet<u64>>>,
+        ethdebug: Option<serde_json::Value>,
     ) -> Self {
         let link_references = unlinked_symbols.map(|unlinked_symbols| {
             let mut link_references = BTreeMap::new();
@@ -77,9 +96,25 @@ impl Bytecode {
             link_references
         });
 
+        let immutable_references = immutables.map(|immutables| {
+            immutables
+                .into_iter()
+                .map(|(id, offsets)| {
+                    let references = offsets
+                        .into_iter()
+                        .map(ImmutableReference::new)
+                        .collect::<Vec<ImmutableReference>>();
+                    (id, references)
+                })
+                .collect()
+        });
+
         Self {
             object,
             llvm_assembly,
+            llvm_ir,
+            llvm_ir_optimized,
+            ethir,
             link_references,
 
             opcodes,
@@ -87,6 +122,7 @@ impl Bytecode {
             generated_sources,
             function_debug_data,
             immutable_references,
+            ethdebug,
         }
     }
 
@@ -96,11 +132,15 @@ impl Bytecode {
     pub fn is_empty(&self) -> bool {
         self.object.is_none()
             && self.llvm_assembly.is_none()
+            && self.llvm_ir.is_none()
+            && self.llvm_ir_optimized.is_none()
+            && self.ethir.is_none()
             && self.link_references.is_none()
             && self.opcodes.is_none()
             && self.source_map.is_none()
             && self.generated_sources.is_none()
             && self.function_debug_data.is_none()
             && self.immutable_references.is_none()
+            && self.ethdebug.is_none()
     }
 }
@@ -15,7 +15,7 @@ pub struct MappedLocation<'a> {
     pub path: String,
     /// The line number.
     pub line: Option<usize>,
-    /// The column number.
+    /// The column number, starting from 0.
     pub column: Option<usize>,
     /// The error area length.
     pub length: Option<usize>,
@@ -67,26 +67,26 @@ impl<'a> MappedLocation<'a> {
             Some(source_code) => source_code,
             None => return Self::new(source_location.file.to_owned()),
         };
-        if source_location.start <= 0 || source_location.end <= 0 {
+        if source_location.start < 0 || source_location.end < 0 {
             return Self::new(source_location.file.to_owned());
         }
         let start = source_location.start as usize;
         let end = source_location.end as usize;
 
-        let mut cursor = 1;
-        for (line, source_line) in source_code.lines().enumerate() {
+        let mut cursor = 0;
+        for (line, source_line) in source_code.split('\n').enumerate() {
             let cursor_next = cursor + source_line.len() + 1;
 
-            if cursor <= start && start <= cursor_next {
+            if cursor <= start && start < cursor_next {
                 let line = line + 1;
                 let column = start - cursor;
-                let length = end - start;
+                let length = end.saturating_sub(start);
                 return Self::new_with_location(
                     source_location.file.to_owned(),
                     line,
                     column,
                     length,
-                    Some(source_line),
+                    Some(source_line.trim_end_matches('\r')),
                 );
             }
 
@@ -105,7 +105,7 @@ impl std::fmt::Display for MappedLocation<'_> {
             path.push_str(line.to_string().as_str());
             if let Some(column) = self.column {
                 path.push(':');
-                path.push_str(column.to_string().as_str());
+                path.push_str((column + 1).to_string().as_str());
                 if let (Some(source_code_line), Some(length)) = (self.source_code_line, self.length)
                 {
                     let line_number_length = line.to_string().len();
@@ -114,10 +114,13 @@ impl std::fmt::Display for MappedLocation<'_> {
                     writeln!(f, " {line} | {source_code_line}")?;
                     writeln!(
                         f,
-                        " {} | {} {}",
+                        " {} | {}{}",
                         " ".repeat(line_number_length),
                         " ".repeat(column),
-                        "^".repeat(std::cmp::min(length, source_code_line.len() - column))
+                        "^".repeat(std::cmp::min(
+                            length,
+                            source_code_line.len().saturating_sub(column)
+                        ))
                     )?;
                 }
             }
@@ -115,6 +115,25 @@ impl Error {
     {
         Self::new("Warning", error_code, message, source_location, sources)
     }
+
+    ///
+    /// Formats the message again with the source code snippet of the error location.
+    ///
+    /// Useful for errors created without access to the source code, for example, in subprocesses.
+    ///
+    pub fn format_with_sources(&mut self, sources: &BTreeMap<String, InputSource>) {
+        let error_code = self
+            .error_code
+            .as_deref()
+            .and_then(|error_code| error_code.parse::<isize>().ok());
+        *self = Self::new(
+            self.r#type.as_str(),
+            error_code,
+            self.message.as_str(),
+            self.source_location.take(),
+            Some(sources),
+        );
+    }
 }
 
 impl std::fmt::Display for Error {
@@ -32,6 +32,9 @@ pub struct Output {
     /// The compilation errors and warnings.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub errors: Vec<JsonOutputError>,
+    /// The ethdebug compilation data.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ethdebug: Option<serde_json::Value>,
 }
 
 impl Output {
@@ -54,6 +57,7 @@ impl Output {
             contracts: BTreeMap::new(),
             sources,
             errors: std::mem::take(messages),
+            ethdebug: None,
         }
     }
 
@@ -67,6 +71,7 @@ impl Output {
             contracts: BTreeMap::new(),
             sources: BTreeMap::new(),
             errors: messages,
+            ethdebug: None,
         }
     }
 
@@ -2,6 +2,8 @@
 //! Collection of dependencies.
 //!
 
+use crate::yul::lexer::token::location::Location;
+
 ///
 /// This structure represents an ordered dependency collection
 /// in the order they are encountered in IR from the top to the bottom.
@@ -12,6 +14,10 @@ pub struct Dependencies {
     pub identifier: String,
     /// List of EVM dependencies.
     pub inner: Vec<String>,
+    /// The full object paths referenced via `dataoffset` and `datasize`, with the locations
+    /// of their literals.
+    #[serde(skip)]
+    pub references: Vec<(String, Location)>,
 }
 
 impl Dependencies {
@@ -22,6 +28,7 @@ impl Dependencies {
         Self {
             identifier: identifier.to_owned(),
             inner: Vec::new(),
+            references: Vec::new(),
         }
     }
 
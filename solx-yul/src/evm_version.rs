@@ -0,0 +1,43 @@
+//!
+//! The EVM versions that introduced the instructions.
+//!
+
+/// The instructions that are not available in all EVM versions, with the versions that
+/// introduced them.
+///
+/// The table is shared by the EVM version checks of the Yul, EVM assembly, and LLVM IR inputs.
+pub const INSTRUCTIONS: [(&str, era_compiler_common::EVMVersion); 18] = [
+    ("returndatasize", era_compiler_common::EVMVersion::Byzantium),
+    ("returndatacopy", era_compiler_common::EVMVersion::Byzantium),
+    ("staticcall", era_compiler_common::EVMVersion::Byzantium),
+    ("revert", era_compiler_common::EVMVersion::Byzantium),
+    ("shl", era_compiler_common::EVMVersion::Constantinople),
+    ("shr", era_compiler_common::EVMVersion::Constantinople),
+    ("sar", era_compiler_common::EVMVersion::Constantinople),
+    ("create2", era_compiler_common::EVMVersion::Constantinople),
+    (
+        "extcodehash",
+        era_compiler_common::EVMVersion::Constantinople,
+    ),
+    ("chainid", era_compiler_common::EVMVersion::Istanbul),
+    ("selfbalance", era_compiler_common::EVMVersion::Istanbul),
+    ("basefee", era_compiler_common::EVMVersion::London),
+    ("prevrandao", era_compiler_common::EVMVersion::Paris),
+    ("tload", era_compiler_common::EVMVersion::Cancun),
+    ("tstore", era_compiler_common::EVMVersion::Cancun),
+    ("mcopy", era_compiler_common::EVMVersion::Cancun),
+    ("blobhash", era_compiler_common::EVMVersion::Cancun),
+    ("blobbasefee", era_compiler_common::EVMVersion::Cancun),
+];
+
+///
+/// Returns the EVM version that introduced the `instruction`, if it is not available in all of them.
+///
+/// The name is matched case-insensitively, so both Yul builtins and EVM assembly mnemonics are accepted.
+///
+pub fn required(instruction: &str) -> Option<era_compiler_common::EVMVersion> {
+    INSTRUCTIONS
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(instruction))
+        .map(|(_, evm_version)| *evm_version)
+}
@@ -10,6 +10,7 @@
 #![allow(clippy::result_large_err)]
 
 pub mod dependencies;
+pub mod evm_version;
 pub mod util;
 pub mod yul;
 
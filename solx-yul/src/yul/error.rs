@@ -3,12 +3,13 @@
 //!
 
 use crate::yul::lexer::error::Error as LexerError;
+use crate::yul::lexer::token::location::Location;
 use crate::yul::parser::error::Error as ParserError;
 
 ///
 /// The Yul IR error.
 ///
-#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
 pub enum Error {
     /// The lexer error.
     #[error("Lexical: {0}")]
@@ -17,3 +18,25 @@ pub enum Error {
     #[error("Syntax: {0}")]
     Parser(#[from] ParserError),
 }
+
+impl Error {
+    ///
+    /// Returns the error location.
+    ///
+    pub fn location(&self) -> Location {
+        match self {
+            Self::Lexer(error) => error.location(),
+            Self::Parser(error) => error.location(),
+        }
+    }
+
+    ///
+    /// Returns the length of the erroneous source code fragment in bytes.
+    ///
+    pub fn length(&self) -> usize {
+        match self {
+            Self::Lexer(error) => error.length(),
+            Self::Parser(error) => error.length(),
+        }
+    }
+}
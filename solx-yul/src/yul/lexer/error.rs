@@ -7,7 +7,7 @@ use crate::yul::lexer::token::location::Location;
 ///
 /// The Yul IR lexer error.
 ///
-#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
 pub enum Error {
     /// The invalid lexeme error.
     #[error("{location} Invalid character sequence `{sequence}`")]
@@ -18,3 +18,23 @@ pub enum Error {
         sequence: String,
     },
 }
+
+impl Error {
+    ///
+    /// Returns the error location.
+    ///
+    pub fn location(&self) -> Location {
+        match self {
+            Self::InvalidLexeme { location, .. } => *location,
+        }
+    }
+
+    ///
+    /// Returns the length of the erroneous source code fragment in bytes.
+    ///
+    pub fn length(&self) -> usize {
+        match self {
+            Self::InvalidLexeme { sequence, .. } => sequence.len(),
+        }
+    }
+}
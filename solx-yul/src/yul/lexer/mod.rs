@@ -8,7 +8,11 @@ pub mod token;
 #[cfg(test)]
 mod tests;
 
+use std::collections::BTreeMap;
+
 use self::error::Error;
+use self::token::annotation::source::Source;
+use self::token::annotation::Annotation;
 use self::token::lexeme::comment::Comment;
 use self::token::lexeme::identifier::Identifier;
 use self::token::lexeme::literal::integer::Integer as IntegerLiteral;
@@ -24,12 +28,18 @@ use self::token::Token;
 pub struct Lexer<'a> {
     /// The input source code.
     input: &'a str,
-    /// The number of characters processed so far.
+    /// The number of bytes processed so far.
     offset: usize,
     /// The current location.
     location: Location,
     /// The peeked lexeme, waiting to be fetched.
     peeked: Option<Token>,
+    /// The last `@use-src` annotation, waiting to be taken by the next object.
+    use_source: Option<BTreeMap<usize, String>>,
+    /// The identifiers of the distinct `@src` annotation ranges seen so far.
+    source_ids: BTreeMap<Source, usize>,
+    /// The syntax errors the parser has recovered from.
+    errors: Vec<crate::yul::error::Error>,
 }
 
 impl<'a> Lexer<'a> {
@@ -42,6 +52,9 @@ impl<'a> Lexer<'a> {
             offset: 0,
             location: Location::default(),
             peeked: None,
+            use_source: None,
+            source_ids: BTreeMap::new(),
+            errors: Vec::new(),
         }
     }
 
@@ -55,6 +68,7 @@ impl<'a> Lexer<'a> {
 
         while self.offset < self.input.len() {
             let input = &self.input[self.offset..];
+            self.location.offset = self.offset;
 
             if input.starts_with(|character| char::is_ascii_whitespace(&character)) {
                 if input.starts_with('\n') {
@@ -68,6 +82,19 @@ impl<'a> Lexer<'a> {
             }
 
             if let Some(token) = Comment::parse(input) {
+                let comment = &input[..std::cmp::min(token.length, input.len())];
+                for annotation in Annotation::parse_comment(comment).into_iter() {
+                    match annotation {
+                        Annotation::Source(source) => {
+                            let next_id = self.source_ids.len();
+                            self.location.source = source;
+                            self.location.source_id = source
+                                .map(|source| *self.source_ids.entry(source).or_insert(next_id));
+                        }
+                        Annotation::UseSource(paths) => self.use_source = Some(paths),
+                    }
+                }
+
                 self.offset += token.length;
                 self.location
                     .shift_down(token.location.line, token.location.column);
@@ -115,9 +142,42 @@ impl<'a> Lexer<'a> {
             });
         }
 
+        self.location.offset = self.offset;
         Ok(Token::new(self.location, Lexeme::EndOfFile, 0))
     }
 
+    ///
+    /// Takes the source paths of the last `@use-src` annotation, if any.
+    ///
+    pub fn take_use_source(&mut self) -> Option<BTreeMap<usize, String>> {
+        self.use_source.take()
+    }
+
+    ///
+    /// Records a syntax error the parser has recovered from.
+    ///
+    /// The same error is recorded only once, as it may be propagated through several blocks.
+    ///
+    pub fn push_error(&mut self, error: crate::yul::error::Error) {
+        if !self.errors.contains(&error) {
+            self.errors.push(error);
+        }
+    }
+
+    ///
+    /// Returns the syntax errors the parser has recovered from.
+    ///
+    pub fn errors(&self) -> &[crate::yul::error::Error] {
+        self.errors.as_slice()
+    }
+
+    ///
+    /// Takes the syntax errors the parser has recovered from.
+    ///
+    pub fn take_errors(&mut self) -> Vec<crate::yul::error::Error> {
+        std::mem::take(&mut self.errors)
+    }
+
     ///
     /// Peeks the next lexeme without advancing the iterator.
     ///
@@ -3,6 +3,7 @@
 //!
 
 use crate::yul::lexer::error::Error;
+use crate::yul::lexer::token::annotation::source::Source;
 use crate::yul::lexer::token::lexeme::Lexeme;
 use crate::yul::lexer::token::location::Location;
 use crate::yul::lexer::Lexer;
@@ -89,3 +90,89 @@ object "Test" {
         }
     }
 }
+
+#[test]
+fn annotations() {
+    let input = r#"
+/// @use-src 0:"contracts/Test.sol", 1:"contracts/Escaped \"Name\".sol"
+object "Test" {
+    code {
+        /// @src 0:57:126  "contract Test {..."
+        mstore(64, 128)
+        /** @src -1:-1:-1 */
+        return(0, 0)
+    }
+}
+    "#;
+
+    let mut lexer = Lexer::new(input);
+
+    let token = lexer.next().expect("Always valid");
+    assert_eq!(token.lexeme.to_string(), "object");
+    assert_eq!(token.location.source, None);
+    let use_source = lexer.take_use_source().expect("Always exists");
+    assert_eq!(
+        use_source.into_iter().collect::<Vec<_>>(),
+        vec![
+            (0, "contracts/Test.sol".to_owned()),
+            (1, r#"contracts/Escaped "Name".sol"#.to_owned()),
+        ]
+    );
+
+    let mut sources = Vec::new();
+    loop {
+        let token = lexer.next().expect("Always valid");
+        if token.lexeme == Lexeme::EndOfFile {
+            break;
+        }
+        if let Lexeme::Identifier(ref identifier) = token.lexeme {
+            sources.push((identifier.inner.to_owned(), token.location.source));
+        }
+    }
+    assert_eq!(
+        sources,
+        vec![
+            ("code".to_owned(), None),
+            ("mstore".to_owned(), Some(Source::new(0, 57, 126))),
+            ("return".to_owned(), None),
+        ]
+    );
+}
+
+#[test]
+fn annotation_source_ids() {
+    let input = r#"
+object "Test" {
+    code {
+        /// @src 0:57:126
+        mstore(64, 128)
+        /// @src 0:57:60
+        pop(0)
+        /// @src 0:57:126
+        return(0, 0)
+    }
+}
+    "#;
+
+    let mut lexer = Lexer::new(input);
+
+    let mut source_ids = Vec::new();
+    loop {
+        let token = lexer.next().expect("Always valid");
+        if token.lexeme == Lexeme::EndOfFile {
+            break;
+        }
+        if let Lexeme::Identifier(ref identifier) = token.lexeme {
+            source_ids.push((identifier.inner.to_owned(), token.location.source_id));
+        }
+    }
+    assert_eq!(
+        source_ids,
+        vec![
+            ("code".to_owned(), None),
+            ("mstore".to_owned(), Some(0)),
+            ("pop".to_owned(), Some(1)),
+            ("return".to_owned(), Some(0)),
+        ]
+    );
+}
@@ -0,0 +1,123 @@
+//!
+//! The Yul comment annotation.
+//!
+
+pub mod source;
+
+use std::collections::BTreeMap;
+
+use self::source::Source;
+
+///
+/// The Yul comment annotation.
+///
+/// The annotations are emitted by `solc` in its Yul output to map the code back to the original
+/// source files.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Annotation {
+    /// The `@src` annotation, applied to the code after it until the next one.
+    /// `None` if the code is not mapped to any source.
+    Source(Option<Source>),
+    /// The `@use-src` annotation, mapping the source indexes of the next object to paths.
+    UseSource(BTreeMap<usize, String>),
+}
+
+impl Annotation {
+    /// The source location annotation tag.
+    pub const TAG_SOURCE: &'static str = "@src";
+    /// The source list annotation tag.
+    pub const TAG_USE_SOURCE: &'static str = "@use-src";
+
+    ///
+    /// Parses the annotations from the comment text.
+    ///
+    /// Malformed annotations are ignored, as they only affect diagnostics.
+    ///
+    pub fn parse_comment(comment: &str) -> Vec<Self> {
+        let mut annotations = Vec::new();
+
+        let mut offset = 0;
+        while let Some(position) = comment[offset..].find('@') {
+            offset += position;
+            let input = &comment[offset..];
+
+            if let Some(arguments) = input.strip_prefix(Self::TAG_USE_SOURCE) {
+                offset += Self::TAG_USE_SOURCE.len();
+                if let Some((paths, length)) = Self::parse_use_source(arguments) {
+                    annotations.push(Self::UseSource(paths));
+                    offset += length;
+                }
+            } else if let Some(arguments) = input.strip_prefix(Self::TAG_SOURCE) {
+                offset += Self::TAG_SOURCE.len();
+                if let Some((source, length)) = Source::parse(arguments) {
+                    annotations.push(Self::Source(source));
+                    offset += length;
+
+                    let snippet = &comment[offset..];
+                    let snippet_trimmed = snippet.trim_start();
+                    if snippet_trimmed.starts_with('"') {
+                        if let Some(length) =
+                            Self::parse_quoted(snippet_trimmed).map(|(_, length)| length)
+                        {
+                            offset += snippet.len() - snippet_trimmed.len() + length;
+                        }
+                    }
+                }
+            } else {
+                offset += 1;
+            }
+        }
+
+        annotations
+    }
+
+    ///
+    /// Parses the `<index>:"<path>", ...` annotation arguments.
+    ///
+    /// Returns the paths by their source indexes, and the number of bytes consumed.
+    ///
+    fn parse_use_source(input: &str) -> Option<(BTreeMap<usize, String>, usize)> {
+        let mut paths = BTreeMap::new();
+
+        let mut offset = 0;
+        loop {
+            let arguments = input[offset..].trim_start();
+            offset = input.len() - arguments.len();
+
+            let index_length = arguments
+                .find(|character: char| !character.is_ascii_digit())
+                .unwrap_or(arguments.len());
+            let index = arguments[..index_length].parse::<usize>().ok()?;
+            let path = arguments[index_length..].strip_prefix(':')?;
+            let (path, path_length) = Self::parse_quoted(path)?;
+            paths.insert(index, path);
+            offset += index_length + 1 + path_length;
+
+            match input[offset..].strip_prefix(',') {
+                Some(_) => offset += 1,
+                None => break,
+            }
+        }
+
+        Some((paths, offset))
+    }
+
+    ///
+    /// Parses a double-quoted string with backslash escapes.
+    ///
+    /// Returns the unescaped string and the number of bytes consumed, including the quotes.
+    ///
+    fn parse_quoted(input: &str) -> Option<(String, usize)> {
+        let mut characters = input.strip_prefix('"')?.char_indices();
+        let mut string = String::new();
+        while let Some((index, character)) = characters.next() {
+            match character {
+                '"' => return Some((string, 1 + index + 1)),
+                '\\' => string.push(characters.next()?.1),
+                character => string.push(character),
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,63 @@
+//!
+//! The Yul source location annotation.
+//!
+
+///
+/// The Yul source location annotation.
+///
+/// Describes the range of the original source code, usually Solidity, the Yul code has been
+/// generated from. Emitted by `solc` as `/// @src <index>:<start>:<end>` comments.
+///
+#[derive(
+    Debug, serde::Serialize, serde::Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord,
+)]
+pub struct Source {
+    /// The source index, resolved to a path with the `@use-src` annotation of the object.
+    pub index: usize,
+    /// The byte offset of the range start in the source file.
+    pub start: usize,
+    /// The byte offset of the range end in the source file.
+    pub end: usize,
+}
+
+impl Source {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(index: usize, start: usize, end: usize) -> Self {
+        Self { index, start, end }
+    }
+
+    ///
+    /// Parses the `<index>:<start>:<end>` annotation arguments.
+    ///
+    /// Returns the annotation and the number of bytes consumed. The annotation is `None` if
+    /// the code is not mapped to any source, which is denoted with negative values.
+    ///
+    pub fn parse(input: &str) -> Option<(Option<Self>, usize)> {
+        let arguments = input.trim_start();
+        let whitespace_length = input.len() - arguments.len();
+
+        let length = arguments
+            .find(|character: char| {
+                !character.is_ascii_digit() && character != ':' && character != '-'
+            })
+            .unwrap_or(arguments.len());
+        let mut values = arguments[..length]
+            .split(':')
+            .map(|value| value.parse::<isize>().ok());
+        let index = values.next()??;
+        let start = values.next()??;
+        let end = values.next()??;
+        if values.next().is_some() {
+            return None;
+        }
+
+        let source = if index < 0 || start < 0 || end < start {
+            None
+        } else {
+            Some(Self::new(index as usize, start as usize, end as usize))
+        };
+        Some((source, whitespace_length + length))
+    }
+}
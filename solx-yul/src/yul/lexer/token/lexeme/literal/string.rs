@@ -81,6 +81,65 @@ impl String {
             length,
         ))
     }
+
+    ///
+    /// Decodes the literal into raw bytes, resolving the hexadecimal digits or escape sequences.
+    ///
+    /// Returns `None` if the literal contains invalid hexadecimal digits or escapes.
+    ///
+    pub fn to_bytes(&self) -> Option<Vec<u8>> {
+        if self.is_hexadecimal {
+            let digits = self.inner.replace('_', "");
+            if digits.len() % 2 != 0 {
+                return None;
+            }
+            return (0..digits.len())
+                .step_by(2)
+                .map(|index| u8::from_str_radix(digits.get(index..index + 2)?, 16).ok())
+                .collect();
+        }
+
+        let mut bytes = Vec::with_capacity(self.inner.len());
+        let mut index = 0;
+        while index < self.inner.len() {
+            let string = &self.inner[index..];
+            if !string.starts_with('\\') {
+                let character = string.chars().next().expect("Always exists");
+                let mut buffer = [0u8; 4];
+                bytes.extend_from_slice(character.encode_utf8(&mut buffer).as_bytes());
+                index += character.len_utf8();
+                continue;
+            }
+
+            let escape = &string[1..];
+            if let Some(digits) = escape.strip_prefix('x') {
+                bytes.push(u8::from_str_radix(digits.get(..2)?, 16).ok()?);
+                index += 4;
+            } else if let Some(digits) = escape.strip_prefix('u') {
+                let codepoint = u32::from_str_radix(digits.get(..4)?, 16).ok()?;
+                let character = char::from_u32(codepoint)?;
+                let mut buffer = [0u8; 4];
+                bytes.extend_from_slice(character.encode_utf8(&mut buffer).as_bytes());
+                index += 6;
+            } else if escape.starts_with("\r\n") {
+                index += 3;
+            } else {
+                let character = escape.chars().next()?;
+                match character {
+                    't' => bytes.push(b'\t'),
+                    'n' => bytes.push(b'\n'),
+                    'r' => bytes.push(b'\r'),
+                    '\n' => {}
+                    character => {
+                        let mut buffer = [0u8; 4];
+                        bytes.extend_from_slice(character.encode_utf8(&mut buffer).as_bytes());
+                    }
+                }
+                index += 1 + character.len_utf8();
+            }
+        }
+        Some(bytes)
+    }
 }
 
 impl std::fmt::Display for String {
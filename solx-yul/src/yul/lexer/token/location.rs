@@ -2,6 +2,8 @@
 //! The lexical token location.
 //!
 
+use crate::yul::lexer::token::annotation::source::Source;
+
 ///
 /// The token location in the source code file.
 ///
@@ -11,11 +13,20 @@ pub struct Location {
     pub line: usize,
     /// The column number, starting from 1.
     pub column: usize,
+    /// The byte offset from the beginning of the source code.
+    #[serde(default)]
+    pub offset: usize,
+    /// The original source location from the closest preceding `@src` annotation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<Source>,
+    /// The identifier of `source`, unique among the distinct source ranges of the file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_id: Option<usize>,
 }
 
 impl Default for Location {
     fn default() -> Self {
-        Self { line: 1, column: 1 }
+        Self::new(1, 1)
     }
 }
 
@@ -24,7 +35,13 @@ impl Location {
     /// Creates a default location.
     ///
     pub fn new(line: usize, column: usize) -> Self {
-        Self { line, column }
+        Self {
+            line,
+            column,
+            offset: 0,
+            source: None,
+            source_id: None,
+        }
     }
 
     ///
@@ -2,6 +2,7 @@
 //! The token.
 //!
 
+pub mod annotation;
 pub mod lexeme;
 pub mod location;
 
@@ -9,7 +9,7 @@ use crate::yul::lexer::token::location::Location;
 ///
 /// The Yul IR parser error.
 ///
-#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
 pub enum Error {
     /// An invalid token received from the lexer.
     #[error("{location} Expected one of {expected:?}, found `{found}`")]
@@ -41,6 +41,14 @@ pub enum Error {
         /// The actual number of arguments.
         found: usize,
     },
+    /// An invalid `data` section literal.
+    #[error("{location} Data section `{identifier}` has an invalid literal")]
+    InvalidData {
+        /// The invalid data section location.
+        location: Location,
+        /// The data section identifier.
+        identifier: String,
+    },
     /// Invalid attributes.
     #[error("{location} Found invalid LLVM attributes: {values:?}")]
     InvalidAttributes {
@@ -50,3 +58,31 @@ pub enum Error {
         values: BTreeSet<String>,
     },
 }
+
+impl Error {
+    ///
+    /// Returns the error location.
+    ///
+    pub fn location(&self) -> Location {
+        match self {
+            Self::InvalidToken { location, .. } => *location,
+            Self::ReservedIdentifier { location, .. } => *location,
+            Self::InvalidNumberOfArguments { location, .. } => *location,
+            Self::InvalidData { location, .. } => *location,
+            Self::InvalidAttributes { location, .. } => *location,
+        }
+    }
+
+    ///
+    /// Returns the length of the erroneous source code fragment in bytes.
+    ///
+    pub fn length(&self) -> usize {
+        match self {
+            Self::InvalidToken { found, .. } => found.len(),
+            Self::ReservedIdentifier { identifier, .. } => identifier.len(),
+            Self::InvalidNumberOfArguments { identifier, .. } => identifier.len(),
+            Self::InvalidData { .. } => 1,
+            Self::InvalidAttributes { .. } => 1,
+        }
+    }
+}
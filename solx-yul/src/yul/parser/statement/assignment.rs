@@ -2,6 +2,7 @@
 //! The assignment expression statement.
 //!
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
 use crate::dependencies::Dependencies;
@@ -117,4 +118,22 @@ impl Assignment {
     pub fn accumulate_evm_dependencies(&self, dependencies: &mut Dependencies) {
         self.initializer.accumulate_evm_dependencies(dependencies);
     }
+
+    ///
+    /// Renames the calls to the functions in the initializer.
+    ///
+    /// See [`crate::yul::parser::statement::block::Block::mangle_function_names`].
+    ///
+    pub fn mangle_function_names(&mut self, scope: &BTreeMap<String, String>) {
+        self.initializer.mangle_function_names(scope);
+    }
+
+    ///
+    /// Marks the `dataoffset` and `datasize` calls in the initializer that reference data sections.
+    ///
+    /// See [`crate::yul::parser::statement::object::Object::resolve_data_references`].
+    ///
+    pub fn resolve_data_references(&mut self, data: &BTreeSet<String>) {
+        self.initializer.resolve_data_references(data);
+    }
 }
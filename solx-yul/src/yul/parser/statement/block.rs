@@ -2,10 +2,12 @@
 //! The source code block.
 //!
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
 use crate::dependencies::Dependencies;
 use crate::yul::error::Error;
+use crate::yul::lexer::token::annotation::source::Source;
 use crate::yul::lexer::token::lexeme::symbol::Symbol;
 use crate::yul::lexer::token::lexeme::Lexeme;
 use crate::yul::lexer::token::location::Location;
@@ -63,64 +65,21 @@ where
         let mut remaining = None;
 
         loop {
-            match crate::yul::parser::take_or_next(remaining.take(), lexer)? {
-                token @ Token {
-                    lexeme: Lexeme::Keyword(_),
-                    ..
-                } => {
-                    let (statement, next) = Statement::parse(lexer, Some(token))?;
+            let token = crate::yul::parser::take_or_next(remaining.take(), lexer)?;
+            if let Lexeme::Symbol(Symbol::BracketCurlyRight) = token.lexeme {
+                break;
+            }
+
+            match Self::parse_statement(lexer, token) {
+                Ok((statement, next)) => {
                     remaining = next;
                     statements.push(statement);
                 }
-                token @ Token {
-                    lexeme: Lexeme::Literal(_),
-                    ..
-                } => {
-                    statements
-                        .push(Expression::parse(lexer, Some(token)).map(Statement::Expression)?);
-                }
-                token @ Token {
-                    lexeme: Lexeme::Identifier(_),
-                    ..
-                } => match lexer.peek()? {
-                    Token {
-                        lexeme: Lexeme::Symbol(Symbol::Assignment),
-                        ..
-                    } => {
-                        statements.push(
-                            Assignment::parse(lexer, Some(token)).map(Statement::Assignment)?,
-                        );
-                    }
-                    Token {
-                        lexeme: Lexeme::Symbol(Symbol::Comma),
-                        ..
-                    } => {
-                        statements.push(
-                            Assignment::parse(lexer, Some(token)).map(Statement::Assignment)?,
-                        );
-                    }
-                    _ => {
-                        statements.push(
-                            Expression::parse(lexer, Some(token)).map(Statement::Expression)?,
-                        );
-                    }
-                },
-                token @ Token {
-                    lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
-                    ..
-                } => statements.push(Block::parse(lexer, Some(token)).map(Statement::Block)?),
-                Token {
-                    lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
-                    ..
-                } => break,
-                token => {
-                    return Err(ParserError::InvalidToken {
-                        location: token.location,
-                        expected: vec!["{keyword}", "{expression}", "{identifier}", "{", "}"],
-                        found: token.lexeme.to_string(),
-                    }
-                    .into());
+                Err(error @ Error::Parser(_)) => {
+                    Self::recover(lexer, error)?;
+                    break;
                 }
+                Err(error) => return Err(error),
             }
         }
 
@@ -130,6 +89,94 @@ where
         })
     }
 
+    ///
+    /// Parses a single statement starting with `token`.
+    ///
+    /// Returns the statement and the token that has been read ahead, if any.
+    ///
+    fn parse_statement(
+        lexer: &mut Lexer,
+        token: Token,
+    ) -> Result<(Statement<P>, Option<Token>), Error> {
+        match token {
+            token @ Token {
+                lexeme: Lexeme::Keyword(_),
+                ..
+            } => Statement::parse(lexer, Some(token)),
+            token @ Token {
+                lexeme: Lexeme::Literal(_),
+                ..
+            } => Ok((
+                Expression::parse(lexer, Some(token)).map(Statement::Expression)?,
+                None,
+            )),
+            token @ Token {
+                lexeme: Lexeme::Identifier(_),
+                ..
+            } => match lexer.peek()? {
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::Assignment),
+                    ..
+                } => Ok((
+                    Assignment::parse(lexer, Some(token)).map(Statement::Assignment)?,
+                    None,
+                )),
+                Token {
+                    lexeme: Lexeme::Symbol(Symbol::Comma),
+                    ..
+                } => Ok((
+                    Assignment::parse(lexer, Some(token)).map(Statement::Assignment)?,
+                    None,
+                )),
+                _ => Ok((
+                    Expression::parse(lexer, Some(token)).map(Statement::Expression)?,
+                    None,
+                )),
+            },
+            token @ Token {
+                lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                ..
+            } => Ok((
+                Block::parse(lexer, Some(token)).map(Statement::Block)?,
+                None,
+            )),
+            token => Err(ParserError::InvalidToken {
+                location: token.location,
+                expected: vec!["{keyword}", "{expression}", "{identifier}", "{", "}"],
+                found: token.lexeme.to_string(),
+            }
+            .into()),
+        }
+    }
+
+    ///
+    /// Records the syntax `error` in the lexer and skips the rest of the block, so the parser
+    /// can continue and report the errors that follow.
+    ///
+    /// Returns the error if the end of file is reached before the end of the block.
+    ///
+    fn recover(lexer: &mut Lexer, error: Error) -> Result<(), Error> {
+        let is_block_end_consumed = matches!(
+            error,
+            Error::Parser(ParserError::InvalidToken { ref found, .. }) if found == "}"
+        );
+        lexer.push_error(error.clone());
+        if is_block_end_consumed {
+            return Ok(());
+        }
+
+        let mut depth = 0;
+        loop {
+            match lexer.next()?.lexeme {
+                Lexeme::Symbol(Symbol::BracketCurlyLeft) => depth += 1,
+                Lexeme::Symbol(Symbol::BracketCurlyRight) if depth == 0 => return Ok(()),
+                Lexeme::Symbol(Symbol::BracketCurlyRight) => depth -= 1,
+                Lexeme::EndOfFile => return Err(error),
+                _ => {}
+            }
+        }
+    }
+
     ///
     /// Get the list of unlinked deployable libraries.
     ///
@@ -149,6 +196,90 @@ where
             statement.accumulate_evm_dependencies(dependencies);
         }
     }
+
+    ///
+    /// Get the signatures of the functions defined in the block.
+    ///
+    /// Maps each function name to its numbers of parameter and return slots.
+    ///
+    pub fn accumulate_function_signatures(
+        &self,
+        signatures: &mut BTreeMap<String, (usize, usize)>,
+    ) {
+        for statement in self.statements.iter() {
+            statement.accumulate_function_signatures(signatures);
+        }
+    }
+
+    ///
+    /// Makes the names of the functions defined in the block unique across the code.
+    ///
+    /// Yul allows functions with the same name in disjoint scopes, whereas the LLVM functions
+    /// and their debug data are keyed by name. Each repeated definition is therefore renamed
+    /// along with the calls it is visible to. `scope` maps the function names visible in the
+    /// enclosing blocks to their unique names, and `declarations` counts the definitions of
+    /// each name, including the generated ones.
+    ///
+    pub fn mangle_function_names(
+        &mut self,
+        scope: &BTreeMap<String, String>,
+        declarations: &mut BTreeMap<String, usize>,
+    ) {
+        let mut scope = scope.to_owned();
+        for statement in self.statements.iter() {
+            let Statement::FunctionDefinition(function) = statement else {
+                continue;
+            };
+
+            let count = declarations
+                .entry(function.identifier.to_owned())
+                .or_default();
+            *count += 1;
+            let count = *count;
+
+            let name = if count == 1 {
+                function.identifier.to_owned()
+            } else {
+                let name = (count - 1..)
+                    .map(|index| format!("{}_{index}", function.identifier))
+                    .find(|name| !declarations.contains_key(name))
+                    .expect("Always exists");
+                declarations.insert(name.clone(), 1);
+                name
+            };
+            scope.insert(function.identifier.to_owned(), name);
+        }
+
+        for statement in self.statements.iter_mut() {
+            statement.mangle_function_names(&scope, declarations);
+        }
+    }
+
+    ///
+    /// Marks the `dataoffset` and `datasize` calls in the block that reference data sections.
+    ///
+    /// See [`crate::yul::parser::statement::object::Object::resolve_data_references`].
+    ///
+    pub fn resolve_data_references(&mut self, data: &BTreeSet<String>) {
+        for statement in self.statements.iter_mut() {
+            statement.resolve_data_references(data);
+        }
+    }
+
+    ///
+    /// Get the original source ranges of the statements in the block.
+    ///
+    /// Maps each range identifier assigned by the lexer to the range.
+    ///
+    pub fn accumulate_source_ranges(&self, ranges: &mut BTreeMap<usize, Source>) {
+        for statement in self.statements.iter() {
+            let location = statement.location();
+            if let (Some(source_id), Some(source)) = (location.source_id, location.source) {
+                ranges.insert(source_id, source);
+            }
+            statement.accumulate_source_ranges(ranges);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -233,4 +364,47 @@ object "Test" {
             .into())
         );
     }
+
+    #[test]
+    fn error_recovery() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            let x := (
+        }
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                :=
+            }
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input);
+        let result = Object::<DefaultDialect>::parse(
+            &mut lexer,
+            None,
+            era_compiler_common::CodeSegment::Deploy,
+        );
+        let errors = lexer.take_errors();
+        assert_eq!(result, Err(errors[0].clone()));
+        assert_eq!(
+            errors
+                .iter()
+                .map(|error| error.location())
+                .collect::<Vec<Location>>(),
+            vec![Location::new(5, 22), Location::new(14, 17)]
+        );
+        assert_eq!(
+            errors[0].location().offset,
+            input.find('(').expect("Always exists")
+        );
+    }
 }
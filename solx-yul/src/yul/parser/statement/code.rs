@@ -2,10 +2,12 @@
 //! The Yul code.
 //!
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
 use crate::dependencies::Dependencies;
 use crate::yul::error::Error;
+use crate::yul::lexer::token::annotation::source::Source;
 use crate::yul::lexer::token::lexeme::Lexeme;
 use crate::yul::lexer::token::location::Location;
 use crate::yul::lexer::token::Token;
@@ -73,6 +75,50 @@ where
     pub fn accumulate_evm_dependencies(&self, dependencies: &mut Dependencies) {
         self.block.accumulate_evm_dependencies(dependencies);
     }
+
+    ///
+    /// Get the signatures of the functions defined in the code.
+    ///
+    /// Maps each function name to its numbers of parameter and return slots.
+    ///
+    pub fn accumulate_function_signatures(
+        &self,
+        signatures: &mut BTreeMap<String, (usize, usize)>,
+    ) {
+        self.block.accumulate_function_signatures(signatures);
+    }
+
+    ///
+    /// Makes the names of the functions defined in the code unique.
+    ///
+    /// See [`Block::mangle_function_names`].
+    ///
+    pub fn mangle_function_names(&mut self) {
+        let mut signatures = BTreeMap::new();
+        self.accumulate_function_signatures(&mut signatures);
+        let mut declarations: BTreeMap<String, usize> =
+            signatures.into_keys().map(|name| (name, 0)).collect();
+        self.block
+            .mangle_function_names(&BTreeMap::new(), &mut declarations);
+    }
+
+    ///
+    /// Marks the `dataoffset` and `datasize` calls in the code that reference data sections.
+    ///
+    /// See [`crate::yul::parser::statement::object::Object::resolve_data_references`].
+    ///
+    pub fn resolve_data_references(&mut self, data: &BTreeSet<String>) {
+        self.block.resolve_data_references(data);
+    }
+
+    ///
+    /// Get the original source ranges of the statements in the code.
+    ///
+    /// Maps each range identifier to the range.
+    ///
+    pub fn accumulate_source_ranges(&self, ranges: &mut BTreeMap<usize, Source>) {
+        self.block.accumulate_source_ranges(ranges);
+    }
 }
 
 #[cfg(test)]
@@ -4,6 +4,7 @@
 
 pub mod name;
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
 use crate::dependencies::Dependencies;
@@ -31,6 +32,10 @@ pub struct FunctionCall {
     pub name: Name,
     /// The function arguments expression list.
     pub arguments: Vec<Expression>,
+    /// Whether the `dataoffset` or `datasize` call references a data section rather than an
+    /// object. Resolved once the enclosing object is parsed.
+    #[serde(default)]
+    pub is_data_reference: bool,
 }
 
 impl FunctionCall {
@@ -91,9 +96,26 @@ impl FunctionCall {
             location,
             name,
             arguments,
+            is_data_reference: false,
         })
     }
 
+    ///
+    /// Returns the identifier of the object or data section at `path` referenced by `dataoffset`
+    /// or `datasize`, as the dependency is named in the assembly.
+    ///
+    /// Objects are identified by the last path segment, whereas data sections keep the full
+    /// path, as it is the only way to tell apart the sections with the same name in different
+    /// objects.
+    ///
+    pub fn dependency_identifier<'a>(&self, path: &'a str) -> &'a str {
+        if self.is_data_reference {
+            path
+        } else {
+            path.split('.').next_back().expect("Always exists")
+        }
+    }
+
     ///
     /// Get the list of unlinked deployable libraries.
     ///
@@ -124,11 +146,15 @@ impl FunctionCall {
         match self.name {
             Name::DataSize | Name::DataOffset => {
                 if let Expression::Literal(Literal {
+                    location,
                     inner: LexicalLiteral::String(identifier),
                     ..
                 }) = self.arguments.first().expect("Always exists")
                 {
-                    let object_name = identifier.inner.split(".").last().expect("Always exists");
+                    dependencies
+                        .references
+                        .push((identifier.inner.to_owned(), *location));
+                    let object_name = self.dependency_identifier(identifier.inner.as_str());
                     let is_runtime_code = dependencies.identifier.as_str()
                         == object_name
                             .strip_suffix("_deployed")
@@ -144,4 +170,41 @@ impl FunctionCall {
             argument.accumulate_evm_dependencies(dependencies);
         }
     }
+
+    ///
+    /// Renames the called function and the calls in the arguments.
+    ///
+    /// See [`crate::yul::parser::statement::block::Block::mangle_function_names`].
+    ///
+    pub fn mangle_function_names(&mut self, scope: &BTreeMap<String, String>) {
+        if let Name::UserDefined(ref mut name) = self.name {
+            if let Some(unique_name) = scope.get(name.as_str()) {
+                *name = unique_name.to_owned();
+            }
+        }
+        for argument in self.arguments.iter_mut() {
+            argument.mangle_function_names(scope);
+        }
+    }
+
+    ///
+    /// Marks the `dataoffset` and `datasize` calls among the call and its arguments that reference data sections.
+    ///
+    /// See [`crate::yul::parser::statement::object::Object::resolve_data_references`].
+    ///
+    pub fn resolve_data_references(&mut self, data: &BTreeSet<String>) {
+        if let Name::DataOffset | Name::DataSize = self.name {
+            if let Some(Expression::Literal(Literal {
+                inner: LexicalLiteral::String(path),
+                ..
+            })) = self.arguments.first()
+            {
+                self.is_data_reference = data.contains(path.inner.as_str());
+            }
+            return;
+        }
+        for argument in self.arguments.iter_mut() {
+            argument.resolve_data_references(data);
+        }
+    }
 }
@@ -226,6 +226,20 @@ pub enum Name {
     SelfDestruct,
 }
 
+impl Name {
+    ///
+    /// Returns the EVM version that introduced the builtin, if it is not available in all of them.
+    ///
+    pub fn evm_version(&self) -> Option<era_compiler_common::EVMVersion> {
+        match self {
+            Self::UserDefined(_) | Self::Verbatim { .. } => None,
+            name => {
+                crate::evm_version::required(crate::yul::printer::name_identifier(name).as_str())
+            }
+        }
+    }
+}
+
 impl From<&str> for Name {
     fn from(input: &str) -> Self {
         match input {
@@ -5,6 +5,7 @@
 pub mod function_call;
 pub mod literal;
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
 use crate::dependencies::Dependencies;
@@ -101,6 +102,28 @@ impl Expression {
         }
     }
 
+    ///
+    /// Renames the calls to the functions in the expression.
+    ///
+    /// See [`crate::yul::parser::statement::block::Block::mangle_function_names`].
+    ///
+    pub fn mangle_function_names(&mut self, scope: &BTreeMap<String, String>) {
+        if let Self::FunctionCall(inner) = self {
+            inner.mangle_function_names(scope);
+        }
+    }
+
+    ///
+    /// Marks the `dataoffset` and `datasize` calls in the expression that reference data sections.
+    ///
+    /// See [`crate::yul::parser::statement::object::Object::resolve_data_references`].
+    ///
+    pub fn resolve_data_references(&mut self, data: &BTreeSet<String>) {
+        if let Self::FunctionCall(inner) = self {
+            inner.resolve_data_references(data);
+        }
+    }
+
     ///
     /// Returns the statement location.
     ///
@@ -2,10 +2,12 @@
 //! The for-loop statement.
 //!
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
 use crate::dependencies::Dependencies;
 use crate::yul::error::Error;
+use crate::yul::lexer::token::annotation::source::Source;
 use crate::yul::lexer::token::location::Location;
 use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
@@ -82,4 +84,57 @@ where
         self.finalizer.accumulate_evm_dependencies(dependencies);
         self.body.accumulate_evm_dependencies(dependencies);
     }
+
+    ///
+    /// Get the signatures of the functions defined in the loop.
+    ///
+    /// Maps each function name to its numbers of parameter and return slots.
+    ///
+    pub fn accumulate_function_signatures(
+        &self,
+        signatures: &mut BTreeMap<String, (usize, usize)>,
+    ) {
+        self.initializer.accumulate_function_signatures(signatures);
+        self.finalizer.accumulate_function_signatures(signatures);
+        self.body.accumulate_function_signatures(signatures);
+    }
+
+    ///
+    /// Renames the functions defined in the loop and the calls to them.
+    ///
+    /// See [`Block::mangle_function_names`].
+    ///
+    pub fn mangle_function_names(
+        &mut self,
+        scope: &BTreeMap<String, String>,
+        declarations: &mut BTreeMap<String, usize>,
+    ) {
+        self.initializer.mangle_function_names(scope, declarations);
+        self.condition.mangle_function_names(scope);
+        self.finalizer.mangle_function_names(scope, declarations);
+        self.body.mangle_function_names(scope, declarations);
+    }
+
+    ///
+    /// Marks the `dataoffset` and `datasize` calls in the loop that reference data sections.
+    ///
+    /// See [`crate::yul::parser::statement::object::Object::resolve_data_references`].
+    ///
+    pub fn resolve_data_references(&mut self, data: &BTreeSet<String>) {
+        self.initializer.resolve_data_references(data);
+        self.condition.resolve_data_references(data);
+        self.finalizer.resolve_data_references(data);
+        self.body.resolve_data_references(data);
+    }
+
+    ///
+    /// Get the original source ranges of the statements in the loop.
+    ///
+    /// Maps each range identifier to the range.
+    ///
+    pub fn accumulate_source_ranges(&self, ranges: &mut BTreeMap<usize, Source>) {
+        self.initializer.accumulate_source_ranges(ranges);
+        self.finalizer.accumulate_source_ranges(ranges);
+        self.body.accumulate_source_ranges(ranges);
+    }
 }
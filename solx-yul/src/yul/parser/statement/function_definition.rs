@@ -2,6 +2,7 @@
 //! The function definition statement.
 //!
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
 use serde::Deserialize;
@@ -9,6 +10,7 @@ use serde::Serialize;
 
 use crate::dependencies::Dependencies;
 use crate::yul::error::Error;
+use crate::yul::lexer::token::annotation::source::Source;
 use crate::yul::lexer::token::lexeme::symbol::Symbol;
 use crate::yul::lexer::token::lexeme::Lexeme;
 use crate::yul::lexer::token::location::Location;
@@ -166,6 +168,56 @@ where
     pub fn accumulate_evm_dependencies(&self, dependencies: &mut Dependencies) {
         self.body.accumulate_evm_dependencies(dependencies);
     }
+
+    ///
+    /// Get the signatures of the functions defined in the function, including the function itself.
+    ///
+    /// Maps each function name to its numbers of parameter and return slots.
+    ///
+    pub fn accumulate_function_signatures(
+        &self,
+        signatures: &mut BTreeMap<String, (usize, usize)>,
+    ) {
+        signatures.insert(
+            self.identifier.to_owned(),
+            (self.arguments.len(), self.result.len()),
+        );
+        self.body.accumulate_function_signatures(signatures);
+    }
+
+    ///
+    /// Renames the function and the functions defined in it, along with the calls to them.
+    ///
+    /// See [`Block::mangle_function_names`].
+    ///
+    pub fn mangle_function_names(
+        &mut self,
+        scope: &BTreeMap<String, String>,
+        declarations: &mut BTreeMap<String, usize>,
+    ) {
+        if let Some(name) = scope.get(self.identifier.as_str()) {
+            self.identifier = name.to_owned();
+        }
+        self.body.mangle_function_names(scope, declarations);
+    }
+
+    ///
+    /// Marks the `dataoffset` and `datasize` calls in the function that reference data sections.
+    ///
+    /// See [`crate::yul::parser::statement::object::Object::resolve_data_references`].
+    ///
+    pub fn resolve_data_references(&mut self, data: &BTreeSet<String>) {
+        self.body.resolve_data_references(data);
+    }
+
+    ///
+    /// Get the original source ranges of the statements in the function.
+    ///
+    /// Maps each range identifier to the range.
+    ///
+    pub fn accumulate_source_ranges(&self, ranges: &mut BTreeMap<usize, Source>) {
+        self.body.accumulate_source_ranges(ranges);
+    }
 }
 
 ///
@@ -2,10 +2,12 @@
 //! The if-conditional statement.
 //!
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
 use crate::dependencies::Dependencies;
 use crate::yul::error::Error;
+use crate::yul::lexer::token::annotation::source::Source;
 use crate::yul::lexer::token::location::Location;
 use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
@@ -68,4 +70,49 @@ where
         self.condition.accumulate_evm_dependencies(dependencies);
         self.block.accumulate_evm_dependencies(dependencies);
     }
+
+    ///
+    /// Get the signatures of the functions defined in the conditional.
+    ///
+    /// Maps each function name to its numbers of parameter and return slots.
+    ///
+    pub fn accumulate_function_signatures(
+        &self,
+        signatures: &mut BTreeMap<String, (usize, usize)>,
+    ) {
+        self.block.accumulate_function_signatures(signatures);
+    }
+
+    ///
+    /// Renames the functions defined in the conditional and the calls to them.
+    ///
+    /// See [`Block::mangle_function_names`].
+    ///
+    pub fn mangle_function_names(
+        &mut self,
+        scope: &BTreeMap<String, String>,
+        declarations: &mut BTreeMap<String, usize>,
+    ) {
+        self.condition.mangle_function_names(scope);
+        self.block.mangle_function_names(scope, declarations);
+    }
+
+    ///
+    /// Marks the `dataoffset` and `datasize` calls in the conditional that reference data sections.
+    ///
+    /// See [`crate::yul::parser::statement::object::Object::resolve_data_references`].
+    ///
+    pub fn resolve_data_references(&mut self, data: &BTreeSet<String>) {
+        self.condition.resolve_data_references(data);
+        self.block.resolve_data_references(data);
+    }
+
+    ///
+    /// Get the original source ranges of the statements in the conditional.
+    ///
+    /// Maps each range identifier to the range.
+    ///
+    pub fn accumulate_source_ranges(&self, ranges: &mut BTreeMap<usize, Source>) {
+        self.block.accumulate_source_ranges(ranges);
+    }
 }
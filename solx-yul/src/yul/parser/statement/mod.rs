@@ -13,10 +13,12 @@ pub mod object;
 pub mod switch;
 pub mod variable_declaration;
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
 use crate::dependencies::Dependencies;
 use crate::yul::error::Error;
+use crate::yul::lexer::token::annotation::source::Source;
 use crate::yul::lexer::token::lexeme::keyword::Keyword;
 use crate::yul::lexer::token::lexeme::Lexeme;
 use crate::yul::lexer::token::location::Location;
@@ -203,6 +205,99 @@ where
         }
     }
 
+    ///
+    /// Get the signatures of the functions defined in the statement.
+    ///
+    /// Maps each function name to its numbers of parameter and return slots.
+    ///
+    pub fn accumulate_function_signatures(
+        &self,
+        signatures: &mut BTreeMap<String, (usize, usize)>,
+    ) {
+        match self {
+            Self::Code(inner) => inner.accumulate_function_signatures(signatures),
+            Self::Block(inner) => inner.accumulate_function_signatures(signatures),
+            Self::FunctionDefinition(inner) => inner.accumulate_function_signatures(signatures),
+            Self::IfConditional(inner) => inner.accumulate_function_signatures(signatures),
+            Self::Switch(inner) => inner.accumulate_function_signatures(signatures),
+            Self::ForLoop(inner) => inner.accumulate_function_signatures(signatures),
+            Self::Object(_)
+            | Self::Expression(_)
+            | Self::VariableDeclaration(_)
+            | Self::Assignment(_)
+            | Self::Continue(_)
+            | Self::Break(_)
+            | Self::Leave(_) => {}
+        }
+    }
+
+    ///
+    /// Renames the functions defined in the statement and the calls to them.
+    ///
+    /// See [`Block::mangle_function_names`].
+    ///
+    pub fn mangle_function_names(
+        &mut self,
+        scope: &BTreeMap<String, String>,
+        declarations: &mut BTreeMap<String, usize>,
+    ) {
+        match self {
+            Self::Code(inner) => inner.block.mangle_function_names(scope, declarations),
+            Self::Block(inner) => inner.mangle_function_names(scope, declarations),
+            Self::Expression(inner) => inner.mangle_function_names(scope),
+            Self::FunctionDefinition(inner) => inner.mangle_function_names(scope, declarations),
+            Self::VariableDeclaration(inner) => inner.mangle_function_names(scope),
+            Self::Assignment(inner) => inner.mangle_function_names(scope),
+            Self::IfConditional(inner) => inner.mangle_function_names(scope, declarations),
+            Self::Switch(inner) => inner.mangle_function_names(scope, declarations),
+            Self::ForLoop(inner) => inner.mangle_function_names(scope, declarations),
+            Self::Object(_) | Self::Continue(_) | Self::Break(_) | Self::Leave(_) => {}
+        }
+    }
+
+    ///
+    /// Marks the `dataoffset` and `datasize` calls in the statement that reference data sections.
+    ///
+    /// See [`crate::yul::parser::statement::object::Object::resolve_data_references`].
+    ///
+    pub fn resolve_data_references(&mut self, data: &BTreeSet<String>) {
+        match self {
+            Self::Code(inner) => inner.resolve_data_references(data),
+            Self::Block(inner) => inner.resolve_data_references(data),
+            Self::Expression(inner) => inner.resolve_data_references(data),
+            Self::FunctionDefinition(inner) => inner.resolve_data_references(data),
+            Self::VariableDeclaration(inner) => inner.resolve_data_references(data),
+            Self::Assignment(inner) => inner.resolve_data_references(data),
+            Self::IfConditional(inner) => inner.resolve_data_references(data),
+            Self::Switch(inner) => inner.resolve_data_references(data),
+            Self::ForLoop(inner) => inner.resolve_data_references(data),
+            Self::Object(_) | Self::Continue(_) | Self::Break(_) | Self::Leave(_) => {}
+        }
+    }
+
+    ///
+    /// Get the original source ranges of the statements in the statement.
+    ///
+    /// Maps each range identifier to the range.
+    ///
+    pub fn accumulate_source_ranges(&self, ranges: &mut BTreeMap<usize, Source>) {
+        match self {
+            Self::Code(inner) => inner.accumulate_source_ranges(ranges),
+            Self::Block(inner) => inner.accumulate_source_ranges(ranges),
+            Self::FunctionDefinition(inner) => inner.accumulate_source_ranges(ranges),
+            Self::IfConditional(inner) => inner.accumulate_source_ranges(ranges),
+            Self::Switch(inner) => inner.accumulate_source_ranges(ranges),
+            Self::ForLoop(inner) => inner.accumulate_source_ranges(ranges),
+            Self::Object(_)
+            | Self::Expression(_)
+            | Self::VariableDeclaration(_)
+            | Self::Assignment(_)
+            | Self::Continue(_)
+            | Self::Break(_)
+            | Self::Leave(_) => {}
+        }
+    }
+
     ///
     /// Returns the statement location.
     ///
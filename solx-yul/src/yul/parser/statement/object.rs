@@ -2,11 +2,13 @@
 //! The Yul object.
 //!
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 use std::collections::HashSet;
 
 use crate::dependencies::Dependencies;
 use crate::yul::error::Error;
+use crate::yul::lexer::token::annotation::source::Source;
 use crate::yul::lexer::token::lexeme::literal::Literal;
 use crate::yul::lexer::token::lexeme::symbol::Symbol;
 use crate::yul::lexer::token::lexeme::Lexeme;
@@ -38,6 +40,13 @@ where
     /// objects are duplicates of the upper-level objects describing the dependencies, so only
     /// their identifiers are preserved. The identifiers are used to address upper-level objects.
     pub factory_dependencies: HashSet<String>,
+    /// The nested deploy objects. In Yul mode they are the only definitions of the objects they
+    /// describe, so they are preserved in full to be compiled alongside the upper-level object.
+    pub nested_objects: Vec<Self>,
+    /// The data sections, keyed by their identifiers.
+    pub data: BTreeMap<String, Vec<u8>>,
+    /// The original source paths from the `@use-src` annotation, keyed by their indexes.
+    pub source_paths: BTreeMap<usize, String>,
 }
 
 impl<P> Object<P>
@@ -47,10 +56,68 @@ where
     ///
     /// The element parser.
     ///
+    /// The parser recovers from syntax errors in code blocks and keeps going. If there have been
+    /// any in this object, the first one is returned, and the full list can be taken from the lexer.
+    ///
     pub fn parse(
         lexer: &mut Lexer,
         initial: Option<Token>,
         code_segment: era_compiler_common::CodeSegment,
+    ) -> Result<Self, Error> {
+        let errors_count = lexer.errors().len();
+        let result = Self::parse_object(lexer, initial, code_segment);
+        match lexer.errors().get(errors_count) {
+            Some(error) => Err(error.to_owned()),
+            None => result,
+        }
+    }
+
+    ///
+    /// Parses all top-level objects until the end of file.
+    ///
+    /// The parser keeps going after objects with recovered syntax errors, so the full list can be
+    /// taken from the lexer, and the first one is returned.
+    ///
+    pub fn parse_all(lexer: &mut Lexer) -> Result<Vec<Self>, Error> {
+        let mut objects = Vec::with_capacity(1);
+        loop {
+            if let Token {
+                lexeme: Lexeme::EndOfFile,
+                ..
+            } = lexer.peek()?
+            {
+                break;
+            }
+
+            match Self::parse(lexer, None, era_compiler_common::CodeSegment::Deploy) {
+                Ok(object) => objects.push(object),
+                Err(error) if lexer.errors().contains(&error) => continue,
+                Err(error) => return Err(error),
+            }
+        }
+
+        if let Some(error) = lexer.errors().first() {
+            return Err(error.to_owned());
+        }
+        if objects.is_empty() {
+            let token = lexer.next()?;
+            return Err(ParserError::InvalidToken {
+                location: token.location,
+                expected: vec!["object"],
+                found: token.lexeme.to_string(),
+            }
+            .into());
+        }
+        Ok(objects)
+    }
+
+    ///
+    /// Parses the object without checking the recovered errors.
+    ///
+    fn parse_object(
+        lexer: &mut Lexer,
+        initial: Option<Token>,
+        code_segment: era_compiler_common::CodeSegment,
     ) -> Result<Self, Error> {
         let token = crate::yul::parser::take_or_next(initial, lexer)?;
 
@@ -70,6 +137,8 @@ where
             }
         };
 
+        let source_paths = lexer.take_use_source().unwrap_or_default();
+
         let identifier = match lexer.next()? {
             Token {
                 lexeme: Lexeme::Literal(Literal::String(literal)),
@@ -100,9 +169,12 @@ where
             }
         }
 
-        let code = Code::parse(lexer, None)?;
+        let mut code = Code::parse(lexer, None)?;
+        code.mangle_function_names();
         let mut inner_object = None;
         let mut factory_dependencies = HashSet::new();
+        let mut nested_objects = Vec::new();
+        let mut data = BTreeMap::new();
 
         if let era_compiler_common::CodeSegment::Deploy = code_segment {
             inner_object = match lexer.peek()? {
@@ -111,25 +183,16 @@ where
                     ..
                 } if identifier.inner.as_str() == "object" => {
                     let mut object =
-                        Self::parse(lexer, None, era_compiler_common::CodeSegment::Runtime)?;
+                        Self::parse_object(lexer, None, era_compiler_common::CodeSegment::Runtime)?;
+                    if object.source_paths.is_empty() {
+                        object.source_paths = source_paths.clone();
+                    }
 
                     factory_dependencies.extend(object.factory_dependencies.drain());
                     Some(Box::new(object))
                 }
                 _ => None,
             };
-
-            if let Token {
-                lexeme: Lexeme::Identifier(identifier),
-                ..
-            } = lexer.peek()?
-            {
-                if identifier.inner.as_str() == "data" {
-                    let _data = lexer.next()?;
-                    let _identifier = lexer.next()?;
-                    let _metadata = lexer.next()?;
-                }
-            };
         }
 
         loop {
@@ -142,24 +205,25 @@ where
                     lexeme: Lexeme::Identifier(ref identifier),
                     ..
                 } if identifier.inner.as_str() == "object" => {
-                    let dependency = Self::parse(
+                    let dependency = Self::parse_object(
                         lexer,
                         Some(token.to_owned()),
                         era_compiler_common::CodeSegment::Deploy,
                     )?;
-                    factory_dependencies.insert(dependency.identifier);
+                    factory_dependencies.insert(dependency.identifier.clone());
+                    nested_objects.push(dependency);
                 }
                 Token {
                     lexeme: Lexeme::Identifier(identifier),
                     ..
                 } if identifier.inner.as_str() == "data" => {
-                    let _identifier = lexer.next()?;
-                    let _metadata = lexer.next()?;
+                    let (identifier, bytes) = Self::parse_data(lexer)?;
+                    data.insert(identifier, bytes);
                 }
                 token => {
                     return Err(ParserError::InvalidToken {
                         location: token.location,
-                        expected: vec!["object", "}"],
+                        expected: vec!["object", "data", "}"],
                         found: token.lexeme.to_string(),
                     }
                     .into());
@@ -167,13 +231,74 @@ where
             }
         }
 
-        Ok(Self {
+        let mut object = Self {
             location,
             identifier,
             code,
             inner_object,
             factory_dependencies,
-        })
+            nested_objects,
+            data,
+            source_paths,
+        };
+        object.resolve_data_references();
+        Ok(object)
+    }
+
+    ///
+    /// Marks the `dataoffset` and `datasize` calls in the object code that reference data
+    /// sections, so that they are told apart from the object references.
+    ///
+    /// The object code is parsed before the data sections, so it is done once the object is
+    /// complete. The inner objects are resolved when they are parsed.
+    ///
+    pub fn resolve_data_references(&mut self) {
+        let data = self
+            .get_evm_data()
+            .into_keys()
+            .collect::<BTreeSet<String>>();
+        self.code.resolve_data_references(&data);
+    }
+
+    ///
+    /// Parses a `data` section after its keyword, returning the identifier and decoded contents.
+    ///
+    fn parse_data(lexer: &mut Lexer) -> Result<(String, Vec<u8>), Error> {
+        let identifier = match lexer.next()? {
+            Token {
+                lexeme: Lexeme::Literal(Literal::String(literal)),
+                ..
+            } => literal.inner,
+            token => {
+                return Err(ParserError::InvalidToken {
+                    location: token.location,
+                    expected: vec!["{string}"],
+                    found: token.lexeme.to_string(),
+                }
+                .into());
+            }
+        };
+
+        match lexer.next()? {
+            Token {
+                lexeme: Lexeme::Literal(Literal::String(literal)),
+                location,
+                ..
+            } => match literal.to_bytes() {
+                Some(bytes) => Ok((identifier, bytes)),
+                None => Err(ParserError::InvalidData {
+                    location,
+                    identifier,
+                }
+                .into()),
+            },
+            token => Err(ParserError::InvalidToken {
+                location: token.location,
+                expected: vec!["{string}"],
+                found: token.lexeme.to_string(),
+            }
+            .into()),
+        }
     }
 
     ///
@@ -195,24 +320,90 @@ where
         self.code.accumulate_evm_dependencies(&mut dependencies);
 
         if let Some(runtime_code) = runtime_code {
-            if !dependencies.inner.contains(&runtime_code.identifier) {
-                dependencies
-                    .inner
-                    .insert(0, runtime_code.identifier.to_owned());
-            }
+            dependencies
+                .inner
+                .retain(|dependency| dependency != &runtime_code.identifier);
+            dependencies
+                .inner
+                .insert(0, runtime_code.identifier.to_owned());
         }
 
         dependencies
     }
+
+    ///
+    /// Get the data sections, keyed by the identifiers they are referenced by in EVM dependencies.
+    ///
+    /// The data sections are keyed by their full paths, which are either the section names or
+    /// the paths through this object or the objects inside it.
+    ///
+    pub fn get_evm_data(&self) -> BTreeMap<String, Vec<u8>> {
+        let mut data = BTreeMap::new();
+        self.accumulate_evm_data(None, &mut data);
+        self.accumulate_evm_data(Some(self.identifier.as_str()), &mut data);
+        data
+    }
+
+    ///
+    /// Collects the data sections of the object and the objects inside it, with their paths
+    /// prefixed by `prefix`.
+    ///
+    fn accumulate_evm_data(&self, prefix: Option<&str>, data: &mut BTreeMap<String, Vec<u8>>) {
+        let path = |identifier: &str| match prefix {
+            Some(prefix) => format!("{prefix}.{identifier}"),
+            None => identifier.to_owned(),
+        };
+
+        for (identifier, bytes) in self.data.iter() {
+            data.insert(path(identifier.as_str()), bytes.to_owned());
+        }
+        for object in self
+            .inner_object
+            .as_deref()
+            .into_iter()
+            .chain(self.nested_objects.iter())
+        {
+            object.accumulate_evm_data(Some(path(object.identifier.as_str()).as_str()), data);
+        }
+    }
+
+    ///
+    /// Get the signatures of the functions defined in the object code.
+    ///
+    /// Maps each function name to its numbers of parameter and return slots.
+    /// The inner object functions are not included, as they belong to another code segment.
+    ///
+    pub fn get_function_signatures(&self) -> BTreeMap<String, (usize, usize)> {
+        let mut signatures = BTreeMap::new();
+        self.code.accumulate_function_signatures(&mut signatures);
+        signatures
+    }
+
+    ///
+    /// Get the original source ranges of the statements in the object code.
+    ///
+    /// Maps each range identifier assigned by the lexer to the range, as the LLVM debug locations
+    /// only retain the identifier. The inner object statements are not included.
+    ///
+    pub fn get_source_ranges(&self) -> BTreeMap<usize, Source> {
+        let mut ranges = BTreeMap::new();
+        self.code.accumulate_source_ranges(&mut ranges);
+        ranges
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeSet;
+
     use crate::yul::lexer::token::location::Location;
     use crate::yul::lexer::Lexer;
     use crate::yul::parser::dialect::DefaultDialect;
     use crate::yul::parser::error::Error;
+    use crate::yul::parser::statement::expression::function_call::name::Name;
+    use crate::yul::parser::statement::expression::Expression;
     use crate::yul::parser::statement::object::Object;
+    use crate::yul::parser::statement::Statement;
 
     #[test]
     fn unrelated_object_names() {
@@ -242,6 +433,101 @@ object "Init" {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn function_signatures() {
+        let input = r#"
+object "Test" {
+    code {
+        function fun_f_12(a, b) -> c {
+            function nested() {}
+            if a {
+                c := b
+            }
+        }
+        switch calldatasize()
+        case 0 {
+            function in_case(x) -> y, z {}
+        }
+        default {}
+    }
+    object "Test_deployed" {
+        code {
+            function runtime_only() {}
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input);
+        let object = Object::<DefaultDialect>::parse(
+            &mut lexer,
+            None,
+            era_compiler_common::CodeSegment::Deploy,
+        )
+        .expect("Always valid");
+
+        let signatures = object.get_function_signatures();
+        assert_eq!(
+            signatures.into_iter().collect::<Vec<_>>(),
+            vec![
+                ("fun_f_12".to_owned(), (2, 1)),
+                ("in_case".to_owned(), (1, 2)),
+                ("nested".to_owned(), (0, 0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn function_signatures_same_names() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            function f(a) -> b {
+                b := add(a, 1)
+            }
+            sstore(0, f(1))
+        }
+        {
+            function f(a, b) {}
+            function f_1() {}
+            f(f_1(), 2)
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input);
+        let object = Object::<DefaultDialect>::parse(
+            &mut lexer,
+            None,
+            era_compiler_common::CodeSegment::Deploy,
+        )
+        .expect("Always valid");
+
+        let signatures = object.get_function_signatures();
+        assert_eq!(
+            signatures.into_iter().collect::<Vec<_>>(),
+            vec![
+                ("f".to_owned(), (1, 1)),
+                ("f_1".to_owned(), (0, 0)),
+                ("f_2".to_owned(), (2, 0)),
+            ]
+        );
+
+        let Statement::Block(ref block) = object.code.block.statements[1] else {
+            panic!("Expected a block");
+        };
+        let Statement::Expression(Expression::FunctionCall(ref call)) = block.statements[2] else {
+            panic!("Expected a function call");
+        };
+        assert_eq!(call.name, Name::UserDefined("f_2".to_owned()));
+        assert!(matches!(
+            call.arguments.first(),
+            Some(Expression::FunctionCall(argument)) if argument.name == Name::UserDefined("f_1".to_owned())
+        ));
+    }
+
     #[test]
     fn error_invalid_token_object() {
         let input = r#"
@@ -379,10 +665,247 @@ object "Test" {
             result,
             Err(Error::InvalidToken {
                 location: Location::new(8, 5),
-                expected: vec!["object", "}"],
+                expected: vec!["object", "data", "}"],
                 found: "class".to_owned(),
             }
             .into())
         );
     }
+
+    #[test]
+    fn data_sections() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            datacopy(0, dataoffset("table"), datasize("table"))
+            return(0, datasize("table"))
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                return(0, 0)
+            }
+        }
+        data "message" "ab\x63\n"
+    }
+    data "table" hex"0102_ff"
+    data ".metadata" hex""
+}
+    "#;
+
+        let mut lexer = Lexer::new(input);
+        let object = Object::<DefaultDialect>::parse(
+            &mut lexer,
+            None,
+            era_compiler_common::CodeSegment::Deploy,
+        )
+        .expect("Always valid");
+
+        assert_eq!(
+            object.data.into_iter().collect::<Vec<_>>(),
+            vec![
+                (".metadata".to_owned(), vec![]),
+                ("table".to_owned(), vec![0x01, 0x02, 0xff]),
+            ]
+        );
+        assert_eq!(
+            object
+                .inner_object
+                .expect("Always exists")
+                .data
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec![("message".to_owned(), b"abc\n".to_vec())]
+        );
+    }
+
+    #[test]
+    fn data_sections_full_paths() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            datacopy(0, dataoffset("a.data"), datasize("Nested.a.data"))
+            return(0, datasize("Nested"))
+        }
+    }
+    object "Nested" {
+        code {
+            {
+                return(0, 0)
+            }
+        }
+        data "a.data" hex"02"
+    }
+    data "a.data" hex"01"
+}
+    "#;
+
+        let mut lexer = Lexer::new(input);
+        let object = Object::<DefaultDialect>::parse(
+            &mut lexer,
+            None,
+            era_compiler_common::CodeSegment::Deploy,
+        )
+        .expect("Always valid");
+
+        let data = object.get_evm_data();
+        assert_eq!(data.get("a.data"), Some(&vec![0x01]));
+        assert_eq!(data.get("Test.a.data"), Some(&vec![0x01]));
+        assert_eq!(data.get("Nested.a.data"), Some(&vec![0x02]));
+        assert_eq!(
+            object
+                .get_evm_dependencies(None)
+                .inner
+                .into_iter()
+                .collect::<BTreeSet<String>>(),
+            BTreeSet::from([
+                "a.data".to_owned(),
+                "Nested.a.data".to_owned(),
+                "Nested".to_owned(),
+            ])
+        );
+    }
+
+    #[test]
+    fn nested_objects() {
+        let input = r#"
+object "Factory" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Factory_deployed" {
+        code {
+            {
+                let size := datasize("Child")
+                datacopy(0, dataoffset("Child"), size)
+                pop(create(0, 0, size))
+            }
+        }
+        object "Child" {
+            code {
+                {
+                    return(0, 0)
+                }
+            }
+            object "Child_deployed" {
+                code {
+                    {
+                        return(0, 0)
+                    }
+                }
+            }
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input);
+        let object = Object::<DefaultDialect>::parse(
+            &mut lexer,
+            None,
+            era_compiler_common::CodeSegment::Deploy,
+        )
+        .expect("Always valid");
+
+        let runtime_object = object.inner_object.expect("Always exists");
+        assert!(object.factory_dependencies.contains("Child"));
+        assert_eq!(runtime_object.nested_objects.len(), 1);
+        assert_eq!(
+            runtime_object.nested_objects[0].identifier.as_str(),
+            "Child"
+        );
+        assert!(runtime_object.nested_objects[0].inner_object.is_some());
+    }
+
+    #[test]
+    fn multiple_objects() {
+        let input = r#"
+object "First" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "First_deployed" {
+        code {
+            {
+                return(0, 0)
+            }
+        }
+    }
+}
+
+object "Second" {
+    code {
+        {
+            let size := datasize("First")
+            datacopy(0, dataoffset("First"), size)
+            return(0, size)
+        }
+    }
+    object "Second_deployed" {
+        code {
+            {
+                return(0, 0)
+            }
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input);
+        let objects = Object::<DefaultDialect>::parse_all(&mut lexer).expect("Always valid");
+
+        assert_eq!(
+            objects
+                .iter()
+                .map(|object| object.identifier.as_str())
+                .collect::<Vec<&str>>(),
+            vec!["First", "Second"]
+        );
+        assert_eq!(
+            objects[1].location.offset,
+            input.find("object \"Second\"").expect("Always exists")
+        );
+        assert_eq!(
+            objects[1]
+                .get_evm_dependencies(objects[1].inner_object.as_deref())
+                .inner,
+            vec!["Second_deployed".to_owned(), "First".to_owned()]
+        );
+    }
+
+    #[test]
+    fn error_invalid_data() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    data "table" hex"0g"
+}
+    "#;
+
+        let mut lexer = Lexer::new(input);
+        let result = Object::<DefaultDialect>::parse(
+            &mut lexer,
+            None,
+            era_compiler_common::CodeSegment::Deploy,
+        );
+        assert_eq!(
+            result,
+            Err(Error::InvalidData {
+                location: Location::new(8, 18),
+                identifier: "table".to_owned(),
+            }
+            .into())
+        );
+    }
 }
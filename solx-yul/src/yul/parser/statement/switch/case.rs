@@ -2,10 +2,12 @@
 //! The switch statement case.
 //!
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
 use crate::dependencies::Dependencies;
 use crate::yul::error::Error;
+use crate::yul::lexer::token::annotation::source::Source;
 use crate::yul::lexer::token::lexeme::Lexeme;
 use crate::yul::lexer::token::location::Location;
 use crate::yul::lexer::token::Token;
@@ -80,6 +82,27 @@ where
     pub fn accumulate_evm_dependencies(&self, dependencies: &mut Dependencies) {
         self.block.accumulate_evm_dependencies(dependencies);
     }
+
+    ///
+    /// Get the signatures of the functions defined in the case.
+    ///
+    /// Maps each function name to its numbers of parameter and return slots.
+    ///
+    pub fn accumulate_function_signatures(
+        &self,
+        signatures: &mut BTreeMap<String, (usize, usize)>,
+    ) {
+        self.block.accumulate_function_signatures(signatures);
+    }
+
+    ///
+    /// Get the original source ranges of the statements in the case.
+    ///
+    /// Maps each range identifier to the range.
+    ///
+    pub fn accumulate_source_ranges(&self, ranges: &mut BTreeMap<usize, Source>) {
+        self.block.accumulate_source_ranges(ranges);
+    }
 }
 
 #[cfg(test)]
@@ -4,10 +4,12 @@
 
 pub mod case;
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
 use crate::dependencies::Dependencies;
 use crate::yul::error::Error;
+use crate::yul::lexer::token::annotation::source::Source;
 use crate::yul::lexer::token::lexeme::keyword::Keyword;
 use crate::yul::lexer::token::lexeme::Lexeme;
 use crate::yul::lexer::token::location::Location;
@@ -144,6 +146,71 @@ where
             default.accumulate_evm_dependencies(dependencies);
         }
     }
+
+    ///
+    /// Get the signatures of the functions defined in the switch.
+    ///
+    /// Maps each function name to its numbers of parameter and return slots.
+    ///
+    pub fn accumulate_function_signatures(
+        &self,
+        signatures: &mut BTreeMap<String, (usize, usize)>,
+    ) {
+        for case in self.cases.iter() {
+            case.accumulate_function_signatures(signatures);
+        }
+        if let Some(default) = &self.default {
+            default.accumulate_function_signatures(signatures);
+        }
+    }
+
+    ///
+    /// Renames the functions defined in the switch and the calls to them.
+    ///
+    /// See [`Block::mangle_function_names`].
+    ///
+    pub fn mangle_function_names(
+        &mut self,
+        scope: &BTreeMap<String, String>,
+        declarations: &mut BTreeMap<String, usize>,
+    ) {
+        self.expression.mangle_function_names(scope);
+        for case in self.cases.iter_mut() {
+            case.block.mangle_function_names(scope, declarations);
+        }
+        if let Some(default) = self.default.as_mut() {
+            default.mangle_function_names(scope, declarations);
+        }
+    }
+
+    ///
+    /// Marks the `dataoffset` and `datasize` calls in the switch that reference data sections.
+    ///
+    /// See [`crate::yul::parser::statement::object::Object::resolve_data_references`].
+    ///
+    pub fn resolve_data_references(&mut self, data: &BTreeSet<String>) {
+        self.expression.resolve_data_references(data);
+        for case in self.cases.iter_mut() {
+            case.block.resolve_data_references(data);
+        }
+        if let Some(default) = self.default.as_mut() {
+            default.resolve_data_references(data);
+        }
+    }
+
+    ///
+    /// Get the original source ranges of the statements in the switch.
+    ///
+    /// Maps each range identifier to the range.
+    ///
+    pub fn accumulate_source_ranges(&self, ranges: &mut BTreeMap<usize, Source>) {
+        for case in self.cases.iter() {
+            case.accumulate_source_ranges(ranges);
+        }
+        if let Some(default) = &self.default {
+            default.accumulate_source_ranges(ranges);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -2,6 +2,7 @@
 //! The variable declaration statement.
 //!
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
 use crate::dependencies::Dependencies;
@@ -102,6 +103,28 @@ impl VariableDeclaration {
             expression.accumulate_evm_dependencies(dependencies);
         }
     }
+
+    ///
+    /// Renames the calls to the functions in the initializer.
+    ///
+    /// See [`crate::yul::parser::statement::block::Block::mangle_function_names`].
+    ///
+    pub fn mangle_function_names(&mut self, scope: &BTreeMap<String, String>) {
+        if let Some(ref mut expression) = self.expression {
+            expression.mangle_function_names(scope);
+        }
+    }
+
+    ///
+    /// Marks the `dataoffset` and `datasize` calls in the initializer that reference data sections.
+    ///
+    /// See [`crate::yul::parser::statement::object::Object::resolve_data_references`].
+    ///
+    pub fn resolve_data_references(&mut self, data: &BTreeSet<String>) {
+        if let Some(ref mut expression) = self.expression {
+            expression.resolve_data_references(data);
+        }
+    }
 }
 
 #[cfg(test)]
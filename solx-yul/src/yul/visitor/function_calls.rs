@@ -0,0 +1,132 @@
+//!
+//! The Yul function call collector.
+//!
+
+use crate::yul::lexer::token::location::Location;
+use crate::yul::parser::dialect::Dialect;
+use crate::yul::parser::statement::assignment::Assignment;
+use crate::yul::parser::statement::block::Block;
+use crate::yul::parser::statement::code::Code;
+use crate::yul::parser::statement::expression::function_call::name::Name;
+use crate::yul::parser::statement::expression::function_call::FunctionCall;
+use crate::yul::parser::statement::expression::literal::Literal;
+use crate::yul::parser::statement::expression::Expression;
+use crate::yul::parser::statement::for_loop::ForLoop;
+use crate::yul::parser::statement::function_definition::FunctionDefinition;
+use crate::yul::parser::statement::if_conditional::IfConditional;
+use crate::yul::parser::statement::object::Object;
+use crate::yul::parser::statement::switch::Switch;
+use crate::yul::parser::statement::variable_declaration::VariableDeclaration;
+use crate::yul::parser::statement::Statement;
+use crate::yul::visitor::Visitor;
+
+///
+/// The Yul function call collector.
+///
+/// Collects the names and locations of all function calls in the visited syntax tree,
+/// in the order they appear in the source code.
+///
+#[derive(Debug, Default)]
+pub struct FunctionCalls {
+    /// The function call names and locations.
+    pub calls: Vec<(Name, Location)>,
+}
+
+impl<P> Visitor<P> for FunctionCalls
+where
+    P: Dialect,
+{
+    fn visit_switch(&mut self, switch: &Switch<P>) {
+        <Self as Visitor<P>>::visit_expression(self, &switch.expression);
+        for case in switch.cases.iter() {
+            self.visit_block(&case.block);
+        }
+        if let Some(block) = switch.default.as_ref() {
+            self.visit_block(block);
+        }
+    }
+
+    fn visit_object(&mut self, object: &Object<P>) {
+        self.visit_code(&object.code);
+        if let Some(inner_object) = object.inner_object.as_deref() {
+            self.visit_object(inner_object);
+        }
+    }
+
+    fn visit_for_loop(&mut self, for_loop: &ForLoop<P>) {
+        self.visit_block(&for_loop.initializer);
+        <Self as Visitor<P>>::visit_expression(self, &for_loop.condition);
+        self.visit_block(&for_loop.finalizer);
+        self.visit_block(&for_loop.body);
+    }
+
+    fn visit_variable_declaration(&mut self, variable_declaration: &VariableDeclaration) {
+        if let Some(expression) = variable_declaration.expression.as_ref() {
+            <Self as Visitor<P>>::visit_expression(self, expression);
+        }
+    }
+
+    fn visit_function_definition(&mut self, function_definition: &FunctionDefinition<P>) {
+        self.visit_block(&function_definition.body);
+    }
+
+    fn visit_name(&mut self, _name: &Name) {}
+
+    fn visit_function_call(&mut self, call: &FunctionCall) {
+        self.calls.push((call.name.clone(), call.location));
+        for argument in call.arguments.iter() {
+            <Self as Visitor<P>>::visit_expression(self, argument);
+        }
+    }
+
+    fn visit_if_conditional(&mut self, if_conditional: &IfConditional<P>) {
+        <Self as Visitor<P>>::visit_expression(self, &if_conditional.condition);
+        self.visit_block(&if_conditional.block);
+    }
+
+    fn visit_literal(&mut self, _literal: &Literal) {}
+
+    fn visit_expression(&mut self, expression: &Expression) {
+        if let Expression::FunctionCall(call) = expression {
+            <Self as Visitor<P>>::visit_function_call(self, call);
+        }
+    }
+
+    fn visit_assignment(&mut self, assignment: &Assignment) {
+        <Self as Visitor<P>>::visit_expression(self, &assignment.initializer);
+    }
+
+    fn visit_statement(&mut self, statement: &Statement<P>) {
+        match statement {
+            Statement::Object(object) => self.visit_object(object),
+            Statement::Code(code) => self.visit_code(code),
+            Statement::Block(block) => self.visit_block(block),
+            Statement::Expression(expression) => {
+                <Self as Visitor<P>>::visit_expression(self, expression)
+            }
+            Statement::FunctionDefinition(function_definition) => {
+                self.visit_function_definition(function_definition)
+            }
+            Statement::VariableDeclaration(variable_declaration) => {
+                <Self as Visitor<P>>::visit_variable_declaration(self, variable_declaration)
+            }
+            Statement::Assignment(assignment) => {
+                <Self as Visitor<P>>::visit_assignment(self, assignment)
+            }
+            Statement::IfConditional(if_conditional) => self.visit_if_conditional(if_conditional),
+            Statement::Switch(switch) => self.visit_switch(switch),
+            Statement::ForLoop(for_loop) => self.visit_for_loop(for_loop),
+            Statement::Continue(_) | Statement::Break(_) | Statement::Leave(_) => {}
+        }
+    }
+
+    fn visit_block(&mut self, block: &Block<P>) {
+        for statement in block.statements.iter() {
+            self.visit_statement(statement);
+        }
+    }
+
+    fn visit_code(&mut self, code: &Code<P>) {
+        self.visit_block(&code.block);
+    }
+}
@@ -2,6 +2,8 @@
 //! Implementation of a visitor pattern for Yul syntax tree.
 //!
 
+pub mod function_calls;
+
 use std::collections::BTreeSet;
 
 use crate::yul::parser::statement::assignment::Assignment;
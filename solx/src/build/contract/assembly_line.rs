@@ -0,0 +1,74 @@
+//!
+//! The LLVM EVM text assembly line.
+//!
+
+///
+/// The LLVM EVM text assembly line.
+///
+/// Only the lines the debug information is built from are distinguished.
+/// The assembly instructions are emitted in the same order as the bytecode ones, so counting the
+/// instruction lines yields the bytecode instruction indexes.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AssemblyLine<'a> {
+    /// The debug location directive.
+    Location {
+        /// The LLVM source line.
+        line: u32,
+        /// The LLVM source column.
+        column: u32,
+    },
+    /// The label, without the trailing colon.
+    Label(&'a str),
+    /// The instruction mnemonic.
+    Instruction(&'a str),
+}
+
+impl<'a> AssemblyLine<'a> {
+    /// The LLVM assembly debug location directive.
+    pub const DIRECTIVE_LOCATION: &'static str = ".loc";
+
+    ///
+    /// Parses the lines of `assembly`, skipping the empty lines, comments and other directives.
+    ///
+    pub fn parse_all(assembly: &'a str) -> impl Iterator<Item = Self> + 'a {
+        assembly.lines().filter_map(Self::parse)
+    }
+
+    ///
+    /// Parses a single assembly line.
+    ///
+    /// Returns `None` for the empty lines, comments and directives other than debug locations.
+    ///
+    pub fn parse(line: &'a str) -> Option<Self> {
+        let line = match line.split_once(';') {
+            Some((line, _comment)) => line,
+            None => line,
+        }
+        .trim();
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+
+        if let Some(arguments) = line.strip_prefix(Self::DIRECTIVE_LOCATION) {
+            let mut arguments = arguments.split_whitespace().skip(1);
+            let line = arguments
+                .next()
+                .and_then(|line| line.parse::<u32>().ok())
+                .unwrap_or_default();
+            let column = arguments
+                .next()
+                .and_then(|column| column.parse::<u32>().ok())
+                .unwrap_or_default();
+            return Some(Self::Location { line, column });
+        }
+        if let Some(label) = line.strip_suffix(':') {
+            return Some(Self::Label(label));
+        }
+        if line.starts_with('.') {
+            return None;
+        }
+
+        line.split_whitespace().next().map(Self::Instruction)
+    }
+}
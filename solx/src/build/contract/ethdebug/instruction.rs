@@ -0,0 +1,32 @@
+//!
+//! The ethdebug program instruction.
+//!
+
+use crate::build::contract::ethdebug::operation::Operation;
+
+///
+/// The ethdebug program instruction.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Instruction {
+    /// The byte offset of the instruction in the bytecode.
+    pub offset: usize,
+    /// The instruction operation.
+    pub operation: Operation,
+    /// The instruction context.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<serde_json::Value>,
+}
+
+impl Instruction {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(offset: usize, operation: Operation, context: Option<serde_json::Value>) -> Self {
+        Self {
+            offset,
+            operation,
+            context,
+        }
+    }
+}
@@ -0,0 +1,333 @@
+//!
+//! The ethdebug program.
+//!
+
+pub mod instruction;
+pub mod operation;
+
+use std::collections::BTreeMap;
+
+use crate::build::contract::object::Object;
+use crate::build::contract::opcodes::Opcodes;
+use crate::build::contract::source_map::SourceMap;
+
+use self::instruction::Instruction;
+use self::operation::Operation;
+
+///
+/// The ethdebug program.
+///
+/// Describes the instructions of a single bytecode object, following the `ethdebug/format/program`
+/// schema. The instruction contexts are derived from the source map and function debug data.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Program {
+    /// The contract description.
+    pub contract: serde_json::Value,
+    /// The execution environment, that is, `create` or `call`.
+    pub environment: String,
+    /// The context of the whole program, such as storage variables and remarks.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<serde_json::Value>,
+    /// The program instructions.
+    pub instructions: Vec<Instruction>,
+}
+
+impl Program {
+    /// The deploy code execution environment.
+    pub const ENVIRONMENT_CREATE: &'static str = "create";
+
+    /// The runtime code execution environment.
+    pub const ENVIRONMENT_CALL: &'static str = "call";
+
+    /// The persistent storage pointer location.
+    pub const LOCATION_STORAGE: &'static str = "storage";
+
+    /// The transient storage pointer location.
+    pub const LOCATION_TRANSIENT: &'static str = "transient";
+
+    /// The stack pointer location.
+    pub const LOCATION_STACK: &'static str = "stack";
+
+    /// The memory pointer location.
+    pub const LOCATION_MEMORY: &'static str = "memory";
+
+    /// The name of the stack region with the memory offset of an external call input.
+    pub const REGION_INPUT_OFFSET: &'static str = "input-offset";
+
+    /// The name of the stack region with the memory length of an external call input.
+    pub const REGION_INPUT_LENGTH: &'static str = "input-length";
+
+    /// The remark on the local variables missing from the program context.
+    pub const REMARK_LOCAL_VARIABLES: &'static str = "The stack and memory locations of local variables are not described, as they are decided by LLVM.";
+
+    ///
+    /// Builds the program of the assembled object.
+    ///
+    /// Returns `None` if the object has not been assembled yet.
+    ///
+    /// Only storage variables have locations that are known at compile time, as the stack and
+    /// memory layouts of the optimized code are decided by LLVM. The stack and memory pointers
+    /// are only emitted for the operands of function calls and contract creations, and the
+    /// missing local variables are reported with a remark in the program context.
+    ///
+    pub fn try_from_object(
+        object: &Object,
+        source_id: Option<usize>,
+        storage_layout: Option<&serde_json::Value>,
+        transient_storage_layout: Option<&serde_json::Value>,
+    ) -> Option<Self> {
+        if !object.is_assembled {
+            return None;
+        }
+        let bytecode = object.bytecode.as_deref()?;
+
+        let mut contract = serde_json::json!({
+            "name": object
+                .contract_name
+                .name
+                .as_deref()
+                .unwrap_or(object.contract_name.path.as_str()),
+        });
+        if let Some(source_id) = source_id {
+            contract["definition"] = serde_json::json!({ "source": { "id": source_id } });
+        }
+
+        let environment = match object.code_segment {
+            era_compiler_common::CodeSegment::Deploy => Self::ENVIRONMENT_CREATE,
+            era_compiler_common::CodeSegment::Runtime => Self::ENVIRONMENT_CALL,
+        };
+
+        let mut variables = Vec::new();
+        if let Some(storage_layout) = storage_layout {
+            variables.extend(Self::storage_variables(
+                storage_layout,
+                Self::LOCATION_STORAGE,
+            ));
+        }
+        if let Some(transient_storage_layout) = transient_storage_layout {
+            variables.extend(Self::storage_variables(
+                transient_storage_layout,
+                Self::LOCATION_TRANSIENT,
+            ));
+        }
+        let remark = serde_json::json!({ "remark": Self::REMARK_LOCAL_VARIABLES });
+        let context = if variables.is_empty() {
+            remark
+        } else {
+            serde_json::json!({ "gather": [{ "variables": variables }, remark] })
+        };
+
+        let source_map = object
+            .source_map
+            .as_deref()
+            .and_then(|source_map| source_map.parse::<SourceMap>().ok())
+            .unwrap_or_default();
+        let instruction_offsets = Opcodes::instruction_offsets(bytecode, object.metadata_length);
+        let functions = object
+            .function_debug_data
+            .iter()
+            .flat_map(|function_debug_data| function_debug_data.entries.iter())
+            .filter_map(|(name, entry)| {
+                let offset = instruction_offsets.get(entry.instruction_index?)?;
+                Some((*offset, (name.as_str(), entry.parameter_slots)))
+            })
+            .collect::<BTreeMap<usize, (&str, usize)>>();
+
+        let instructions = instruction_offsets
+            .iter()
+            .enumerate()
+            .map(|(index, &offset)| {
+                let opcode = bytecode[offset];
+                let mnemonic = Opcodes::name(opcode)
+                    .map(|name| name.to_owned())
+                    .unwrap_or_else(|| format!("0x{opcode:X}"));
+                let arguments = Opcodes::immediate(bytecode, offset)
+                    .map(|immediate| vec![format!("0x{}", hex::encode(immediate))])
+                    .unwrap_or_default();
+
+                let mut contexts = Vec::with_capacity(2);
+                if let Some(entry) = source_map.entries.get(index) {
+                    if entry.start >= 0 && entry.length >= 0 && entry.source_index >= 0 {
+                        contexts.push(serde_json::json!({
+                            "code": {
+                                "source": { "id": entry.source_index },
+                                "range": { "offset": entry.start, "length": entry.length },
+                            }
+                        }));
+                    }
+                }
+                let invoke = if mnemonic == "JUMP" {
+                    index
+                        .checked_sub(1)
+                        .and_then(|index| Opcodes::immediate(bytecode, instruction_offsets[index]))
+                        .and_then(|immediate| {
+                            immediate.iter().try_fold(0usize, |target, byte| {
+                                target
+                                    .checked_mul(256)
+                                    .map(|target| target + *byte as usize)
+                            })
+                        })
+                        .and_then(|target| functions.get(&target))
+                        .map(|(name, parameter_slots)| {
+                            Self::internal_invoke(name, *parameter_slots)
+                        })
+                } else {
+                    Self::external_invoke(mnemonic.as_str())
+                };
+                contexts.extend(invoke.map(|invoke| serde_json::json!({ "invoke": invoke })));
+                let context = match contexts.len() {
+                    0 => None,
+                    1 => contexts.pop(),
+                    _ => Some(serde_json::json!({ "gather": contexts })),
+                };
+
+                Instruction::new(offset, Operation::new(mnemonic, arguments), context)
+            })
+            .collect();
+
+        Some(Self {
+            contract,
+            environment: environment.to_owned(),
+            context: Some(context),
+            instructions,
+        })
+    }
+
+    ///
+    /// Returns the invoke context of an internal function call.
+    ///
+    /// The call is a `JUMP` to the function entry point, with the entry point on the stack top,
+    /// followed by the arguments.
+    ///
+    pub fn internal_invoke(identifier: &str, parameter_slots: usize) -> serde_json::Value {
+        let mut invoke = serde_json::json!({
+            "identifier": identifier,
+            "jump": true,
+            "target": { "pointer": Self::stack_pointer(0) },
+        });
+        if parameter_slots > 0 {
+            let arguments = (1..=parameter_slots)
+                .map(Self::stack_pointer)
+                .collect::<Vec<serde_json::Value>>();
+            invoke["arguments"] = serde_json::json!({ "pointer": { "group": arguments } });
+        }
+        invoke
+    }
+
+    ///
+    /// Returns the invoke context of an external call or contract creation instruction.
+    ///
+    /// Returns `None` for other instructions. The operand pointers follow the instruction stack
+    /// layouts defined by the EVM.
+    ///
+    pub fn external_invoke(mnemonic: &str) -> Option<serde_json::Value> {
+        let invoke = match mnemonic {
+            "CALL" => serde_json::json!({
+                "message": true,
+                "gas": { "pointer": Self::stack_pointer(0) },
+                "target": { "pointer": Self::stack_pointer(1) },
+                "value": { "pointer": Self::stack_pointer(2) },
+                "input": { "pointer": Self::memory_pointer(3, 4) },
+            }),
+            "DELEGATECALL" => serde_json::json!({
+                "message": true,
+                "delegate": true,
+                "gas": { "pointer": Self::stack_pointer(0) },
+                "target": { "pointer": Self::stack_pointer(1) },
+                "input": { "pointer": Self::memory_pointer(2, 3) },
+            }),
+            "STATICCALL" => serde_json::json!({
+                "message": true,
+                "static": true,
+                "gas": { "pointer": Self::stack_pointer(0) },
+                "target": { "pointer": Self::stack_pointer(1) },
+                "input": { "pointer": Self::memory_pointer(2, 3) },
+            }),
+            "CREATE" => serde_json::json!({
+                "create": true,
+                "value": { "pointer": Self::stack_pointer(0) },
+                "input": { "pointer": Self::memory_pointer(1, 2) },
+            }),
+            "CREATE2" => serde_json::json!({
+                "create": true,
+                "value": { "pointer": Self::stack_pointer(0) },
+                "input": { "pointer": Self::memory_pointer(1, 2) },
+                "salt": { "pointer": Self::stack_pointer(3) },
+            }),
+            _ => return None,
+        };
+        Some(invoke)
+    }
+
+    ///
+    /// Returns the pointer to the stack slot, counted from the stack top.
+    ///
+    pub fn stack_pointer(slot: usize) -> serde_json::Value {
+        serde_json::json!({ "location": Self::LOCATION_STACK, "slot": slot })
+    }
+
+    ///
+    /// Returns the pointer to the memory range whose offset and length are in the stack slots.
+    ///
+    pub fn memory_pointer(offset_slot: usize, length_slot: usize) -> serde_json::Value {
+        serde_json::json!({
+            "group": [
+                {
+                    "name": Self::REGION_INPUT_OFFSET,
+                    "location": Self::LOCATION_STACK,
+                    "slot": offset_slot,
+                },
+                {
+                    "name": Self::REGION_INPUT_LENGTH,
+                    "location": Self::LOCATION_STACK,
+                    "slot": length_slot,
+                },
+                {
+                    "location": Self::LOCATION_MEMORY,
+                    "offset": { "$read": Self::REGION_INPUT_OFFSET },
+                    "length": { "$read": Self::REGION_INPUT_LENGTH },
+                },
+            ]
+        })
+    }
+
+    ///
+    /// Converts the `solc` storage layout into ethdebug variables with storage pointers.
+    ///
+    pub fn storage_variables(layout: &serde_json::Value, location: &str) -> Vec<serde_json::Value> {
+        let types = layout.get("types");
+        layout
+            .get("storage")
+            .and_then(serde_json::Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|variable| {
+                let label = variable.get("label")?.as_str()?;
+                let slot = variable.get("slot")?.as_str()?;
+                let offset = variable.get("offset")?.as_u64()?;
+                let length = variable
+                    .get("type")
+                    .and_then(serde_json::Value::as_str)
+                    .and_then(|r#type| types?.get(r#type)?.get("numberOfBytes")?.as_str())
+                    .and_then(|length| length.parse::<u64>().ok())?;
+
+                let slot = match slot.parse::<u64>() {
+                    Ok(slot) => serde_json::json!(slot),
+                    Err(_) => {
+                        serde_json::json!(format!("0x{:x}", slot.parse::<num::BigUint>().ok()?))
+                    }
+                };
+                Some(serde_json::json!({
+                    "identifier": label,
+                    "pointer": {
+                        "location": location,
+                        "slot": slot,
+                        "offset": offset,
+                        "length": length,
+                    },
+                }))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,27 @@
+//!
+//! The ethdebug program instruction operation.
+//!
+
+///
+/// The ethdebug program instruction operation.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Operation {
+    /// The instruction mnemonic.
+    pub mnemonic: String,
+    /// The hexadecimal instruction arguments, that is, `PUSH` immediates.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub arguments: Vec<String>,
+}
+
+impl Operation {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(mnemonic: String, arguments: Vec<String>) -> Self {
+        Self {
+            mnemonic,
+            arguments,
+        }
+    }
+}
@@ -0,0 +1,66 @@
+//!
+//! The function debug data entry.
+//!
+
+///
+/// The function debug data entry.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Entry {
+    /// The index of the function entry instruction in the final bytecode.
+    /// Only set for functions that have survived the optimizations.
+    pub instruction_index: Option<usize>,
+    /// The AST identifier of the source function, if known.
+    pub id: Option<usize>,
+    /// The number of stack slots occupied by the function parameters.
+    pub parameter_slots: usize,
+    /// The number of stack slots occupied by the function return values.
+    pub return_slots: usize,
+}
+
+impl Entry {
+    /// The `solc` IR codegen prefixes of functions generated from Solidity sources.
+    pub const SOURCE_FUNCTION_PREFIXES: [&'static str; 3] = ["fun_", "modifier_", "constructor_"];
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(id: Option<usize>, parameter_slots: usize, return_slots: usize) -> Self {
+        Self {
+            instruction_index: None,
+            id,
+            parameter_slots,
+            return_slots,
+        }
+    }
+
+    ///
+    /// Extracts the AST identifier from a function name generated by `solc`.
+    ///
+    /// Functions generated from Solidity sources are named `fun_<name>_<id>`, with the
+    /// `modifier_` and `constructor_` prefixes used for modifiers and constructors.
+    ///
+    pub fn ast_id(function_name: &str) -> Option<usize> {
+        let name = Self::SOURCE_FUNCTION_PREFIXES
+            .iter()
+            .find_map(|prefix| function_name.strip_prefix(prefix))?;
+        let (_name, id) = name.rsplit_once('_')?;
+        id.parse::<usize>().ok()
+    }
+
+    ///
+    /// Converts the entry to the `solc` standard JSON format, resolving the entry point with
+    /// the instruction offsets of the final bytecode.
+    ///
+    pub fn to_standard_json(&self, instruction_offsets: &[usize]) -> serde_json::Value {
+        let entry_point = self
+            .instruction_index
+            .and_then(|index| instruction_offsets.get(index));
+        serde_json::json!({
+            "entryPoint": entry_point,
+            "id": self.id,
+            "parameterSlots": self.parameter_slots,
+            "returnSlots": self.return_slots,
+        })
+    }
+}
@@ -0,0 +1,97 @@
+//!
+//! The function debug data.
+//!
+
+pub mod entry;
+
+use std::collections::BTreeMap;
+
+use crate::build::contract::assembly_line::AssemblyLine;
+
+use self::entry::Entry;
+
+///
+/// The function debug data.
+///
+/// Describes the functions that have survived in the final bytecode, keyed by their names.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct FunctionDebugData {
+    /// The function entries.
+    pub entries: BTreeMap<String, Entry>,
+}
+
+impl FunctionDebugData {
+    /// The LLVM assembly prefix of private symbols.
+    pub const PRIVATE_SYMBOL_PREFIX: &'static str = ".L";
+
+    ///
+    /// Builds the function debug data from the LLVM EVM text assembly.
+    ///
+    /// The entry point of each function is the index of the first instruction after its label.
+    /// Functions without a label in the assembly have been inlined, so they are omitted.
+    ///
+    pub fn from_assembly(assembly: &str, mut functions: BTreeMap<String, Entry>) -> Self {
+        let mut instruction_index = 0;
+        for line in AssemblyLine::parse_all(assembly) {
+            match line {
+                AssemblyLine::Label(label) => {
+                    let name = label
+                        .strip_prefix(Self::PRIVATE_SYMBOL_PREFIX)
+                        .filter(|name| functions.contains_key(*name))
+                        .unwrap_or(label);
+                    if let Some(entry) = functions.get_mut(name) {
+                        entry.instruction_index.get_or_insert(instruction_index);
+                    }
+                }
+                AssemblyLine::Location { .. } => {}
+                AssemblyLine::Instruction(_) => instruction_index += 1,
+            }
+        }
+
+        functions.retain(|_name, entry| entry.instruction_index.is_some());
+        Self { entries: functions }
+    }
+
+    ///
+    /// Builds the function debug data of the code translated from Yul.
+    ///
+    /// `signatures` maps the Yul function names to their numbers of parameter and return slots.
+    /// The parser renames the functions defined more than once in a code segment, so the names
+    /// are unique and match the LLVM function symbols.
+    ///
+    pub fn from_yul_assembly(assembly: &str, signatures: BTreeMap<String, (usize, usize)>) -> Self {
+        let functions = signatures
+            .into_iter()
+            .map(|(name, (parameter_slots, return_slots))| {
+                let entry = Entry::new(Entry::ast_id(name.as_str()), parameter_slots, return_slots);
+                (name, entry)
+            })
+            .collect();
+        Self::from_assembly(assembly, functions)
+    }
+
+    ///
+    /// Builds the function debug data of the code translated from EVM legacy assembly.
+    ///
+    /// Only the recursive functions are preserved by the Ethereal IR, whereas the rest are
+    /// inlined into their callers.
+    ///
+    pub fn from_evmla_assembly(
+        assembly: &str,
+        functions: BTreeMap<String, &solx_evm_assembly::ExtraMetadataRecursiveFunction>,
+    ) -> Self {
+        let functions = functions
+            .into_iter()
+            .map(|(name, function)| {
+                let entry = Entry::new(
+                    Entry::ast_id(function.name.as_str()),
+                    function.input_size,
+                    function.output_size,
+                );
+                (name, entry)
+            })
+            .collect();
+        Self::from_assembly(assembly, functions)
+    }
+}
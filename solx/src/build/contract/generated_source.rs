@@ -0,0 +1,153 @@
+//!
+//! The generated source with the `solc` utility code.
+//!
+
+use solx_yul::yul::parser::statement::object::Object;
+use solx_yul::yul::parser::statement::Statement;
+
+use crate::project::contract::ir::yul::Yul;
+use crate::yul::parser::dialect::era::EraDialect;
+
+///
+/// The generated source with the `solc` utility code.
+///
+/// With the IR codegen, the utility functions, such as ABI encoders and decoders, are emitted by
+/// `solc` into the optimized IR alongside the functions translated from the user code.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GeneratedSource {
+    /// The source ID, following the IDs of the original sources.
+    pub id: usize,
+    /// The Yul source code of the utility functions.
+    pub contents: String,
+}
+
+impl GeneratedSource {
+    /// The generated source name, the same as the `solc` one.
+    pub const NAME: &'static str = "#utility.yul";
+
+    /// The prefixes of the optimized IR functions translated from the user code.
+    pub const USER_FUNCTION_PREFIXES: [&'static str; 6] = [
+        "fun_",
+        "modifier_",
+        "constructor_",
+        "getter_fun_",
+        "external_fun_",
+        "usr$",
+    ];
+
+    ///
+    /// Extracts the utility functions of the deploy and runtime code from the optimized IR.
+    ///
+    /// The functions are copied from `ir_optimized` verbatim, in the order of appearance.
+    ///
+    pub fn from_ir_optimized(
+        path: &str,
+        ir_optimized: &str,
+        id: usize,
+    ) -> (Option<Self>, Option<Self>) {
+        let Ok(Some(mut yul)) = Yul::try_from_source(path, ir_optimized, None) else {
+            return (None, None);
+        };
+        let runtime_code = yul.take_runtime_code();
+
+        (
+            Self::from_object(ir_optimized, &yul.object.0, id),
+            runtime_code.and_then(|object| Self::from_object(ir_optimized, &object, id)),
+        )
+    }
+
+    ///
+    /// Converts the generated source to the `solc` standard JSON format.
+    ///
+    pub fn to_standard_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "ast": null,
+            "contents": self.contents,
+            "id": self.id,
+            "language": "Yul",
+            "name": Self::NAME,
+        })
+    }
+
+    ///
+    /// Collects the utility functions defined at the top level of the `object` code.
+    ///
+    fn from_object(source_code: &str, object: &Object<EraDialect>, id: usize) -> Option<Self> {
+        let functions: Vec<&str> = object
+            .code
+            .block
+            .statements
+            .iter()
+            .filter_map(|statement| match statement {
+                Statement::FunctionDefinition(function)
+                    if !Self::USER_FUNCTION_PREFIXES
+                        .iter()
+                        .any(|prefix| function.identifier.starts_with(prefix)) =>
+                {
+                    Self::function_source(source_code, function.location.offset)
+                }
+                _ => None,
+            })
+            .collect();
+        if functions.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            id,
+            contents: functions.join("\n\n"),
+        })
+    }
+
+    ///
+    /// Returns the source code of the function whose identifier starts at `offset`.
+    ///
+    /// The function body is delimited by matching the braces, skipping string literals and comments.
+    ///
+    fn function_source(source_code: &str, offset: usize) -> Option<&str> {
+        let start = source_code.get(..offset)?.rfind("function")?;
+        let body_start = offset + source_code.get(offset..)?.find('{')?;
+
+        let bytes = source_code.as_bytes();
+        let mut depth = 0;
+        let mut index = body_start;
+        while index < bytes.len() {
+            match bytes[index] {
+                b'{' => depth += 1,
+                b'}' => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return source_code.get(start..=index);
+                    }
+                }
+                b'"' => {
+                    index += 1;
+                    while index < bytes.len() && bytes[index] != b'"' {
+                        if bytes[index] == b'\\' {
+                            index += 1;
+                        }
+                        index += 1;
+                    }
+                }
+                b'/' if bytes.get(index + 1) == Some(&b'/') => {
+                    while index < bytes.len() && bytes[index] != b'\n' {
+                        index += 1;
+                    }
+                }
+                b'/' if bytes.get(index + 1) == Some(&b'*') => {
+                    index += 2;
+                    while index + 1 < bytes.len()
+                        && !(bytes[index] == b'*' && bytes[index + 1] == b'/')
+                    {
+                        index += 1;
+                    }
+                    index += 1;
+                }
+                _ => {}
+            }
+            index += 1;
+        }
+        None
+    }
+}
@@ -2,7 +2,13 @@
 //! The Solidity contract build.
 //!
 
+pub mod assembly_line;
+pub mod ethdebug;
+pub mod function_debug_data;
+pub mod generated_source;
 pub mod object;
+pub mod opcodes;
+pub mod source_map;
 
 use std::collections::BTreeMap;
 use std::io::Write;
@@ -11,6 +17,8 @@ use std::path::PathBuf;
 
 use normpath::PathExt;
 
+use self::ethdebug::Program;
+use self::generated_source::GeneratedSource;
 use self::object::Object;
 
 ///
@@ -45,6 +53,9 @@ pub struct Contract {
 }
 
 impl Contract {
+    /// The Ethereal IR file extension.
+    pub const EXTENSION_ETHIR: &'static str = "ethir";
+
     ///
     /// A shortcut constructor.
     ///
@@ -115,6 +126,72 @@ impl Contract {
             )?;
         }
 
+        if output_selection.check_selection(
+            self.name.path.as_str(),
+            self.name.name.as_deref(),
+            solx_standard_json::InputSelector::BytecodeLLVMIR,
+        ) {
+            let deploy_llvm_ir = self.deploy_object.llvm_ir.take().expect("Always exists");
+            writeln!(std::io::stdout(), "Deploy LLVM IR:\n{deploy_llvm_ir}")?;
+        }
+        if output_selection.check_selection(
+            self.name.path.as_str(),
+            self.name.name.as_deref(),
+            solx_standard_json::InputSelector::BytecodeLLVMIROptimized,
+        ) {
+            let deploy_llvm_ir_optimized = self
+                .deploy_object
+                .llvm_ir_optimized
+                .take()
+                .expect("Always exists");
+            writeln!(
+                std::io::stdout(),
+                "Deploy optimized LLVM IR:\n{deploy_llvm_ir_optimized}"
+            )?;
+        }
+        if output_selection.check_selection(
+            self.name.path.as_str(),
+            self.name.name.as_deref(),
+            solx_standard_json::InputSelector::RuntimeBytecodeLLVMIR,
+        ) {
+            let runtime_llvm_ir = self.runtime_object.llvm_ir.take().expect("Always exists");
+            writeln!(std::io::stdout(), "Runtime LLVM IR:\n{runtime_llvm_ir}")?;
+        }
+        if output_selection.check_selection(
+            self.name.path.as_str(),
+            self.name.name.as_deref(),
+            solx_standard_json::InputSelector::RuntimeBytecodeLLVMIROptimized,
+        ) {
+            let runtime_llvm_ir_optimized = self
+                .runtime_object
+                .llvm_ir_optimized
+                .take()
+                .expect("Always exists");
+            writeln!(
+                std::io::stdout(),
+                "Runtime optimized LLVM IR:\n{runtime_llvm_ir_optimized}"
+            )?;
+        }
+
+        if output_selection.check_selection(
+            self.name.path.as_str(),
+            self.name.name.as_deref(),
+            solx_standard_json::InputSelector::BytecodeEthIR,
+        ) {
+            if let Some(deploy_ethir) = self.deploy_object.ethir.take() {
+                writeln!(std::io::stdout(), "Deploy Ethereal IR:\n{deploy_ethir}")?;
+            }
+        }
+        if output_selection.check_selection(
+            self.name.path.as_str(),
+            self.name.name.as_deref(),
+            solx_standard_json::InputSelector::RuntimeBytecodeEthIR,
+        ) {
+            if let Some(runtime_ethir) = self.runtime_object.ethir.take() {
+                writeln!(std::io::stdout(), "Runtime Ethereal IR:\n{runtime_ethir}")?;
+            }
+        }
+
         if output_selection.check_selection(
             self.name.path.as_str(),
             self.name.name.as_deref(),
@@ -335,6 +412,91 @@ impl Contract {
             }
         }
 
+        for (object, code_segment, selector, selector_optimized) in [
+            (
+                &mut self.deploy_object,
+                era_compiler_common::CodeSegment::Deploy,
+                solx_standard_json::InputSelector::BytecodeLLVMIR,
+                solx_standard_json::InputSelector::BytecodeLLVMIROptimized,
+            ),
+            (
+                &mut self.runtime_object,
+                era_compiler_common::CodeSegment::Runtime,
+                solx_standard_json::InputSelector::RuntimeBytecodeLLVMIR,
+                solx_standard_json::InputSelector::RuntimeBytecodeLLVMIROptimized,
+            ),
+        ] {
+            for (llvm_ir, selector, suffix) in [
+                (&mut object.llvm_ir, selector, ""),
+                (
+                    &mut object.llvm_ir_optimized,
+                    selector_optimized,
+                    "_optimized",
+                ),
+            ] {
+                if !output_selection.check_selection(
+                    self.name.path.as_str(),
+                    self.name.name.as_deref(),
+                    selector,
+                ) {
+                    continue;
+                }
+
+                let output_name = format!(
+                    "{contract_path}_{}{suffix}.{}{}",
+                    self.name.name.as_deref().unwrap_or(contract_name),
+                    era_compiler_common::EXTENSION_LLVM_SOURCE,
+                    match code_segment {
+                        era_compiler_common::CodeSegment::Deploy => "".to_owned(),
+                        era_compiler_common::CodeSegment::Runtime => format!("-{code_segment}"),
+                    },
+                );
+                let mut output_path = output_directory.to_owned();
+                output_path.push(output_name.as_str());
+
+                let llvm_ir = llvm_ir.take().expect("Always exists");
+                Self::write_to_file(output_path.as_path(), llvm_ir, overwrite)?;
+            }
+        }
+
+        for (object, code_segment, selector) in [
+            (
+                &mut self.deploy_object,
+                era_compiler_common::CodeSegment::Deploy,
+                solx_standard_json::InputSelector::BytecodeEthIR,
+            ),
+            (
+                &mut self.runtime_object,
+                era_compiler_common::CodeSegment::Runtime,
+                solx_standard_json::InputSelector::RuntimeBytecodeEthIR,
+            ),
+        ] {
+            if !output_selection.check_selection(
+                self.name.path.as_str(),
+                self.name.name.as_deref(),
+                selector,
+            ) {
+                continue;
+            }
+            let Some(ethir) = object.ethir.take() else {
+                continue;
+            };
+
+            let output_name = format!(
+                "{contract_path}_{}.{}{}",
+                self.name.name.as_deref().unwrap_or(contract_name),
+                Self::EXTENSION_ETHIR,
+                match code_segment {
+                    era_compiler_common::CodeSegment::Deploy => "".to_owned(),
+                    era_compiler_common::CodeSegment::Runtime => format!("-{code_segment}"),
+                },
+            );
+            let mut output_path = output_directory.to_owned();
+            output_path.push(output_name.as_str());
+
+            Self::write_to_file(output_path.as_path(), ethir, overwrite)?;
+        }
+
         if output_selection.check_selection(
             self.name.path.as_str(),
             self.name.name.as_deref(),
@@ -496,11 +658,46 @@ impl Contract {
     ///
     /// Writes the contract text assembly and bytecode to the standard JSON.
     ///
+    /// `sources` are the original sources with their IDs. The generated sources are received from
+    /// `solc` with the EVM assembly codegen, and are extracted from the optimized IR with the IR
+    /// codegen, taking the ID following the original sources.
+    ///
     pub fn write_to_standard_json(
         self,
         standard_json_contract: &mut solx_standard_json::OutputContract,
         output_selection: &solx_standard_json::InputSelection,
+        sources: &BTreeMap<String, solx_standard_json::OutputSource>,
     ) {
+        let source_id = sources.get(self.name.path.as_str()).map(|source| source.id);
+        let deploy_ethdebug = if output_selection.check_selection(
+            self.name.path.as_str(),
+            self.name.name.as_deref(),
+            solx_standard_json::InputSelector::BytecodeEthdebug,
+        ) {
+            Program::try_from_object(
+                &self.deploy_object,
+                source_id,
+                self.storage_layout.as_ref(),
+                self.transient_storage_layout.as_ref(),
+            )
+        } else {
+            None
+        };
+        let runtime_ethdebug = if output_selection.check_selection(
+            self.name.path.as_str(),
+            self.name.name.as_deref(),
+            solx_standard_json::InputSelector::RuntimeBytecodeEthdebug,
+        ) {
+            Program::try_from_object(
+                &self.runtime_object,
+                source_id,
+                self.storage_layout.as_ref(),
+                self.transient_storage_layout.as_ref(),
+            )
+        } else {
+            None
+        };
+
         standard_json_contract.metadata = self.metadata.filter(|_| {
             output_selection.check_selection(
                 self.name.path.as_str(),
@@ -544,6 +741,19 @@ impl Contract {
                     solx_standard_json::InputSelector::TransientStorageLayout,
                 )
             });
+        let generated_source_id = sources
+            .values()
+            .map(|source| source.id + 1)
+            .max()
+            .unwrap_or_default();
+        let (deploy_utility_source, runtime_utility_source) = match self.ir_optimized.as_deref() {
+            Some(ir_optimized) => GeneratedSource::from_ir_optimized(
+                self.name.path.as_str(),
+                ir_optimized,
+                generated_source_id,
+            ),
+            None => (None, None),
+        };
         standard_json_contract.ir_optimized = self.ir_optimized.filter(|_| {
             output_selection.check_selection(
                 self.name.path.as_str(),
@@ -569,6 +779,33 @@ impl Contract {
                 solx_standard_json::InputSelector::EVMLegacyAssembly,
             )
         });
+        let deploy_generated_sources = evm
+            .bytecode
+            .as_mut()
+            .and_then(|bytecode| bytecode.generated_sources.take())
+            .filter(|generated_sources| !generated_sources.is_empty())
+            .unwrap_or_else(|| {
+                deploy_utility_source
+                    .iter()
+                    .map(GeneratedSource::to_standard_json)
+                    .collect()
+            });
+        let runtime_generated_sources = evm
+            .deployed_bytecode
+            .as_mut()
+            .and_then(|bytecode| bytecode.generated_sources.take())
+            .filter(|generated_sources| !generated_sources.is_empty())
+            .unwrap_or_else(|| {
+                runtime_utility_source
+                    .iter()
+                    .map(GeneratedSource::to_standard_json)
+                    .collect()
+            });
+        let deploy_opcodes = self.deploy_object.opcodes();
+        let runtime_opcodes = self.runtime_object.opcodes();
+        let deploy_function_debug_data = self.deploy_object.function_debug_data();
+        let runtime_function_debug_data = self.runtime_object.function_debug_data();
+        let runtime_immutable_references = self.runtime_object.immutable_references();
         evm.bytecode = Some(solx_standard_json::OutputContractEVMBytecode::new(
             self.deploy_object.bytecode_hex.filter(|_| {
                 output_selection.check_selection(
@@ -584,6 +821,27 @@ impl Contract {
                     solx_standard_json::InputSelector::BytecodeLLVMAssembly,
                 )
             }),
+            self.deploy_object.llvm_ir.filter(|_| {
+                output_selection.check_selection(
+                    self.name.path.as_str(),
+                    self.name.name.as_deref(),
+                    solx_standard_json::InputSelector::BytecodeLLVMIR,
+                )
+            }),
+            self.deploy_object.llvm_ir_optimized.filter(|_| {
+                output_selection.check_selection(
+                    self.name.path.as_str(),
+                    self.name.name.as_deref(),
+                    solx_standard_json::InputSelector::BytecodeLLVMIROptimized,
+                )
+            }),
+            self.deploy_object.ethir.filter(|_| {
+                output_selection.check_selection(
+                    self.name.path.as_str(),
+                    self.name.name.as_deref(),
+                    solx_standard_json::InputSelector::BytecodeEthIR,
+                )
+            }),
             if output_selection.check_selection(
                 self.name.path.as_str(),
                 self.name.name.as_deref(),
@@ -598,7 +856,7 @@ impl Contract {
                 self.name.name.as_deref(),
                 solx_standard_json::InputSelector::BytecodeOpcodes,
             ) {
-                Some(String::new())
+                deploy_opcodes
             } else {
                 None
             },
@@ -607,7 +865,7 @@ impl Contract {
                 self.name.name.as_deref(),
                 solx_standard_json::InputSelector::BytecodeSourceMap,
             ) {
-                Some(String::new())
+                Some(self.deploy_object.source_map.unwrap_or_default())
             } else {
                 None
             },
@@ -616,7 +874,7 @@ impl Contract {
                 self.name.name.as_deref(),
                 solx_standard_json::InputSelector::BytecodeGeneratedSources,
             ) {
-                Some(Vec::new())
+                Some(deploy_generated_sources)
             } else {
                 None
             },
@@ -625,11 +883,12 @@ impl Contract {
                 self.name.name.as_deref(),
                 solx_standard_json::InputSelector::BytecodeFunctionDebugData,
             ) {
-                Some(BTreeMap::new())
+                Some(deploy_function_debug_data.unwrap_or_default())
             } else {
                 None
             },
             None,
+            deploy_ethdebug.map(|program| serde_json::to_value(program).expect("Always valid")),
         ));
         evm.deployed_bytecode = Some(solx_standard_json::OutputContractEVMBytecode::new(
             self.runtime_object.bytecode_hex.filter(|_| {
@@ -646,6 +905,27 @@ impl Contract {
                     solx_standard_json::InputSelector::RuntimeBytecodeLLVMAssembly,
                 )
             }),
+            self.runtime_object.llvm_ir.filter(|_| {
+                output_selection.check_selection(
+                    self.name.path.as_str(),
+                    self.name.name.as_deref(),
+                    solx_standard_json::InputSelector::RuntimeBytecodeLLVMIR,
+                )
+            }),
+            self.runtime_object.llvm_ir_optimized.filter(|_| {
+                output_selection.check_selection(
+                    self.name.path.as_str(),
+                    self.name.name.as_deref(),
+                    solx_standard_json::InputSelector::RuntimeBytecodeLLVMIROptimized,
+                )
+            }),
+            self.runtime_object.ethir.filter(|_| {
+                output_selection.check_selection(
+                    self.name.path.as_str(),
+                    self.name.name.as_deref(),
+                    solx_standard_json::InputSelector::RuntimeBytecodeEthIR,
+                )
+            }),
             if output_selection.check_selection(
                 self.name.path.as_str(),
                 self.name.name.as_deref(),
@@ -660,7 +940,7 @@ impl Contract {
                 self.name.name.as_deref(),
                 solx_standard_json::InputSelector::RuntimeBytecodeOpcodes,
             ) {
-                Some(String::new())
+                runtime_opcodes
             } else {
                 None
             },
@@ -669,7 +949,7 @@ impl Contract {
                 self.name.name.as_deref(),
                 solx_standard_json::InputSelector::RuntimeBytecodeSourceMap,
             ) {
-                Some(String::new())
+                Some(self.runtime_object.source_map.unwrap_or_default())
             } else {
                 None
             },
@@ -678,7 +958,7 @@ impl Contract {
                 self.name.name.as_deref(),
                 solx_standard_json::InputSelector::RuntimeBytecodeGeneratedSources,
             ) {
-                Some(Vec::new())
+                Some(runtime_generated_sources)
             } else {
                 None
             },
@@ -687,7 +967,7 @@ impl Contract {
                 self.name.name.as_deref(),
                 solx_standard_json::InputSelector::RuntimeBytecodeFunctionDebugData,
             ) {
-                Some(BTreeMap::new())
+                Some(runtime_function_debug_data.unwrap_or_default())
             } else {
                 None
             },
@@ -696,10 +976,11 @@ impl Contract {
                 self.name.name.as_deref(),
                 solx_standard_json::InputSelector::RuntimeBytecodeImmutableReferences,
             ) {
-                Some(serde_json::json!({}))
+                Some(runtime_immutable_references.unwrap_or_default())
             } else {
                 None
             },
+            runtime_ethdebug.map(|program| serde_json::to_value(program).expect("Always valid")),
         ));
     }
 
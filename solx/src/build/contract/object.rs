@@ -3,6 +3,10 @@
 //!
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use crate::build::contract::function_debug_data::FunctionDebugData;
+use crate::build::contract::opcodes::Opcodes;
 
 ///
 /// Bytecode object.
@@ -17,8 +21,20 @@ pub struct Object {
     pub contract_name: era_compiler_common::ContractName,
     /// Text assembly.
     pub assembly: Option<String>,
+    /// LLVM IR before optimization.
+    pub llvm_ir: Option<String>,
+    /// LLVM IR after optimization.
+    pub llvm_ir_optimized: Option<String>,
+    /// Ethereal IR. Only produced by the EVM assembly codegen.
+    pub ethir: Option<String>,
+    /// The `solc`-compatible compressed source map.
+    pub source_map: Option<String>,
+    /// The function debug data.
+    pub function_debug_data: Option<FunctionDebugData>,
     /// Bytecode.
     pub bytecode: Option<Vec<u8>>,
+    /// The immutable offsets, keyed by the immutable identifiers. Only set for runtime code.
+    pub immutables: Option<BTreeMap<String, BTreeSet<u64>>>,
     /// Hexadecimal bytecode.
     pub bytecode_hex: Option<String>,
     /// Whether IR codegen is used.
@@ -27,8 +43,14 @@ pub struct Object {
     pub code_segment: era_compiler_common::CodeSegment,
     /// The metadata bytes. Only appended to runtime code.
     pub metadata_bytes: Option<Vec<u8>>,
+    /// The length of the CBOR metadata appended to the bytecode at assembly time, including its
+    /// length suffix. Only set for runtime code.
+    #[serde(default)]
+    pub metadata_length: usize,
     /// Dependencies.
     pub dependencies: solx_yul::Dependencies,
+    /// The Yul data sections, resolved as dependencies at assembly time.
+    pub data: BTreeMap<String, Vec<u8>>,
     /// The unlinked symbols, such as libraries.
     pub unlinked_symbols: BTreeMap<String, Vec<u64>>,
     /// Whether the object is already assembled.
@@ -41,6 +63,15 @@ impl Object {
     /// Length of the library placeholder.
     pub const LIBRARY_PLACEHOLDER_LENGTH: usize = 17;
 
+    /// The library placeholder prefix.
+    pub const LIBRARY_PLACEHOLDER_PREFIX: &'static str = "__$";
+
+    /// The library placeholder suffix.
+    pub const LIBRARY_PLACEHOLDER_SUFFIX: &'static str = "$__";
+
+    /// The opcode of the `PUSH20` instruction, whose immediates are library addresses.
+    pub const PUSH20: u8 = 0x73;
+
     ///
     /// A shortcut constructor.
     ///
@@ -48,11 +79,18 @@ impl Object {
         identifier: String,
         contract_name: era_compiler_common::ContractName,
         assembly: Option<String>,
+        llvm_ir: Option<String>,
+        llvm_ir_optimized: Option<String>,
+        ethir: Option<String>,
+        source_map: Option<String>,
+        function_debug_data: Option<FunctionDebugData>,
         bytecode: Option<Vec<u8>>,
+        immutables: Option<BTreeMap<String, BTreeSet<u64>>>,
         via_ir: bool,
         code_segment: era_compiler_common::CodeSegment,
         metadata_bytes: Option<Vec<u8>>,
         dependencies: solx_yul::Dependencies,
+        data: BTreeMap<String, Vec<u8>>,
         warnings: Vec<era_compiler_llvm_context::EVMWarning>,
     ) -> Self {
         let bytecode_hex = bytecode.as_ref().map(hex::encode);
@@ -60,12 +98,20 @@ impl Object {
             identifier,
             contract_name,
             assembly,
+            llvm_ir,
+            llvm_ir_optimized,
+            ethir,
+            source_map,
+            function_debug_data,
             bytecode,
             bytecode_hex,
+            immutables,
             via_ir,
             code_segment,
             metadata_bytes,
+            metadata_length: 0,
             dependencies,
+            data,
             unlinked_symbols: BTreeMap::new(),
             is_assembled: false,
             warnings,
@@ -113,6 +159,7 @@ impl Object {
     pub fn assemble(
         &self,
         all_objects: &[&Self],
+        identifier_paths: &BTreeMap<String, String>,
         cbor_data: Option<Vec<(String, semver::Version)>>,
     ) -> anyhow::Result<inkwell::memory_buffer::MemoryBuffer> {
         let memory_buffer = self.to_memory_buffer(cbor_data.clone())?;
@@ -122,10 +169,18 @@ impl Object {
 
         memory_buffers.extend(self.dependencies.inner.iter().map(|dependency| {
             let original_dependency_identifier = dependency.to_owned();
-            let dependency = all_objects
-                .iter()
-                .find(|object| object.identifier.as_str() == dependency.as_str())
-                .expect("Dependency not found");
+            if let Some(data) = self.data.get(dependency.as_str()) {
+                let memory_buffer = inkwell::memory_buffer::MemoryBuffer::create_from_memory_range(
+                    data.as_slice(),
+                    dependency.as_str(),
+                    false,
+                );
+                return (original_dependency_identifier, memory_buffer);
+            }
+
+            let dependency =
+                Self::find_dependency(all_objects, dependency.as_str(), identifier_paths)
+                    .expect("Dependency not found");
             let dependency_bytecode = dependency.bytecode.as_deref().expect("Bytecode is not set");
             let memory_buffer = inkwell::memory_buffer::MemoryBuffer::create_from_memory_range(
                 dependency_bytecode,
@@ -150,6 +205,38 @@ impl Object {
         )
     }
 
+    ///
+    /// Finds the object referenced as `dependency` in `all_objects`.
+    ///
+    /// If there is no object with such identifier, the dependency is resolved to the deploy code
+    /// of its contract, as contracts recompiled via the other `solc` codegen have other identifiers.
+    ///
+    pub fn find_dependency<'a>(
+        all_objects: &[&'a Self],
+        dependency: &str,
+        identifier_paths: &BTreeMap<String, String>,
+    ) -> Option<&'a Self> {
+        if let Some(object) = all_objects
+            .iter()
+            .find(|object| object.identifier.as_str() == dependency)
+            .copied()
+        {
+            return Some(object);
+        }
+
+        let full_path = identifier_paths
+            .get(dependency)
+            .map(|path| path.as_str())
+            .unwrap_or(dependency);
+        all_objects
+            .iter()
+            .find(|object| {
+                object.code_segment == era_compiler_common::CodeSegment::Deploy
+                    && object.contract_name.full_path.as_str() == full_path
+            })
+            .copied()
+    }
+
     ///
     /// Links the object with its linker symbols.
     ///
@@ -183,11 +270,7 @@ impl Object {
 
         let mut bytecode_hex = hex::encode(linked_object_with_placeholders.as_slice());
         for (symbol, offsets) in self.unlinked_symbols.iter() {
-            let hash = era_compiler_common::Keccak256Hash::from_slice(symbol.as_bytes()).to_vec();
-            let placeholder = format!(
-                "__${}$__",
-                hex::encode(&hash[0..Self::LIBRARY_PLACEHOLDER_LENGTH])
-            );
+            let placeholder = Self::library_placeholder(symbol.as_str());
             for offset in offsets.iter() {
                 let offset = *offset as usize;
                 unsafe {
@@ -203,6 +286,182 @@ impl Object {
         Ok(())
     }
 
+    ///
+    /// Links the library addresses into the hexadecimal bytecode with placeholders, that is, into
+    /// a previously compiled artifact.
+    ///
+    /// The input may have the `0x` prefix, which is kept in the linked bytecode, and whitespace
+    /// such as line breaks, which is removed. Raw binary bytecode is rejected.
+    ///
+    /// Returns the linked bytecode and the placeholders that are not in `linker_symbols`.
+    /// Every placeholder must be the immediate of a `PUSH20` instruction, which is checked to
+    /// ensure that the linked bytecode is still valid.
+    ///
+    pub fn link_placeholders(
+        bytecode_hex: &str,
+        linker_symbols: &BTreeMap<String, [u8; era_compiler_common::BYTE_LENGTH_ETH_ADDRESS]>,
+    ) -> anyhow::Result<(String, BTreeSet<String>)> {
+        let bytecode_hex = bytecode_hex.trim();
+        let (prefix, bytecode_hex) = match bytecode_hex.strip_prefix("0x") {
+            Some(bytecode_hex) => ("0x", bytecode_hex),
+            None => ("", bytecode_hex),
+        };
+        let bytecode_hex = bytecode_hex
+            .chars()
+            .filter(|character| !character.is_ascii_whitespace())
+            .collect::<String>();
+        if let Some(character) = bytecode_hex.chars().find(|character| {
+            !character.is_ascii_hexdigit() && !Self::LIBRARY_PLACEHOLDER_PREFIX.contains(*character)
+        }) {
+            anyhow::bail!(
+                "Expected hexadecimal bytecode, found character {character:?}. Raw binary bytecode is not supported"
+            );
+        }
+
+        let placeholder_length = era_compiler_common::BYTE_LENGTH_ETH_ADDRESS * 2;
+        let addresses = linker_symbols
+            .iter()
+            .map(|(symbol, address)| (Self::library_placeholder(symbol.as_str()), address))
+            .collect::<BTreeMap<String, &[u8; era_compiler_common::BYTE_LENGTH_ETH_ADDRESS]>>();
+
+        let mut linked_hex = String::with_capacity(bytecode_hex.len());
+        let mut zero_filled_hex = String::with_capacity(bytecode_hex.len());
+        let mut placeholder_offsets = Vec::new();
+        let mut unresolved = BTreeSet::new();
+        let mut remaining = bytecode_hex.as_str();
+        while let Some(start) = remaining.find(Self::LIBRARY_PLACEHOLDER_PREFIX) {
+            linked_hex.push_str(&remaining[..start]);
+            zero_filled_hex.push_str(&remaining[..start]);
+            if linked_hex.len() % 2 != 0 {
+                anyhow::bail!(
+                    "Library placeholder at odd hexadecimal offset {}",
+                    linked_hex.len()
+                );
+            }
+
+            let placeholder = match remaining.get(start..start + placeholder_length) {
+                Some(placeholder) if placeholder.ends_with(Self::LIBRARY_PLACEHOLDER_SUFFIX) => {
+                    placeholder
+                }
+                _ => anyhow::bail!(
+                    "Malformed library placeholder at byte offset {}",
+                    linked_hex.len() / 2
+                ),
+            };
+            placeholder_offsets.push(linked_hex.len() / 2);
+            match addresses.get(placeholder) {
+                Some(address) => linked_hex.push_str(hex::encode(address).as_str()),
+                None => {
+                    linked_hex.push_str(placeholder);
+                    unresolved.insert(placeholder.to_owned());
+                }
+            }
+            zero_filled_hex.push_str("0".repeat(placeholder_length).as_str());
+            remaining = &remaining[start + placeholder_length..];
+        }
+        linked_hex.push_str(remaining);
+        zero_filled_hex.push_str(remaining);
+
+        let bytecode = hex::decode(zero_filled_hex.as_str())
+            .map_err(|error| anyhow::anyhow!("Invalid bytecode hexadecimal: {error}"))?;
+        let instruction_offsets = Opcodes::instruction_offsets(bytecode.as_slice(), 0)
+            .into_iter()
+            .collect::<BTreeSet<usize>>();
+        for offset in placeholder_offsets.into_iter() {
+            let push_offset = offset.checked_sub(1);
+            let is_push20_immediate = push_offset.is_some_and(|push_offset| {
+                instruction_offsets.contains(&push_offset) && bytecode[push_offset] == Self::PUSH20
+            });
+            if !is_push20_immediate {
+                anyhow::bail!(
+                    "Library placeholder at byte offset {offset} is not a `PUSH20` immediate"
+                );
+            }
+        }
+
+        Ok((format!("{prefix}{linked_hex}"), unresolved))
+    }
+
+    ///
+    /// Returns the `solc`-compatible opcode listing of the assembled bytecode.
+    ///
+    /// Returns `None` if the object has not been assembled yet.
+    ///
+    pub fn opcodes(&self) -> Option<String> {
+        if !self.is_assembled {
+            return None;
+        }
+        let bytecode = self.bytecode.as_deref()?;
+
+        let placeholders = self
+            .unlinked_symbols
+            .iter()
+            .flat_map(|(symbol, offsets)| {
+                let placeholder = Self::library_placeholder(symbol.as_str());
+                offsets
+                    .iter()
+                    .map(move |offset| (*offset as usize, placeholder.clone()))
+            })
+            .collect::<BTreeMap<usize, String>>();
+        Some(Opcodes::disassemble(
+            bytecode,
+            self.metadata_length,
+            &placeholders,
+        ))
+    }
+
+    ///
+    /// Returns the function debug data in the `solc` standard JSON format.
+    ///
+    /// The entry points are only known after the object is assembled.
+    ///
+    pub fn function_debug_data(&self) -> Option<BTreeMap<String, serde_json::Value>> {
+        if !self.is_assembled {
+            return None;
+        }
+        let bytecode = self.bytecode.as_deref()?;
+        let function_debug_data = self.function_debug_data.as_ref()?;
+
+        let instruction_offsets = Opcodes::instruction_offsets(bytecode, self.metadata_length);
+        Some(
+            function_debug_data
+                .entries
+                .iter()
+                .map(|(name, entry)| {
+                    (
+                        name.to_owned(),
+                        entry.to_standard_json(instruction_offsets.as_slice()),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    ///
+    /// Returns the immutable offsets in the assembled bytecode.
+    ///
+    /// Returns `None` if the object has not been assembled yet.
+    ///
+    pub fn immutable_references(&self) -> Option<BTreeMap<String, BTreeSet<u64>>> {
+        if !self.is_assembled {
+            return None;
+        }
+        self.immutables.to_owned()
+    }
+
+    ///
+    /// Returns the library placeholder for the linker symbol, e.g. `__$<keccak256[0..17]>$__`.
+    ///
+    pub fn library_placeholder(symbol: &str) -> String {
+        let hash = era_compiler_common::Keccak256Hash::from_slice(symbol.as_bytes()).to_vec();
+        format!(
+            "{}{}{}",
+            Self::LIBRARY_PLACEHOLDER_PREFIX,
+            hex::encode(&hash[0..Self::LIBRARY_PLACEHOLDER_LENGTH]),
+            Self::LIBRARY_PLACEHOLDER_SUFFIX,
+        )
+    }
+
     ///
     /// Returns warnings in standard JSON format.
     ///
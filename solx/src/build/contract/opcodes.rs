@@ -0,0 +1,310 @@
+//!
+//! The EVM bytecode disassembler.
+//!
+
+use std::collections::BTreeMap;
+
+///
+/// The EVM bytecode disassembler.
+///
+/// Produces a `solc`-compatible space-separated opcode listing.
+///
+#[derive(Debug)]
+pub struct Opcodes;
+
+impl Opcodes {
+    /// The opcode of the first `PUSH` instruction with an immediate.
+    pub const PUSH1: u8 = 0x60;
+
+    /// The opcode of the last `PUSH` instruction with an immediate.
+    pub const PUSH32: u8 = 0x7f;
+
+    /// The size of the CBOR metadata length suffix.
+    pub const CBOR_LENGTH_SIZE: usize = 2;
+
+    ///
+    /// Disassembles the bytecode.
+    ///
+    /// `placeholders` maps the offsets of `PUSH` immediates to their string representation.
+    /// It is used to render unlinked library placeholders, which are zero-filled in `bytecode`.
+    ///
+    /// Immutables are zero-filled `PUSH32` immediates in the runtime code, so they are rendered
+    /// as regular instructions. The trailing `metadata_length` bytes of metadata are not code
+    /// and are not disassembled.
+    ///
+    pub fn disassemble(
+        bytecode: &[u8],
+        metadata_length: usize,
+        placeholders: &BTreeMap<usize, String>,
+    ) -> String {
+        let bytecode = &bytecode[..bytecode.len().saturating_sub(metadata_length)];
+
+        let mut result = String::with_capacity(bytecode.len() * 4);
+        let mut offset = 0;
+        while offset < bytecode.len() {
+            let opcode = bytecode[offset];
+            offset += 1;
+
+            match Self::name(opcode) {
+                Some(name) => result.push_str(name),
+                None => {
+                    result.push_str(format!("0x{opcode:X} ").as_str());
+                    continue;
+                }
+            }
+
+            if let Some(immediate) = Self::immediate(bytecode, offset - 1) {
+                result.push(' ');
+                match placeholders.get(&offset) {
+                    Some(placeholder) => result.push_str(placeholder.as_str()),
+                    None => result.push_str(Self::format_immediate(immediate.as_slice()).as_str()),
+                }
+                offset += immediate.len();
+            }
+            result.push(' ');
+        }
+        result
+    }
+
+    ///
+    /// Returns the offsets of all instructions in the bytecode, excluding the trailing
+    /// `metadata_length` bytes of metadata.
+    ///
+    pub fn instruction_offsets(bytecode: &[u8], metadata_length: usize) -> Vec<usize> {
+        let code_length = bytecode.len().saturating_sub(metadata_length);
+
+        let mut offsets = Vec::with_capacity(code_length);
+        let mut offset = 0;
+        while offset < code_length {
+            offsets.push(offset);
+
+            let opcode = bytecode[offset];
+            offset += 1;
+            if (Self::PUSH1..=Self::PUSH32).contains(&opcode) {
+                offset += (opcode - Self::PUSH1 + 1) as usize;
+            }
+        }
+        offsets
+    }
+
+    ///
+    /// Returns the immediate of the `PUSH` instruction at `offset`, or `None` for other instructions.
+    ///
+    /// The immediate truncated by the end of the bytecode is right-padded with zeros.
+    ///
+    pub fn immediate(bytecode: &[u8], offset: usize) -> Option<Vec<u8>> {
+        let opcode = bytecode[offset];
+        if !(Self::PUSH1..=Self::PUSH32).contains(&opcode) {
+            return None;
+        }
+
+        let size = (opcode - Self::PUSH1 + 1) as usize;
+        let start = offset + 1;
+        let mut immediate = vec![0u8; size];
+        let available = std::cmp::min(size, bytecode.len() - start);
+        immediate[..available].copy_from_slice(&bytecode[start..start + available]);
+        Some(immediate)
+    }
+
+    ///
+    /// Returns the length of the CBOR metadata appended to the bytecode, including its 2-byte
+    /// length suffix.
+    ///
+    /// Must only be called for bytecode the metadata has been appended to at assembly time,
+    /// as the suffix is read unconditionally.
+    ///
+    pub fn cbor_metadata_length(bytecode: &[u8]) -> usize {
+        if bytecode.len() < Self::CBOR_LENGTH_SIZE {
+            return 0;
+        }
+
+        let length_offset = bytecode.len() - Self::CBOR_LENGTH_SIZE;
+        let length =
+            u16::from_be_bytes([bytecode[length_offset], bytecode[length_offset + 1]]) as usize;
+        std::cmp::min(length + Self::CBOR_LENGTH_SIZE, bytecode.len())
+    }
+
+    ///
+    /// Formats a `PUSH` immediate the way `solc` does, that is, as a hexadecimal number
+    /// in upper case without leading zeros.
+    ///
+    fn format_immediate(immediate: &[u8]) -> String {
+        let hex = hex::encode_upper(immediate);
+        let trimmed = hex.trim_start_matches('0');
+        if trimmed.is_empty() {
+            "0x0".to_owned()
+        } else {
+            format!("0x{trimmed}")
+        }
+    }
+
+    ///
+    /// Returns the instruction name for the opcode, if it is defined.
+    ///
+    pub fn name(opcode: u8) -> Option<&'static str> {
+        let name = match opcode {
+            0x00 => "STOP",
+            0x01 => "ADD",
+            0x02 => "MUL",
+            0x03 => "SUB",
+            0x04 => "DIV",
+            0x05 => "SDIV",
+            0x06 => "MOD",
+            0x07 => "SMOD",
+            0x08 => "ADDMOD",
+            0x09 => "MULMOD",
+            0x0a => "EXP",
+            0x0b => "SIGNEXTEND",
+
+            0x10 => "LT",
+            0x11 => "GT",
+            0x12 => "SLT",
+            0x13 => "SGT",
+            0x14 => "EQ",
+            0x15 => "ISZERO",
+            0x16 => "AND",
+            0x17 => "OR",
+            0x18 => "XOR",
+            0x19 => "NOT",
+            0x1a => "BYTE",
+            0x1b => "SHL",
+            0x1c => "SHR",
+            0x1d => "SAR",
+
+            0x20 => "KECCAK256",
+
+            0x30 => "ADDRESS",
+            0x31 => "BALANCE",
+            0x32 => "ORIGIN",
+            0x33 => "CALLER",
+            0x34 => "CALLVALUE",
+            0x35 => "CALLDATALOAD",
+            0x36 => "CALLDATASIZE",
+            0x37 => "CALLDATACOPY",
+            0x38 => "CODESIZE",
+            0x39 => "CODECOPY",
+            0x3a => "GASPRICE",
+            0x3b => "EXTCODESIZE",
+            0x3c => "EXTCODECOPY",
+            0x3d => "RETURNDATASIZE",
+            0x3e => "RETURNDATACOPY",
+            0x3f => "EXTCODEHASH",
+
+            0x40 => "BLOCKHASH",
+            0x41 => "COINBASE",
+            0x42 => "TIMESTAMP",
+            0x43 => "NUMBER",
+            0x44 => "PREVRANDAO",
+            0x45 => "GASLIMIT",
+            0x46 => "CHAINID",
+            0x47 => "SELFBALANCE",
+            0x48 => "BASEFEE",
+            0x49 => "BLOBHASH",
+            0x4a => "BLOBBASEFEE",
+
+            0x50 => "POP",
+            0x51 => "MLOAD",
+            0x52 => "MSTORE",
+            0x53 => "MSTORE8",
+            0x54 => "SLOAD",
+            0x55 => "SSTORE",
+            0x56 => "JUMP",
+            0x57 => "JUMPI",
+            0x58 => "PC",
+            0x59 => "MSIZE",
+            0x5a => "GAS",
+            0x5b => "JUMPDEST",
+            0x5c => "TLOAD",
+            0x5d => "TSTORE",
+            0x5e => "MCOPY",
+            0x5f => "PUSH0",
+
+            0x60 => "PUSH1",
+            0x61 => "PUSH2",
+            0x62 => "PUSH3",
+            0x63 => "PUSH4",
+            0x64 => "PUSH5",
+            0x65 => "PUSH6",
+            0x66 => "PUSH7",
+            0x67 => "PUSH8",
+            0x68 => "PUSH9",
+            0x69 => "PUSH10",
+            0x6a => "PUSH11",
+            0x6b => "PUSH12",
+            0x6c => "PUSH13",
+            0x6d => "PUSH14",
+            0x6e => "PUSH15",
+            0x6f => "PUSH16",
+            0x70 => "PUSH17",
+            0x71 => "PUSH18",
+            0x72 => "PUSH19",
+            0x73 => "PUSH20",
+            0x74 => "PUSH21",
+            0x75 => "PUSH22",
+            0x76 => "PUSH23",
+            0x77 => "PUSH24",
+            0x78 => "PUSH25",
+            0x79 => "PUSH26",
+            0x7a => "PUSH27",
+            0x7b => "PUSH28",
+            0x7c => "PUSH29",
+            0x7d => "PUSH30",
+            0x7e => "PUSH31",
+            0x7f => "PUSH32",
+
+            0x80 => "DUP1",
+            0x81 => "DUP2",
+            0x82 => "DUP3",
+            0x83 => "DUP4",
+            0x84 => "DUP5",
+            0x85 => "DUP6",
+            0x86 => "DUP7",
+            0x87 => "DUP8",
+            0x88 => "DUP9",
+            0x89 => "DUP10",
+            0x8a => "DUP11",
+            0x8b => "DUP12",
+            0x8c => "DUP13",
+            0x8d => "DUP14",
+            0x8e => "DUP15",
+            0x8f => "DUP16",
+
+            0x90 => "SWAP1",
+            0x91 => "SWAP2",
+            0x92 => "SWAP3",
+            0x93 => "SWAP4",
+            0x94 => "SWAP5",
+            0x95 => "SWAP6",
+            0x96 => "SWAP7",
+            0x97 => "SWAP8",
+            0x98 => "SWAP9",
+            0x99 => "SWAP10",
+            0x9a => "SWAP11",
+            0x9b => "SWAP12",
+            0x9c => "SWAP13",
+            0x9d => "SWAP14",
+            0x9e => "SWAP15",
+            0x9f => "SWAP16",
+
+            0xa0 => "LOG0",
+            0xa1 => "LOG1",
+            0xa2 => "LOG2",
+            0xa3 => "LOG3",
+            0xa4 => "LOG4",
+
+            0xf0 => "CREATE",
+            0xf1 => "CALL",
+            0xf2 => "CALLCODE",
+            0xf3 => "RETURN",
+            0xf4 => "DELEGATECALL",
+            0xf5 => "CREATE2",
+            0xfa => "STATICCALL",
+            0xfd => "REVERT",
+            0xfe => "INVALID",
+            0xff => "SELFDESTRUCT",
+
+            _ => return None,
+        };
+        Some(name)
+    }
+}
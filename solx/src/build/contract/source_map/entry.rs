@@ -0,0 +1,74 @@
+//!
+//! The source map entry.
+//!
+
+use crate::build::contract::source_map::jump::Jump;
+
+///
+/// The source map entry.
+///
+/// Describes the source code range a single instruction has been generated from.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Entry {
+    /// The byte offset of the range start in the source file, or -1 if unknown.
+    pub start: isize,
+    /// The byte length of the range, or -1 if unknown.
+    pub length: isize,
+    /// The source file index, or -1 if unknown.
+    pub source_index: isize,
+    /// The jump type.
+    pub jump: Jump,
+    /// The modifier depth.
+    pub modifier_depth: usize,
+}
+
+impl Entry {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        start: isize,
+        length: isize,
+        source_index: isize,
+        jump: Jump,
+        modifier_depth: usize,
+    ) -> Self {
+        Self {
+            start,
+            length,
+            source_index,
+            jump,
+            modifier_depth,
+        }
+    }
+
+    ///
+    /// Creates an entry of an instruction without a known source location.
+    ///
+    pub fn unknown() -> Self {
+        Self::new(-1, -1, -1, Jump::Regular, 0)
+    }
+}
+
+impl From<&solx_evm_assembly::assembly::instruction::Instruction> for Entry {
+    fn from(instruction: &solx_evm_assembly::assembly::instruction::Instruction) -> Self {
+        if instruction.begin < 0 || instruction.end < instruction.begin {
+            return Self::unknown();
+        }
+
+        let jump = match instruction.name {
+            solx_evm_assembly::assembly::instruction::name::Name::JUMP => {
+                Jump::from_annotation(instruction.value.as_deref())
+            }
+            _ => Jump::Regular,
+        };
+        Self::new(
+            instruction.begin,
+            instruction.end - instruction.begin,
+            instruction.source.unwrap_or(-1),
+            jump,
+            0,
+        )
+    }
+}
@@ -0,0 +1,52 @@
+//!
+//! The source map jump type.
+//!
+
+///
+/// The source map jump type.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Jump {
+    /// The jump into a function.
+    Into,
+    /// The return from a function.
+    OutOf,
+    /// A regular jump or any other instruction.
+    Regular,
+}
+
+impl Jump {
+    ///
+    /// Returns the jump type of a `solc` legacy assembly jump annotation, such as `[in]`.
+    ///
+    pub fn from_annotation(annotation: Option<&str>) -> Self {
+        match annotation {
+            Some("[in]") => Self::Into,
+            Some("[out]") => Self::OutOf,
+            _ => Self::Regular,
+        }
+    }
+}
+
+impl std::str::FromStr for Jump {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "i" => Ok(Self::Into),
+            "o" => Ok(Self::OutOf),
+            "-" => Ok(Self::Regular),
+            string => anyhow::bail!("Invalid source map jump type `{string}`"),
+        }
+    }
+}
+
+impl std::fmt::Display for Jump {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Into => write!(f, "i"),
+            Self::OutOf => write!(f, "o"),
+            Self::Regular => write!(f, "-"),
+        }
+    }
+}
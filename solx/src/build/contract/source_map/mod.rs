@@ -0,0 +1,202 @@
+//!
+//! The EVM source map.
+//!
+
+pub mod entry;
+pub mod jump;
+
+use std::collections::BTreeMap;
+
+use crate::build::contract::assembly_line::AssemblyLine;
+
+use self::entry::Entry;
+use self::jump::Jump;
+
+///
+/// The EVM source map.
+///
+/// Contains one entry per instruction of the final bytecode, in the order of appearance.
+/// It is serialized to the `solc` compressed format, that is, `s:l:f:j:m` entries
+/// separated with `;`, where the fields equal to the previous entry's ones are omitted.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SourceMap {
+    /// The source map entries.
+    pub entries: Vec<Entry>,
+}
+
+impl SourceMap {
+    ///
+    /// Builds the source map from the LLVM EVM text assembly.
+    ///
+    /// Each instruction gets the source location of the closest preceding debug location
+    /// directive, whose LLVM line and column are converted into an entry with `resolver`.
+    ///
+    pub fn from_assembly<R>(assembly: &str, resolver: R) -> Self
+    where
+        R: Fn(u32, u32) -> Option<Entry>,
+    {
+        let mut entries = Vec::new();
+        let mut current_entry = None;
+        for line in AssemblyLine::parse_all(assembly) {
+            match line {
+                AssemblyLine::Location { line, column } => current_entry = resolver(line, column),
+                AssemblyLine::Label(_) => {}
+                AssemblyLine::Instruction(_) => {
+                    entries.push(current_entry.unwrap_or_else(Entry::unknown))
+                }
+            }
+        }
+        Self { entries }
+    }
+
+    ///
+    /// Builds the source map of the code translated from EVM legacy assembly.
+    ///
+    /// The LLVM debug locations are decoded with [`solx_evm_assembly::DebugLocation`], and
+    /// resolved to the original instructions of the deploy or runtime code.
+    ///
+    pub fn from_evmla_assembly(
+        assembly: &str,
+        deploy_code: &[solx_evm_assembly::assembly::instruction::Instruction],
+        runtime_code: &[solx_evm_assembly::assembly::instruction::Instruction],
+    ) -> Self {
+        Self::from_assembly(assembly, |line, column| {
+            let location = solx_evm_assembly::DebugLocation::try_from_line_column(line, column)?;
+            let instructions = match location.code_segment {
+                era_compiler_common::CodeSegment::Deploy => deploy_code,
+                era_compiler_common::CodeSegment::Runtime => runtime_code,
+            };
+            instructions
+                .get(location.instruction_index)
+                .map(Entry::from)
+        })
+    }
+
+    ///
+    /// Builds the source map of the code translated from Yul.
+    ///
+    /// The Yul translator sets the LLVM debug locations from the `@src` annotations, with the
+    /// 1-based range identifier as the line. The ranges are restored from the `ranges` of the
+    /// Yul object.
+    ///
+    pub fn from_yul_assembly(
+        assembly: &str,
+        ranges: &BTreeMap<usize, solx_yul::yul::lexer::token::annotation::source::Source>,
+    ) -> Self {
+        Self::from_assembly(assembly, |line, _column| {
+            let source_id = (line as usize).checked_sub(1)?;
+            let range = ranges.get(&source_id)?;
+            Some(Entry::new(
+                range.start as isize,
+                (range.end - range.start) as isize,
+                range.index as isize,
+                Jump::Regular,
+                0,
+            ))
+        })
+    }
+}
+
+impl std::str::FromStr for SourceMap {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        if string.is_empty() {
+            return Ok(Self::default());
+        }
+
+        let mut entries = Vec::new();
+        let mut previous = Entry::unknown();
+        for item in string.split(';') {
+            let mut entry = previous;
+            for (index, field) in item.split(':').enumerate() {
+                if field.is_empty() {
+                    continue;
+                }
+                let invalid_field =
+                    |error| anyhow::anyhow!("Invalid source map field `{field}`: {error}");
+                match index {
+                    0 => entry.start = field.parse().map_err(invalid_field)?,
+                    1 => entry.length = field.parse().map_err(invalid_field)?,
+                    2 => entry.source_index = field.parse().map_err(invalid_field)?,
+                    3 => entry.jump = field.parse()?,
+                    4 => entry.modifier_depth = field.parse().map_err(invalid_field)?,
+                    _ => anyhow::bail!("Invalid source map entry `{item}`"),
+                }
+            }
+            entries.push(entry);
+            previous = entry;
+        }
+        Ok(Self { entries })
+    }
+}
+
+impl std::fmt::Display for SourceMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut previous_start = None;
+        let mut previous_length = None;
+        let mut previous_source_index = None;
+        let mut previous_jump = None;
+        let mut previous_modifier_depth = None;
+
+        for (index, entry) in self.entries.iter().enumerate() {
+            if index > 0 {
+                write!(f, ";")?;
+            }
+
+            let mut components = 5;
+            if Some(entry.modifier_depth) == previous_modifier_depth {
+                components -= 1;
+                if Some(entry.jump) == previous_jump {
+                    components -= 1;
+                    if Some(entry.source_index) == previous_source_index {
+                        components -= 1;
+                        if Some(entry.length) == previous_length {
+                            components -= 1;
+                            if Some(entry.start) == previous_start {
+                                components -= 1;
+                            }
+                        }
+                    }
+                }
+            }
+
+            if components > 0 && Some(entry.start) != previous_start {
+                write!(f, "{}", entry.start)?;
+            }
+            if components > 1 {
+                write!(f, ":")?;
+                if Some(entry.length) != previous_length {
+                    write!(f, "{}", entry.length)?;
+                }
+            }
+            if components > 2 {
+                write!(f, ":")?;
+                if Some(entry.source_index) != previous_source_index {
+                    write!(f, "{}", entry.source_index)?;
+                }
+            }
+            if components > 3 {
+                write!(f, ":")?;
+                if Some(entry.jump) != previous_jump {
+                    write!(f, "{}", entry.jump)?;
+                }
+            }
+            if components > 4 {
+                write!(f, ":")?;
+                if Some(entry.modifier_depth) != previous_modifier_depth {
+                    write!(f, "{}", entry.modifier_depth)?;
+                }
+            }
+
+            previous_start = Some(entry.start);
+            previous_length = Some(entry.length);
+            previous_source_index = Some(entry.source_index);
+            previous_jump = Some(entry.jump);
+            previous_modifier_depth = Some(entry.modifier_depth);
+        }
+
+        Ok(())
+    }
+}
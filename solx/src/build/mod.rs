@@ -5,6 +5,7 @@
 pub mod contract;
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::io::Write;
 use std::path::Path;
 use std::path::PathBuf;
@@ -13,6 +14,7 @@ use normpath::PathExt;
 use solx_standard_json::CollectableError;
 
 use self::contract::object::Object as ContractObject;
+use self::contract::opcodes::Opcodes;
 use self::contract::Contract;
 
 ///
@@ -26,9 +28,14 @@ pub struct Build {
     pub ast_jsons: Option<BTreeMap<String, Option<serde_json::Value>>>,
     /// The additional message to output.
     pub messages: Vec<solx_standard_json::OutputError>,
+    /// The mapping of auxiliary identifiers, e.g. Yul object names, to full contract paths.
+    pub identifier_paths: BTreeMap<String, String>,
 }
 
 impl Build {
+    /// The stack-too-deep error pattern, matched against the lowercase letters of error messages.
+    pub const STACK_TOO_DEEP_PATTERN: &'static str = "stacktoodeep";
+
     ///
     /// A shortcut constructor.
     ///
@@ -41,6 +48,99 @@ impl Build {
             results,
             ast_jsons,
             messages: std::mem::take(messages),
+            identifier_paths: BTreeMap::new(),
+        }
+    }
+
+    ///
+    /// Returns the full paths of the contracts that failed with stack-too-deep errors or exceeded
+    /// the Ethereal IR limits.
+    ///
+    pub fn pipeline_fallback_paths(&self) -> BTreeSet<String> {
+        self.results
+            .iter()
+            .filter(|(path, _result)| {
+                self.error_matches(path, Self::STACK_TOO_DEEP_PATTERN)
+                    || self
+                        .error_matches(path, solx_evm_assembly::EtherealIRLimitViolation::PATTERN)
+            })
+            .map(|(path, _result)| path.to_owned())
+            .collect()
+    }
+
+    ///
+    /// Replaces the results of the contracts recompiled via the other `solc` codegen.
+    ///
+    /// The contracts that switched codegen are reported with warnings.
+    ///
+    pub fn apply_pipeline_fallback(&mut self, fallback: Self, via_ir: bool) {
+        let (from, to) = if via_ir {
+            ("Yul IR", "EVM assembly")
+        } else {
+            ("EVM assembly", "Yul IR")
+        };
+        for (path, result) in fallback.results.into_iter() {
+            if result.is_ok() {
+                let reason = if self.error_matches(
+                    path.as_str(),
+                    solx_evm_assembly::EtherealIRLimitViolation::PATTERN,
+                ) {
+                    "exceeded the Ethereal IR limits"
+                } else {
+                    "failed with a stack-too-deep error"
+                };
+                self.messages
+                    .push(solx_standard_json::OutputError::new_warning(
+                        None,
+                        format!(
+                            "Contract `{path}` {reason} via {from}, so it was recompiled via {to}."
+                        ),
+                        None,
+                        None,
+                    ));
+                self.results.insert(path, result);
+            }
+        }
+        self.identifier_paths.extend(fallback.identifier_paths);
+        self.messages.extend(fallback.messages);
+    }
+
+    ///
+    /// Checks whether the contract at `path` failed with an error matching `pattern`.
+    ///
+    /// The pattern is matched against the lowercase letters of the error message.
+    ///
+    fn error_matches(&self, path: &str, pattern: &str) -> bool {
+        self.results
+            .get(path)
+            .and_then(|result| result.as_ref().err())
+            .map(|error| {
+                error
+                    .message
+                    .chars()
+                    .filter(char::is_ascii_alphabetic)
+                    .collect::<String>()
+                    .to_lowercase()
+                    .contains(pattern)
+            })
+            .unwrap_or_default()
+    }
+
+    ///
+    /// Formats the contract errors with the source code snippets of their locations.
+    ///
+    /// The errors are created in subprocesses, which do not have access to the source code.
+    ///
+    pub fn format_errors_with_sources(
+        &mut self,
+        sources: &BTreeMap<String, solx_standard_json::InputSource>,
+    ) {
+        for error in self
+            .results
+            .values_mut()
+            .filter_map(|result| result.as_mut().err())
+        {
+            error.format_with_sources(sources);
         }
     }
 
@@ -71,11 +171,12 @@ impl Build {
                     .filter(|object| {
                         !object.is_assembled
                             && object.dependencies.inner.iter().all(|dependency| {
-                                all_objects
-                                    .iter()
-                                    .find(|object| {
-                                        object.identifier.as_str() == dependency.as_str()
-                                    })
+                                object.data.contains_key(dependency.as_str())
+                                    || ContractObject::find_dependency(
+                                        all_objects.as_slice(),
+                                        dependency.as_str(),
+                                        &self.identifier_paths,
+                                    )
                                     .map(|object| object.is_assembled)
                                     .unwrap_or_default()
                             })
@@ -88,17 +189,20 @@ impl Build {
 
                 let mut assembled_objects_data = Vec::with_capacity(assembleable_objects.len());
                 for object in assembleable_objects.into_iter() {
-                    let assembled_object =
-                        match object.assemble(all_objects.as_slice(), cbor_data.clone()) {
-                            Ok(assembled_object) => assembled_object,
-                            Err(error) => {
-                                self.messages
-                                    .push(solx_standard_json::OutputError::new_error(
-                                        None, &error, None, None,
-                                    ));
-                                return Self::new(BTreeMap::new(), ast_jsons, &mut self.messages);
-                            }
-                        };
+                    let assembled_object = match object.assemble(
+                        all_objects.as_slice(),
+                        &self.identifier_paths,
+                        cbor_data.clone(),
+                    ) {
+                        Ok(assembled_object) => assembled_object,
+                        Err(error) => {
+                            self.messages
+                                .push(solx_standard_json::OutputError::new_error(
+                                    None, &error, None, None,
+                                ));
+                            return Self::new(BTreeMap::new(), ast_jsons, &mut self.messages);
+                        }
+                    };
                     assembled_objects_data.push((
                         object.contract_name.full_path.to_owned(),
                         object.code_segment,
@@ -117,6 +221,11 @@ impl Build {
                     era_compiler_common::CodeSegment::Runtime => &mut contract.runtime_object,
                 };
                 object.bytecode = Some(assembled_object.as_slice().to_owned());
+                if code_segment == era_compiler_common::CodeSegment::Runtime && cbor_data.is_some()
+                {
+                    object.metadata_length =
+                        Opcodes::cbor_metadata_length(assembled_object.as_slice());
+                }
                 for undefined_reference in assembled_object
                     .get_undefined_references_evm()
                     .into_iter()
@@ -290,7 +399,11 @@ impl Build {
                     contracts.get_mut(name.name.as_deref().unwrap_or(name.path.as_str()))
                 }) {
                 Some(contract) => {
-                    build.write_to_standard_json(contract, output_selection);
+                    build.write_to_standard_json(
+                        contract,
+                        output_selection,
+                        &standard_json.sources,
+                    );
                 }
                 None => {
                     let contracts = standard_json
@@ -298,15 +411,50 @@ impl Build {
                         .entry(name.path.clone())
                         .or_default();
                     let mut contract = solx_standard_json::OutputContract::default();
-                    build.write_to_standard_json(&mut contract, output_selection);
+                    build.write_to_standard_json(
+                        &mut contract,
+                        output_selection,
+                        &standard_json.sources,
+                    );
                     contracts.insert(name.name.unwrap_or(name.path), contract);
                 }
             }
         }
 
+        if output_selection.is_ethdebug_set_for_any() {
+            standard_json.ethdebug = Some(Self::ethdebug_compilation(&standard_json.sources));
+        }
+
         standard_json.errors.extend(errors);
         Ok(())
     }
+
+    ///
+    /// Returns the ethdebug compilation data, which describes the compiler and the sources
+    /// referenced by the ethdebug programs of the contracts.
+    ///
+    fn ethdebug_compilation(
+        sources: &BTreeMap<String, solx_standard_json::OutputSource>,
+    ) -> serde_json::Value {
+        let sources = sources
+            .iter()
+            .map(|(path, source)| {
+                serde_json::json!({
+                    "id": source.id,
+                    "path": path,
+                })
+            })
+            .collect::<Vec<serde_json::Value>>();
+        serde_json::json!({
+            "compilation": {
+                "compiler": {
+                    "name": crate::r#const::DEFAULT_EXECUTABLE_NAME,
+                    "version": crate::r#const::version(),
+                },
+                "sources": sources,
+            }
+        })
+    }
 }
 
 impl solx_standard_json::CollectableError for Build {
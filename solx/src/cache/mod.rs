@@ -0,0 +1,191 @@
+//!
+//! The on-disk compilation cache.
+//!
+
+pub mod stats;
+
+use std::ffi::OsStr;
+use std::io::Write;
+use std::path::PathBuf;
+
+use crate::process::input::Input as EVMInput;
+use crate::process::output::Output as EVMOutput;
+
+use self::stats::Stats;
+
+///
+/// The on-disk compilation cache.
+///
+/// Stores the outputs of contract compilation processes, keyed by the hash of the process input
+/// together with the `solx`, `solc`, and LLVM versions. The input includes the contract IR, the
+/// optimizer settings, the extra LLVM options, and the output selection.
+///
+/// Cache I/O errors are not fatal, as the cache is only an optimization: unreadable entries are
+/// treated as misses, and failed writes are skipped.
+///
+#[derive(Debug)]
+pub struct Cache {
+    /// The cache directory.
+    pub directory: PathBuf,
+    /// The total size of entries in bytes, above which the least recently used ones are evicted.
+    pub max_size: u64,
+    /// Whether to print the statistics to `stderr` after compilation.
+    pub is_stats_reported: bool,
+    /// The statistics of the current run.
+    pub stats: Stats,
+    /// The compiler versions the entries are only valid for.
+    versions: serde_json::Value,
+}
+
+impl Cache {
+    /// The default maximum size of the cache directory in bytes.
+    pub const DEFAULT_MAX_SIZE: u64 = 1 << 30;
+
+    /// The cache entry file extension.
+    pub const EXTENSION: &'static str = "json";
+
+    /// The temporary cache entry file extension.
+    pub const EXTENSION_TEMPORARY: &'static str = "tmp";
+
+    ///
+    /// Creates the cache directory if it does not exist.
+    ///
+    pub fn try_new(directory: PathBuf, is_stats_reported: bool) -> anyhow::Result<Self> {
+        std::fs::create_dir_all(directory.as_path()).map_err(|error| {
+            anyhow::anyhow!("Cache directory {directory:?} creation error: {error}")
+        })?;
+
+        let solc_version = solx_solc::Compiler::default().version;
+        let versions = serde_json::json!({
+            "solx": crate::r#const::version(),
+            "solc": solc_version.long,
+            "solc_llvm_revision": solc_version.llvm_revision,
+            "llvm": inkwell::support::get_commit_id().to_string(),
+        });
+
+        Ok(Self {
+            directory,
+            max_size: Self::DEFAULT_MAX_SIZE,
+            is_stats_reported,
+            stats: Stats::default(),
+            versions,
+        })
+    }
+
+    ///
+    /// Returns the cache key of the process input.
+    ///
+    /// Returns `None` if the input must not be cached, that is, if it has side effects such as
+    /// writing debug artifacts.
+    ///
+    pub fn key(&self, input: &EVMInput) -> Option<String> {
+        if input.debug_config.is_some() {
+            return None;
+        }
+
+        let preimage = serde_json::to_vec(&serde_json::json!({
+            "input": input,
+            "versions": self.versions,
+        }))
+        .expect("Always valid");
+        Some(era_compiler_common::Keccak256Hash::from_slice(preimage.as_slice()).to_string())
+    }
+
+    ///
+    /// Returns the cached output, and marks the entry as recently used.
+    ///
+    pub fn get(&self, key: &str) -> Option<EVMOutput> {
+        let path = self.entry_path(key);
+        let output = std::fs::read(path.as_path())
+            .ok()
+            .and_then(|data| era_compiler_common::deserialize_from_slice(data.as_slice()).ok());
+        match output {
+            Some(output) => {
+                let _ = std::fs::File::options()
+                    .write(true)
+                    .open(path.as_path())
+                    .and_then(|file| file.set_modified(std::time::SystemTime::now()));
+                self.stats.hit();
+                Some(output)
+            }
+            None => {
+                self.stats.miss();
+                None
+            }
+        }
+    }
+
+    ///
+    /// Stores the output.
+    ///
+    /// The entry is written to a temporary file first, so concurrent runs never read partial data.
+    ///
+    pub fn insert(&self, key: &str, output: &EVMOutput) {
+        let path = self.entry_path(key);
+        let temporary_path = path.with_extension(format!(
+            "{}.{}",
+            std::process::id(),
+            Self::EXTENSION_TEMPORARY
+        ));
+
+        let data = serde_json::to_vec(output).expect("Always valid");
+        if std::fs::write(temporary_path.as_path(), data.as_slice()).is_err()
+            || std::fs::rename(temporary_path.as_path(), path.as_path()).is_err()
+        {
+            let _ = std::fs::remove_file(temporary_path.as_path());
+        }
+    }
+
+    ///
+    /// Removes the least recently used entries until the cache fits into `max_size`.
+    ///
+    pub fn evict(&self) {
+        let entries = match std::fs::read_dir(self.directory.as_path()) {
+            Ok(entries) => entries,
+            Err(_) => return,
+        };
+        let mut entries = entries
+            .filter_map(|entry| {
+                let path = entry.ok()?.path();
+                if path.extension() != Some(OsStr::new(Self::EXTENSION)) {
+                    return None;
+                }
+                let metadata = path.metadata().ok()?;
+                Some((metadata.modified().ok()?, metadata.len(), path))
+            })
+            .collect::<Vec<(std::time::SystemTime, u64, PathBuf)>>();
+        entries.sort();
+
+        let mut size = entries.iter().map(|(_, length, _)| length).sum::<u64>();
+        for (_, length, path) in entries.into_iter() {
+            if size <= self.max_size {
+                break;
+            }
+            if std::fs::remove_file(path.as_path()).is_ok() {
+                size -= length;
+                self.stats.evict();
+            }
+        }
+    }
+
+    ///
+    /// Evicts the least recently used entries, and prints the statistics if requested.
+    ///
+    /// Must be called once per run, after all contracts have been compiled, including the ones
+    /// recompiled via the pipeline fallback.
+    ///
+    pub fn finish(&self) -> anyhow::Result<()> {
+        self.evict();
+        if self.is_stats_reported {
+            writeln!(std::io::stderr(), "{}", self.stats)?;
+        }
+        Ok(())
+    }
+
+    ///
+    /// Returns the path of the entry file.
+    ///
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.directory.join(format!("{key}.{}", Self::EXTENSION))
+    }
+}
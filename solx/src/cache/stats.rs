@@ -0,0 +1,56 @@
+//!
+//! The compilation cache statistics.
+//!
+
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+///
+/// The compilation cache statistics.
+///
+/// Counters are atomic, as contracts are compiled in parallel.
+///
+#[derive(Debug, Default)]
+pub struct Stats {
+    /// The number of contracts reused from the cache.
+    pub hits: AtomicUsize,
+    /// The number of contracts compiled and stored in the cache.
+    pub misses: AtomicUsize,
+    /// The number of entries removed in order to fit the cache size limit.
+    pub evictions: AtomicUsize,
+}
+
+impl Stats {
+    ///
+    /// Records a cache hit.
+    ///
+    pub fn hit(&self) {
+        self.hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    ///
+    /// Records a cache miss.
+    ///
+    pub fn miss(&self) {
+        self.misses.fetch_add(1, Ordering::Relaxed);
+    }
+
+    ///
+    /// Records an evicted entry.
+    ///
+    pub fn evict(&self) {
+        self.evictions.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+impl std::fmt::Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Compilation cache: {} hits, {} misses, {} evictions",
+            self.hits.load(Ordering::Relaxed),
+            self.misses.load(Ordering::Relaxed),
+            self.evictions.load(Ordering::Relaxed),
+        )
+    }
+}
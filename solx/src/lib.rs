@@ -10,14 +10,18 @@
 #![allow(clippy::result_large_err)]
 
 pub mod build;
+pub mod cache;
 pub mod r#const;
 pub mod process;
 pub mod project;
 pub mod yul;
 
+pub use self::build::contract::object::Object as EVMContractObject;
 pub use self::build::contract::Contract as EVMContractBuild;
 pub use self::build::Build as EVMBuild;
+pub use self::cache::Cache;
 pub use self::process::input::Input as EVMProcessInput;
+pub use self::process::mode::Mode as EVMProcessMode;
 pub use self::process::output::Output as EVMProcessOutput;
 pub use self::process::run as run_recursive;
 pub use self::process::EXECUTABLE;
@@ -41,17 +45,20 @@ pub fn yul_to_evm(
     libraries: &[String],
     output_selection: &solx_standard_json::InputSelection,
     messages: &mut Vec<solx_standard_json::OutputError>,
+    evm_version: Option<era_compiler_common::EVMVersion>,
     metadata_hash_type: era_compiler_common::EVMMetadataHashType,
     append_cbor: bool,
     optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
     llvm_options: Vec<String>,
     debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+    cache: Option<Cache>,
+    process_mode: EVMProcessMode,
 ) -> anyhow::Result<EVMBuild> {
     let libraries = era_compiler_common::Libraries::try_from(libraries)?;
     let linker_symbols = libraries.as_linker_symbols()?;
 
     let solc_compiler = solx_solc::Compiler::default();
-    solc_compiler.validate_yul_paths(paths, libraries.clone(), messages)?;
+    solc_compiler.validate_yul_paths(paths, libraries.clone(), evm_version, messages)?;
 
     let project = Project::try_from_yul_paths(
         paths,
@@ -64,11 +71,17 @@ pub fn yul_to_evm(
     let mut build = project.compile_to_evm(
         messages,
         output_selection,
+        evm_version,
         metadata_hash_type,
         optimizer_settings,
         llvm_options,
         debug_config,
+        cache.as_ref(),
+        process_mode,
     )?;
+    if let Some(cache) = cache.as_ref() {
+        cache.finish()?;
+    }
     build.take_and_write_warnings();
     build.check_errors()?;
 
@@ -109,25 +122,102 @@ pub fn llvm_ir_to_evm(
     libraries: &[String],
     output_selection: &solx_standard_json::InputSelection,
     messages: &mut Vec<solx_standard_json::OutputError>,
+    evm_version: Option<era_compiler_common::EVMVersion>,
+    metadata_hash_type: era_compiler_common::EVMMetadataHashType,
+    append_cbor: bool,
+    optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
+    llvm_options: Vec<String>,
+    debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+    cache: Option<Cache>,
+    process_mode: EVMProcessMode,
+) -> anyhow::Result<EVMBuild> {
+    let libraries = era_compiler_common::Libraries::try_from(libraries)?;
+    let linker_symbols = libraries.as_linker_symbols()?;
+
+    let project =
+        Project::try_from_llvm_ir_paths(paths, libraries, output_selection, evm_version, None)?;
+
+    let mut build = project.compile_to_evm(
+        messages,
+        output_selection,
+        evm_version,
+        metadata_hash_type,
+        optimizer_settings,
+        llvm_options,
+        debug_config,
+        cache.as_ref(),
+        process_mode,
+    )?;
+    if let Some(cache) = cache.as_ref() {
+        cache.finish()?;
+    }
+    build.take_and_write_warnings();
+    build.check_errors()?;
+
+    let cbor_data = if append_cbor {
+        Some(vec![(
+            crate::r#const::DEFAULT_EXECUTABLE_NAME.to_owned(),
+            crate::r#const::version().parse().expect("Always valid"),
+        )])
+    } else {
+        None
+    };
+
+    Ok(if output_selection.is_bytecode_set_for_any() {
+        let mut build = build.link(linker_symbols, cbor_data);
+        build.take_and_write_warnings();
+        build.check_errors()?;
+        build
+    } else {
+        build
+    })
+}
+
+///
+/// Runs the EVM assembly mode for the EVM target.
+///
+pub fn evm_assembly_to_evm(
+    paths: &[PathBuf],
+    libraries: &[String],
+    output_selection: &solx_standard_json::InputSelection,
+    messages: &mut Vec<solx_standard_json::OutputError>,
+    evm_version: Option<era_compiler_common::EVMVersion>,
+    ethir_limits: solx_evm_assembly::EtherealIRLimits,
+    recover_internal_functions: bool,
     metadata_hash_type: era_compiler_common::EVMMetadataHashType,
     append_cbor: bool,
     optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
     llvm_options: Vec<String>,
     debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+    cache: Option<Cache>,
+    process_mode: EVMProcessMode,
 ) -> anyhow::Result<EVMBuild> {
     let libraries = era_compiler_common::Libraries::try_from(libraries)?;
     let linker_symbols = libraries.as_linker_symbols()?;
 
-    let project = Project::try_from_llvm_ir_paths(paths, libraries, output_selection, None)?;
+    let project = Project::try_from_evm_assembly_paths(
+        paths,
+        libraries,
+        output_selection,
+        ethir_limits,
+        recover_internal_functions,
+        None,
+    )?;
 
     let mut build = project.compile_to_evm(
         messages,
         output_selection,
+        evm_version,
         metadata_hash_type,
         optimizer_settings,
         llvm_options,
         debug_config,
+        cache.as_ref(),
+        process_mode,
     )?;
+    if let Some(cache) = cache.as_ref() {
+        cache.finish()?;
+    }
     build.take_and_write_warnings();
     build.check_errors()?;
 
@@ -150,9 +240,56 @@ pub fn llvm_ir_to_evm(
     })
 }
 
+///
+/// Runs the link mode for the EVM target.
+///
+/// Links the library addresses into the hexadecimal bytecode files produced earlier, and
+/// rewrites the files in place. The placeholders of missing libraries are reported as warnings.
+///
+pub fn link_evm(
+    paths: &[PathBuf],
+    libraries: &[String],
+    messages: &mut Vec<solx_standard_json::OutputError>,
+) -> anyhow::Result<()> {
+    let libraries = era_compiler_common::Libraries::try_from(libraries)?;
+    let linker_symbols = libraries.as_linker_symbols()?;
+
+    for path in paths.iter() {
+        let source_location =
+            solx_standard_json::OutputErrorSourceLocation::new(path.to_string_lossy().to_string());
+
+        let file = std::fs::read(path)
+            .map_err(|error| anyhow::anyhow!("File {path:?} reading: {error}"))?;
+        let bytecode_hex = String::from_utf8(file).map_err(|_| {
+            anyhow::anyhow!(
+                "File {path:?} linking: Expected hexadecimal bytecode. Raw binary bytecode is not supported"
+            )
+        })?;
+
+        let (linked_bytecode_hex, unresolved_placeholders) =
+            EVMContractObject::link_placeholders(bytecode_hex.as_str(), &linker_symbols)
+                .map_err(|error| anyhow::anyhow!("File {path:?} linking: {error}"))?;
+        std::fs::write(path, linked_bytecode_hex)
+            .map_err(|error| anyhow::anyhow!("File {path:?} writing: {error}"))?;
+
+        messages.extend(unresolved_placeholders.into_iter().map(|placeholder| {
+            solx_standard_json::OutputError::new_warning(
+                None,
+                format!("Library placeholder `{placeholder}` is still unresolved."),
+                Some(source_location.clone()),
+                None,
+            )
+        }));
+    }
+
+    Ok(())
+}
+
 ///
 /// Runs the standard output mode for the EVM target.
 ///
+/// If `import_resolver` is set, it serves the imports instead of the filesystem.
+///
 pub fn standard_output_evm(
     paths: &[PathBuf],
     libraries: &[String],
@@ -160,6 +297,9 @@ pub fn standard_output_evm(
     messages: &mut Vec<solx_standard_json::OutputError>,
     evm_version: Option<era_compiler_common::EVMVersion>,
     via_ir: bool,
+    pipeline_fallback: bool,
+    ethir_limits: solx_evm_assembly::EtherealIRLimits,
+    recover_internal_functions: bool,
     metadata_hash_type: era_compiler_common::EVMMetadataHashType,
     metadata_literal: bool,
     append_cbor: bool,
@@ -167,10 +307,13 @@ pub fn standard_output_evm(
     include_paths: Vec<String>,
     allow_paths: Option<String>,
     use_import_callback: bool,
+    import_resolver: Option<&dyn solx_solc::ImportResolver>,
     remappings: BTreeSet<String>,
     optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
     llvm_options: Vec<String>,
     debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+    cache: Option<Cache>,
+    process_mode: EVMProcessMode,
 ) -> anyhow::Result<EVMBuild> {
     let mut solc_input = solx_standard_json::Input::try_from_solidity_paths(
         paths,
@@ -183,6 +326,8 @@ pub fn standard_output_evm(
         solx_standard_json::InputMetadata::new(metadata_literal, append_cbor, metadata_hash_type),
         llvm_options.clone(),
     )?;
+    solc_input.settings.ethir_limits = ethir_limits;
+    solc_input.settings.recover_internal_functions = recover_internal_functions;
 
     let solc_compiler = solx_solc::Compiler::default();
 
@@ -190,9 +335,10 @@ pub fn standard_output_evm(
         &mut solc_input,
         messages,
         use_import_callback,
-        base_path,
-        include_paths,
-        allow_paths,
+        base_path.clone(),
+        include_paths.clone(),
+        allow_paths.clone(),
+        import_resolver,
     )?;
     solc_output.take_and_write_warnings();
     solc_output.check_errors()?;
@@ -200,8 +346,10 @@ pub fn standard_output_evm(
     let linker_symbols = solc_input.settings.libraries.as_linker_symbols()?;
 
     let project = Project::try_from_solc_output(
-        solc_input.settings.libraries,
+        solc_input.settings.libraries.clone(),
         via_ir,
+        ethir_limits,
+        recover_internal_functions,
         &mut solc_output,
         debug_config.as_ref(),
     )?;
@@ -211,11 +359,36 @@ pub fn standard_output_evm(
     let mut build = project.compile_to_evm(
         messages,
         &solc_input.settings.output_selection,
+        evm_version,
         metadata_hash_type,
-        optimizer_settings,
-        llvm_options,
-        debug_config,
+        optimizer_settings.clone(),
+        llvm_options.clone(),
+        debug_config.clone(),
+        cache.as_ref(),
+        process_mode,
     )?;
+    if pipeline_fallback {
+        compile_pipeline_fallback(
+            &mut build,
+            &solc_compiler,
+            &mut solc_input,
+            use_import_callback,
+            base_path,
+            include_paths,
+            allow_paths,
+            import_resolver,
+            metadata_hash_type,
+            optimizer_settings,
+            llvm_options,
+            debug_config,
+            cache.as_ref(),
+            process_mode,
+        )?;
+    }
+    if let Some(cache) = cache.as_ref() {
+        cache.finish()?;
+    }
+    build.format_errors_with_sources(&solc_input.sources);
     build.take_and_write_warnings();
     build.check_errors()?;
 
@@ -257,6 +430,9 @@ pub fn standard_output_evm(
 ///
 /// Runs the standard JSON mode for the EVM target.
 ///
+/// If `import_resolver` is set, it serves the imports instead of the filesystem.
+/// If `cache` is not set, the cache directory from the standard JSON settings is used, if any.
+///
 pub fn standard_json_evm(
     json_path: Option<PathBuf>,
     messages: &mut Vec<solx_standard_json::OutputError>,
@@ -264,13 +440,22 @@ pub fn standard_json_evm(
     include_paths: Vec<String>,
     allow_paths: Option<String>,
     use_import_callback: bool,
+    import_resolver: Option<&dyn solx_solc::ImportResolver>,
     debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+    cache: Option<Cache>,
+    process_mode: EVMProcessMode,
 ) -> anyhow::Result<()> {
     let solc_compiler = solx_solc::Compiler::default();
 
     let mut solc_input = solx_standard_json::Input::try_from(json_path.as_deref())?;
+    let cache = match (cache, solc_input.settings.cache_directory.take()) {
+        (Some(cache), _) => Some(cache),
+        (None, Some(cache_directory)) => Some(Cache::try_new(cache_directory, false)?),
+        (None, None) => None,
+    };
     let language = solc_input.language;
     let via_ir = solc_input.settings.via_ir;
+    let pipeline_fallback = solc_input.settings.pipeline_fallback;
     let linker_symbols = solc_input.settings.libraries.as_linker_symbols()?;
 
     let mut optimizer_settings = era_compiler_llvm_context::OptimizerSettings::try_from_cli(
@@ -320,17 +505,20 @@ pub fn standard_json_evm(
                 &mut solc_input,
                 messages,
                 use_import_callback,
-                base_path,
-                include_paths,
-                allow_paths,
+                base_path.clone(),
+                include_paths.clone(),
+                allow_paths.clone(),
+                import_resolver,
             )?;
             if solc_output.has_errors() {
                 solc_output.write_and_exit(&solc_input.settings.output_selection);
             }
 
             let project = Project::try_from_solc_output(
-                solc_input.settings.libraries,
+                solc_input.settings.libraries.clone(),
                 via_ir,
+                solc_input.settings.ethir_limits,
+                solc_input.settings.recover_internal_functions,
                 &mut solc_output,
                 debug_config.as_ref(),
             )?;
@@ -342,13 +530,13 @@ pub fn standard_json_evm(
         }
         solx_standard_json::InputLanguage::Yul => {
             let mut solc_output =
-                solc_compiler.validate_yul_standard_json(&mut solc_input, messages)?;
+                crate::yul::validation::validate(&solc_compiler, &mut solc_input, messages)?;
             if solc_output.has_errors() {
                 solc_output.write_and_exit(&solc_input.settings.output_selection);
             }
 
             let project = Project::try_from_yul_sources(
-                solc_input.sources,
+                solc_input.sources.clone(),
                 solc_input.settings.libraries,
                 &solc_input.settings.output_selection,
                 Some(&mut solc_output),
@@ -364,9 +552,27 @@ pub fn standard_json_evm(
             let mut solc_output = solx_standard_json::Output::new(&solc_input.sources, messages);
 
             let project = Project::try_from_llvm_ir_sources(
-                solc_input.sources,
+                solc_input.sources.clone(),
+                solc_input.settings.libraries,
+                &solc_input.settings.output_selection,
+                solc_input.settings.evm_version,
+                Some(&mut solc_output),
+            )?;
+            if solc_output.has_errors() {
+                solc_output.write_and_exit(&solc_input.settings.output_selection);
+            }
+
+            (solc_output, project)
+        }
+        solx_standard_json::InputLanguage::EVMAssembly => {
+            let mut solc_output = solx_standard_json::Output::new(&solc_input.sources, messages);
+
+            let project = Project::try_from_evm_assembly_sources(
+                solc_input.sources.clone(),
                 solc_input.settings.libraries,
                 &solc_input.settings.output_selection,
+                solc_input.settings.ethir_limits,
+                solc_input.settings.recover_internal_functions,
                 Some(&mut solc_output),
             )?;
             if solc_output.has_errors() {
@@ -377,14 +583,39 @@ pub fn standard_json_evm(
         }
     };
 
-    let build = project.compile_to_evm(
+    let mut build = project.compile_to_evm(
         messages,
         &solc_input.settings.output_selection,
+        solc_input.settings.evm_version,
         metadata_hash_type,
-        optimizer_settings,
-        llvm_options,
-        debug_config,
+        optimizer_settings.clone(),
+        llvm_options.clone(),
+        debug_config.clone(),
+        cache.as_ref(),
+        process_mode,
     )?;
+    if pipeline_fallback && language == solx_standard_json::InputLanguage::Solidity {
+        compile_pipeline_fallback(
+            &mut build,
+            &solc_compiler,
+            &mut solc_input,
+            use_import_callback,
+            base_path,
+            include_paths,
+            allow_paths,
+            import_resolver,
+            metadata_hash_type,
+            optimizer_settings,
+            llvm_options,
+            debug_config,
+            cache.as_ref(),
+            process_mode,
+        )?;
+    }
+    if let Some(cache) = cache.as_ref() {
+        cache.finish()?;
+    }
+    build.format_errors_with_sources(&solc_input.sources);
     if build.has_errors() {
         build.write_to_standard_json(&mut solc_output, &solc_input.settings.output_selection)?;
         solc_output.write_and_exit(&solc_input.settings.output_selection);
@@ -402,3 +633,132 @@ pub fn standard_json_evm(
     build.write_to_standard_json(&mut solc_output, &solc_input.settings.output_selection)?;
     solc_output.write_and_exit(&solc_input.settings.output_selection);
 }
+
+///
+/// Recompiles the contracts that failed with stack-too-deep errors or exceeded the Ethereal IR
+/// limits via the other `solc` codegen.
+///
+/// The other IR is requested from `solc` for the whole input, as the failed contracts may depend
+/// on the others, but only the failed contracts are recompiled. If `solc` cannot produce the other
+/// IR, the original errors are kept, and the `solc` errors are reported as warnings.
+///
+fn compile_pipeline_fallback(
+    build: &mut EVMBuild,
+    solc_compiler: &solx_solc::Compiler,
+    solc_input: &mut solx_standard_json::Input,
+    use_import_callback: bool,
+    base_path: Option<String>,
+    include_paths: Vec<String>,
+    allow_paths: Option<String>,
+    import_resolver: Option<&dyn solx_solc::ImportResolver>,
+    metadata_hash_type: era_compiler_common::EVMMetadataHashType,
+    optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
+    llvm_options: Vec<String>,
+    debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+    cache: Option<&Cache>,
+    process_mode: EVMProcessMode,
+) -> anyhow::Result<()> {
+    let paths = build.pipeline_fallback_paths();
+    if paths.is_empty() {
+        return Ok(());
+    }
+
+    let via_ir = solc_input.settings.via_ir;
+    let codegen = if via_ir { "EVM assembly" } else { "Yul IR" };
+    let mut messages = Vec::new();
+    solc_input.settings.via_ir = !via_ir;
+    let solc_output = solc_compiler.standard_json(
+        solc_input,
+        &mut messages,
+        use_import_callback,
+        base_path,
+        include_paths,
+        allow_paths,
+        import_resolver,
+    );
+    solc_input.settings.via_ir = via_ir;
+    let mut solc_output = match solc_output {
+        Ok(solc_output) => solc_output,
+        Err(error) => {
+            build
+                .messages
+                .push(solx_standard_json::OutputError::new_warning(
+                    None,
+                    format!("Pipeline fallback via {codegen} failed: {error}"),
+                    None,
+                    None,
+                ));
+            return Ok(());
+        }
+    };
+    if solc_output.has_errors() {
+        push_pipeline_fallback_errors(build, codegen, solc_output.errors);
+        return Ok(());
+    }
+
+    let project = Project::try_from_solc_output(
+        solc_input.settings.libraries.clone(),
+        !via_ir,
+        solc_input.settings.ethir_limits,
+        solc_input.settings.recover_internal_functions,
+        &mut solc_output,
+        debug_config.as_ref(),
+    );
+    let mut project = match project {
+        Ok(project) if !solc_output.has_errors() => project,
+        Ok(_) => {
+            push_pipeline_fallback_errors(build, codegen, solc_output.errors);
+            return Ok(());
+        }
+        Err(error) => {
+            build
+                .messages
+                .push(solx_standard_json::OutputError::new_warning(
+                    None,
+                    format!("Pipeline fallback via {codegen} failed: {error}"),
+                    None,
+                    None,
+                ));
+            return Ok(());
+        }
+    };
+    project.retain_pipeline_fallback(&paths);
+
+    let fallback_build = project.compile_to_evm(
+        &mut vec![],
+        &solc_input.settings.output_selection,
+        solc_input.settings.evm_version,
+        metadata_hash_type,
+        optimizer_settings,
+        llvm_options,
+        debug_config,
+        cache,
+        process_mode,
+    )?;
+    build.apply_pipeline_fallback(fallback_build, via_ir);
+    Ok(())
+}
+
+///
+/// Reports the errors that prevented the pipeline fallback via `codegen` as warnings, as the
+/// original errors of the failed contracts are kept.
+///
+fn push_pipeline_fallback_errors(
+    build: &mut EVMBuild,
+    codegen: &str,
+    errors: Vec<solx_standard_json::OutputError>,
+) {
+    build.messages.extend(
+        errors
+            .into_iter()
+            .filter(|error| error.severity == "error")
+            .map(|error| {
+                solx_standard_json::OutputError::new_warning(
+                    None,
+                    format!("Pipeline fallback via {codegen} failed: {}", error.message),
+                    error.source_location,
+                    None,
+                )
+            }),
+    );
+}
@@ -0,0 +1,86 @@
+//!
+//! The recursive process failure.
+//!
+
+use std::time::Duration;
+
+///
+/// The recursive process failure.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    /// The process could not be spawned.
+    Spawning(String),
+    /// The process could not be communicated with, or its output is malformed.
+    Communication(String),
+    /// An LLVM assertion or fatal error, with the reported message.
+    LLVMAssertion(String),
+    /// The process ran out of memory.
+    OutOfMemory,
+    /// The process was terminated by a signal.
+    Signal(i32),
+    /// The process did not finish in time and was killed.
+    Timeout(Duration),
+    /// The process exited with a failure code.
+    ExitCode(Option<i32>),
+}
+
+impl Failure {
+    /// The LLVM assertion messages prefixes.
+    pub const LLVM_ASSERTION_PATTERNS: [&'static str; 3] =
+        ["Assertion `", "Assertion failed", "LLVM ERROR:"];
+
+    /// The out-of-memory messages of Rust and C++ allocators.
+    pub const OUT_OF_MEMORY_PATTERNS: [&'static str; 3] =
+        ["memory allocation of", "std::bad_alloc", "out of memory"];
+
+    ///
+    /// Classifies the failure of a finished process by its exit status and `stderr`.
+    ///
+    /// A process killed by the OOM killer cannot be told apart from one killed by other means,
+    /// so it is only reported as out of memory if `stderr` says so.
+    ///
+    pub fn classify(status: std::process::ExitStatus, stderr: &str) -> Self {
+        if let Some(line) = stderr.lines().find(|line| {
+            Self::LLVM_ASSERTION_PATTERNS
+                .iter()
+                .any(|pattern| line.contains(pattern))
+        }) {
+            return Self::LLVMAssertion(line.trim().to_owned());
+        }
+        if Self::OUT_OF_MEMORY_PATTERNS
+            .iter()
+            .any(|pattern| stderr.contains(pattern))
+        {
+            return Self::OutOfMemory;
+        }
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::process::ExitStatusExt;
+
+            if let Some(signal) = status.signal() {
+                return Self::Signal(signal);
+            }
+        }
+
+        Self::ExitCode(status.code())
+    }
+}
+
+impl std::fmt::Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Spawning(error) => write!(f, "subprocess spawning error: {error}"),
+            Self::Communication(error) => write!(f, "subprocess communication error: {error}"),
+            Self::LLVMAssertion(message) => write!(f, "LLVM assertion failure: {message}"),
+            Self::OutOfMemory => write!(f, "out of memory"),
+            Self::Signal(signal) => write!(f, "terminated by signal {signal}"),
+            Self::Timeout(timeout) => {
+                write!(f, "timed out after {} seconds", timeout.as_secs())
+            }
+            Self::ExitCode(Some(code)) => write!(f, "exited with code {code}"),
+            Self::ExitCode(None) => write!(f, "exited without code"),
+        }
+    }
+}
@@ -19,6 +19,8 @@ pub struct Input {
     pub identifier_paths: BTreeMap<String, String>,
     /// Output selection for the compilation.
     pub output_selection: solx_standard_json::InputSelection,
+    /// The target EVM version.
+    pub evm_version: Option<era_compiler_common::EVMVersion>,
     /// The metadata hash type.
     pub metadata_hash_type: era_compiler_common::EVMMetadataHashType,
     /// The optimizer settings.
@@ -37,6 +39,7 @@ impl Input {
         contract: Contract,
         identifier_paths: BTreeMap<String, String>,
         output_selection: solx_standard_json::InputSelection,
+        evm_version: Option<era_compiler_common::EVMVersion>,
         metadata_hash_type: era_compiler_common::EVMMetadataHashType,
         optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
         llvm_options: Vec<String>,
@@ -46,6 +49,7 @@ impl Input {
             contract,
             identifier_paths,
             output_selection,
+            evm_version,
             metadata_hash_type,
             optimizer_settings,
             llvm_options,
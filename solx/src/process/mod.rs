@@ -2,14 +2,25 @@
 //! Process for compiling a single compilation unit.
 //!
 
+pub mod failure;
 pub mod input;
+pub mod mode;
 pub mod output;
 
+use std::io::Read;
 use std::io::Write;
+use std::path::Path;
 use std::path::PathBuf;
+use std::process::Child;
 use std::process::Command;
+use std::process::ExitStatus;
 use std::sync::OnceLock;
+use std::sync::RwLock;
 use std::thread::Builder;
+use std::time::Duration;
+use std::time::Instant;
+
+use self::failure::Failure;
 
 use self::input::Input as EVMInput;
 use self::output::Output as EVMOutput;
@@ -17,6 +28,27 @@ use self::output::Output as EVMOutput;
 /// The overridden executable name used when the compiler is run as a library.
 pub static EXECUTABLE: OnceLock<PathBuf> = OnceLock::new();
 
+/// The timeout of a single contract compilation process, after which it is killed.
+pub static TIMEOUT: OnceLock<Duration> = OnceLock::new();
+
+/// The directory to dump the inputs of failed compilation processes to.
+pub static REPRODUCER_DIRECTORY: OnceLock<PathBuf> = OnceLock::new();
+
+/// The interval of polling the process status when [`TIMEOUT`] is set.
+const TIMEOUT_POLLING_INTERVAL: Duration = Duration::from_millis(10);
+
+/// The number of trailing `stderr` lines included into the failure errors.
+const STDERR_TAIL_LINES: usize = 32;
+
+/// The lock of the LLVM options in in-process compilation, as they are global in LLVM.
+///
+/// Compilations with extra LLVM options parse them, so they hold the lock exclusively, whereas
+/// the other compilations share it.
+static LLVM_OPTIONS_LOCK: RwLock<()> = RwLock::new(());
+
+/// The thread pool for in-process compilation, shared by all compilation runs in the process.
+static THREAD_POOL: OnceLock<rayon::ThreadPool> = OnceLock::new();
+
 ///
 /// Read input from `stdin`, compile a contract, and write the output to `stdout`.
 ///
@@ -26,32 +58,9 @@ pub fn run() -> anyhow::Result<()> {
     let input: EVMInput = era_compiler_common::deserialize_from_str(input_json.as_str())
         .map_err(|error| anyhow::anyhow!("Stdin parsing error: {error}"))?;
 
-    let source_location =
-        solx_standard_json::OutputErrorSourceLocation::new(input.contract.name.path.clone());
-
     let result = Builder::new()
         .stack_size(crate::WORKER_THREAD_STACK_SIZE)
-        .spawn(move || {
-            input
-                .contract
-                .compile_to_evm(
-                    input.identifier_paths,
-                    input.output_selection,
-                    input.metadata_hash_type,
-                    input.optimizer_settings,
-                    input.llvm_options,
-                    input.debug_config,
-                )
-                .map(EVMOutput::new)
-                .map_err(|error| {
-                    solx_standard_json::OutputError::new_error(
-                        None,
-                        error,
-                        Some(source_location),
-                        None,
-                    )
-                })
-        })
+        .spawn(move || compile(input))
         .expect("Threading error")
         .join()
         .expect("Threading error");
@@ -62,9 +71,89 @@ pub fn run() -> anyhow::Result<()> {
     Ok(())
 }
 
+///
+/// Compiles a single contract in the current thread.
+///
+pub fn compile(input: EVMInput) -> crate::Result<EVMOutput> {
+    let source_location =
+        solx_standard_json::OutputErrorSourceLocation::new(input.contract.name.path.clone());
+
+    input
+        .contract
+        .compile_to_evm(
+            input.identifier_paths,
+            input.output_selection,
+            input.evm_version,
+            input.metadata_hash_type,
+            input.optimizer_settings,
+            input.llvm_options,
+            input.debug_config,
+        )
+        .map(EVMOutput::new)
+        .map_err(|error| {
+            let source_location =
+                crate::yul::error::Error::source_location(&error).unwrap_or(source_location);
+            solx_standard_json::OutputError::new_error(None, error, Some(source_location), None)
+        })
+}
+
+///
+/// Returns the thread pool for in-process compilation, creating it on the first call.
+///
+/// The pool threads have at least [`crate::WORKER_THREAD_STACK_SIZE`] of stack.
+///
+pub fn thread_pool() -> &'static rayon::ThreadPool {
+    THREAD_POOL.get_or_init(|| {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(rayon::current_num_threads())
+            .stack_size(crate::WORKER_THREAD_STACK_SIZE)
+            .build()
+            .expect("Thread pool configuration failure")
+    })
+}
+
+///
+/// Compiles a single contract in the current process.
+///
+/// Must be called from a thread with at least [`crate::WORKER_THREAD_STACK_SIZE`] of stack,
+/// such as the ones of [`thread_pool`].
+///
+/// If the compilation panics, it is retried with [`call`], so the panic is reported the same way
+/// as in the subprocess mode. Only Rust panics can be recovered from: LLVM aborts, such as failed
+/// assertions and fatal errors, and segmentation faults terminate the whole process.
+///
+/// The extra LLVM options are global in LLVM and cannot be reset. Compilations that set them
+/// never run concurrently with other ones, but the options stay in effect for all later
+/// compilations in the process.
+///
+pub fn call_in_process(path: &str, input: EVMInput) -> crate::Result<EVMOutput> {
+    let fallback_input = input.clone();
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(move || {
+        if input.llvm_options.is_empty() {
+            let _lock = LLVM_OPTIONS_LOCK
+                .read()
+                .unwrap_or_else(|poison| poison.into_inner());
+            compile(input)
+        } else {
+            let _lock = LLVM_OPTIONS_LOCK
+                .write()
+                .unwrap_or_else(|poison| poison.into_inner());
+            compile(input)
+        }
+    }));
+    match result {
+        Ok(result) => result,
+        Err(_) => call(path, fallback_input),
+    }
+}
+
 ///
 /// Runs this process recursively to compile a single contract.
 ///
+/// The process failures are classified, and reported as errors located at the contract path.
+/// If [`REPRODUCER_DIRECTORY`] is set, the input of a failed process is dumped there, so the
+/// failure can be replayed with `solx --recursive-process <path> < <reproducer>`.
+///
 pub fn call<I, O>(path: &str, input: I) -> crate::Result<O>
 where
     I: serde::Serialize,
@@ -74,60 +163,180 @@ where
         .get()
         .cloned()
         .unwrap_or_else(|| std::env::current_exe().expect("Current executable path getting error"));
+    let stdin_input = serde_json::to_vec(&input).expect("Always valid");
 
-    let mut command = Command::new(executable.as_path());
+    let (stdout, stderr) = match execute(executable.as_path(), path, stdin_input.as_slice()) {
+        Ok(output) => output,
+        Err((failure, stderr)) => {
+            return Err(failure_error(
+                path,
+                failure,
+                stderr.as_str(),
+                stdin_input.as_slice(),
+            ))
+        }
+    };
+
+    match era_compiler_common::deserialize_from_slice(stdout.as_slice()) {
+        Ok(output) => output,
+        Err(error) => Err(failure_error(
+            path,
+            Failure::Communication(format!("stdout parsing: {error}")),
+            stderr.as_str(),
+            stdin_input.as_slice(),
+        )),
+    }
+}
+
+///
+/// Runs the process and returns its `stdout` and `stderr` if it has succeeded.
+///
+/// The output pipes are drained on separate threads, so the process never blocks on them.
+/// The `stderr` of a successful process is forwarded, as it may contain LLVM debug logs.
+///
+fn execute(
+    executable: &Path,
+    path: &str,
+    input: &[u8],
+) -> Result<(Vec<u8>, String), (Failure, String)> {
+    let mut command = Command::new(executable);
     command.stdin(std::process::Stdio::piped());
     command.stdout(std::process::Stdio::piped());
-    // Disabled for debugging purpose
-    // command.stderr(std::process::Stdio::piped());
+    command.stderr(std::process::Stdio::piped());
     command.arg("--recursive-process");
     command.arg(path);
 
-    let mut process = command
-        .spawn()
-        .unwrap_or_else(|error| panic!("{executable:?} subprocess spawning: {error:?}"));
+    let mut process = command.spawn().map_err(|error| {
+        (
+            Failure::Spawning(format!("{executable:?}: {error}")),
+            String::new(),
+        )
+    })?;
 
-    let stdin = process
-        .stdin
-        .as_mut()
-        .unwrap_or_else(|| panic!("{executable:?} subprocess stdin getting error"));
-    let stdin_input = serde_json::to_vec(&input).expect("Always valid");
-    stdin
-        .write_all(stdin_input.as_slice())
-        .unwrap_or_else(|error| panic!("{executable:?} subprocess stdin writing: {error:?}"));
-
-    let result = process
-        .wait_with_output()
-        .unwrap_or_else(|error| panic!("{executable:?} subprocess output reading: {error:?}"));
-
-    if result.status.code() != Some(era_compiler_common::EXIT_CODE_SUCCESS) {
-        let message = format!(
-            "{executable:?} subprocess failed {}:\n{}\n{}",
-            match result.status.code() {
-                Some(code) => format!("with exit code {code:?}"),
-                None => "without exit code".to_owned(),
-            },
-            String::from_utf8_lossy(result.stdout.as_slice()),
-            String::from_utf8_lossy(result.stderr.as_slice()),
-        );
-        return Err(solx_standard_json::OutputError::new_error(
-            None,
-            message,
-            Some(solx_standard_json::OutputErrorSourceLocation::new(
-                path.to_owned(),
-            )),
-            None,
-        ));
+    let mut stdout = process.stdout.take().expect("Always exists");
+    let stdout_reader = std::thread::spawn(move || {
+        let mut buffer = Vec::new();
+        stdout.read_to_end(&mut buffer).map(|_| buffer)
+    });
+    let mut stderr = process.stderr.take().expect("Always exists");
+    let stderr_reader = std::thread::spawn(move || {
+        let mut buffer = Vec::new();
+        stderr.read_to_end(&mut buffer).map(|_| buffer)
+    });
+
+    // A writing error means that the process has exited early, which is reported by its status.
+    if let Some(mut stdin) = process.stdin.take() {
+        let _ = stdin.write_all(input);
     }
 
-    match era_compiler_common::deserialize_from_slice(result.stdout.as_slice()) {
-        Ok(output) => output,
+    let status = wait(&mut process);
+    let stdout = stdout_reader.join().expect("Threading error");
+    let stderr = stderr_reader
+        .join()
+        .expect("Threading error")
+        .map(|stderr| String::from_utf8_lossy(stderr.as_slice()).into_owned())
+        .unwrap_or_default();
+
+    let status = match status {
+        Ok(status) => status,
+        Err(failure) => return Err((failure, stderr)),
+    };
+    if status.code() != Some(era_compiler_common::EXIT_CODE_SUCCESS) {
+        return Err((Failure::classify(status, stderr.as_str()), stderr));
+    }
+    let stdout = match stdout {
+        Ok(stdout) => stdout,
         Err(error) => {
-            panic!(
-                "{executable:?} subprocess stdout parsing error: {error:?}\n{}\n{}",
-                String::from_utf8_lossy(result.stdout.as_slice()),
-                String::from_utf8_lossy(result.stderr.as_slice()),
-            );
+            return Err((
+                Failure::Communication(format!("stdout reading: {error}")),
+                stderr,
+            ))
+        }
+    };
+
+    let _ = std::io::stderr().write_all(stderr.as_bytes());
+    Ok((stdout, stderr))
+}
+
+///
+/// Waits for the process to finish, killing it after [`TIMEOUT`], if set.
+///
+fn wait(process: &mut Child) -> Result<ExitStatus, Failure> {
+    let timeout = match TIMEOUT.get() {
+        Some(timeout) => *timeout,
+        None => {
+            return process
+                .wait()
+                .map_err(|error| Failure::Communication(format!("waiting: {error}")))
+        }
+    };
+
+    let start = Instant::now();
+    loop {
+        match process.try_wait() {
+            Ok(Some(status)) => return Ok(status),
+            Ok(None) if start.elapsed() >= timeout => {
+                let _ = process.kill();
+                let _ = process.wait();
+                return Err(Failure::Timeout(timeout));
+            }
+            Ok(None) => std::thread::sleep(TIMEOUT_POLLING_INTERVAL),
+            Err(error) => return Err(Failure::Communication(format!("waiting: {error}"))),
         }
     }
 }
+
+///
+/// Creates the error of a failed process, dumping the reproducer if requested.
+///
+/// Only the last lines of `stderr` are included, as LLVM stack dumps may be very long.
+///
+fn failure_error(
+    path: &str,
+    failure: Failure,
+    stderr: &str,
+    input: &[u8],
+) -> solx_standard_json::OutputError {
+    let mut message = format!("Contract compilation failure: {failure}");
+
+    if let Some(directory) = REPRODUCER_DIRECTORY.get() {
+        let reproducer_path = directory.join(format!(
+            "{}.{}",
+            path.replace(['\\', '/', ':', '.'], "_"),
+            era_compiler_common::EXTENSION_JSON
+        ));
+        match std::fs::create_dir_all(directory)
+            .and_then(|_| std::fs::write(reproducer_path.as_path(), input))
+        {
+            Ok(()) => message.push_str(
+                format!(
+                    "\nReplay it with `solx --recursive-process '{path}' < '{}'`.",
+                    reproducer_path.to_string_lossy(),
+                )
+                .as_str(),
+            ),
+            Err(error) => message.push_str(
+                format!("\nReproducer {reproducer_path:?} writing error: {error}.").as_str(),
+            ),
+        }
+    }
+
+    let lines = stderr.trim().lines().collect::<Vec<&str>>();
+    if !lines.is_empty() {
+        let skipped = lines.len().saturating_sub(STDERR_TAIL_LINES);
+        message.push_str("\n\n");
+        if skipped > 0 {
+            message.push_str(format!("[{skipped} lines of stderr skipped]\n").as_str());
+        }
+        message.push_str(lines[skipped..].join("\n").as_str());
+    }
+
+    solx_standard_json::OutputError::new_error(
+        None,
+        message,
+        Some(solx_standard_json::OutputErrorSourceLocation::new(
+            path.to_owned(),
+        )),
+        None,
+    )
+}
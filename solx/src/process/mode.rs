@@ -0,0 +1,19 @@
+//!
+//! The compilation process mode.
+//!
+
+///
+/// The compilation process mode.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// Each contract is compiled in a recursive child process.
+    #[default]
+    Subprocess,
+    /// Each contract is compiled in the current process on a large-stack worker thread.
+    ///
+    /// Contracts are still compiled in separate LLVM contexts. If the compilation panics,
+    /// it is retried in a child process, so the panic is reported as its failure. LLVM aborts
+    /// and segmentation faults cannot be recovered from, and terminate the whole process.
+    InProcess,
+}
@@ -2,6 +2,7 @@
 //! The contract EVM legacy assembly source code.
 //!
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
 ///
@@ -14,21 +15,78 @@ pub struct EVMLegacyAssembly {
 }
 
 impl EVMLegacyAssembly {
+    ///
+    /// Returns the contract name of the EVM assembly file at `path`.
+    ///
+    /// The name is the file name up to its first extension, e.g. `Test` for `path/Test.asm.json`.
+    ///
+    pub fn contract_name(path: &str) -> String {
+        let file_name = path.rsplit(['/', '\\']).next().unwrap_or(path);
+        match file_name.split('.').next() {
+            Some(stem) if !stem.is_empty() => stem.to_owned(),
+            _ => file_name.to_owned(),
+        }
+    }
+
     ///
     /// Transforms the `solc` standard JSON output contract into an EVM legacy assembly object.
     ///
+    /// If `recover_internal_functions` is set, the `function_definitions` are used to name the
+    /// internal functions recovered from the assembly.
+    /// See [`solx_evm_assembly::Assembly::resolve_function_names`].
+    ///
     pub fn try_from_contract(
         mut assembly: solx_evm_assembly::Assembly,
         extra_metadata: Option<solx_evm_assembly::ExtraMetadata>,
+        function_definitions: &BTreeMap<(isize, isize, isize), String>,
+        ethir_limits: solx_evm_assembly::EtherealIRLimits,
+        recover_internal_functions: bool,
     ) -> Option<Self> {
+        assembly.resolve_function_names(function_definitions);
         assembly.extra_metadata = extra_metadata.clone();
+        assembly.ethir_limits = Some(ethir_limits);
+        assembly.recover_internal_functions = recover_internal_functions;
         if let Ok(runtime_code) = assembly.runtime_code_mut() {
             runtime_code.extra_metadata = extra_metadata;
+            runtime_code.ethir_limits = Some(ethir_limits);
+            runtime_code.recover_internal_functions = recover_internal_functions;
         }
 
         Some(Self { assembly })
     }
 
+    ///
+    /// Checks that the instructions of the deploy and runtime code are available in `evm_version`.
+    ///
+    pub fn check_evm_version(
+        &self,
+        evm_version: era_compiler_common::EVMVersion,
+    ) -> anyhow::Result<()> {
+        let runtime_code = self.assembly.runtime_code().ok();
+        for (code_segment, assembly) in [
+            (self.assembly.code_segment(), Some(&self.assembly)),
+            (era_compiler_common::CodeSegment::Runtime, runtime_code),
+        ] {
+            for (index, instruction) in assembly
+                .and_then(|assembly| assembly.code.as_deref())
+                .into_iter()
+                .flatten()
+                .enumerate()
+            {
+                let Some(required_evm_version) = instruction.name.evm_version() else {
+                    continue;
+                };
+                if required_evm_version > evm_version {
+                    anyhow::bail!(
+                        "{code_segment} code: the `{}` instruction at index {index} is only available since EVM version `{required_evm_version}`, but the target is `{evm_version}`",
+                        instruction.name,
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
     ///
     /// Get the list of unlinked deployable libraries.
     ///
@@ -2,6 +2,11 @@
 //! The contract LLVM IR source code.
 //!
 
+use std::collections::BTreeMap;
+
+use inkwell::types::AnyType;
+use inkwell::values::AnyValue;
+
 ///
 /// The contract LLVM IR source code.
 ///
@@ -11,13 +16,547 @@ pub struct LLVMIR {
     pub path: String,
     /// The LLVM IR source code.
     pub source: String,
+    /// The optional LLVM IR deploy code. If not set, the minimal deploy code is generated.
+    pub deploy_source: Option<String>,
 }
 
 impl LLVMIR {
+    /// The file name suffix of LLVM IR deploy code files.
+    pub const DEPLOY_CODE_SUFFIX: &'static str = ".deploy.ll";
+
+    /// The file name extension of LLVM IR runtime code files.
+    pub const RUNTIME_CODE_EXTENSION: &'static str = "ll";
+
+    /// The identifier custom deploy code refers to itself with, e.g. in `@llvm.evm.datasize`.
+    pub const DEPLOY_CODE_IDENTIFIER: &'static str = "deploy";
+
+    /// The identifier custom deploy code refers to its runtime code with.
+    pub const RUNTIME_CODE_IDENTIFIER: &'static str = "runtime";
+
+    /// The only supported target architecture.
+    pub const TARGET_ARCHITECTURE: &'static str = "evm";
+
+    /// The only supported data layout.
+    pub const DATA_LAYOUT: &'static str = "E-p:256:256-i256:256:256-S256-a:256:256";
+
+    /// The number of EVM address spaces: stack, heap, calldata, return data, code, storage, and
+    /// transient storage.
+    pub const ADDRESS_SPACES_COUNT: usize = 7;
+
+    /// The prefix of LLVM intrinsic names.
+    pub const INTRINSIC_PREFIX: &'static str = "llvm.";
+
+    /// The prefix of the EVM target intrinsic names. Such intrinsics are only accepted if they are
+    /// known to the EVM backend LLVM is built with.
+    pub const EVM_INTRINSIC_PREFIX: &'static str = "llvm.evm.";
+
+    /// The target-independent intrinsics supported by the EVM backend. The overloaded ones are
+    /// matched with any type suffix, e.g. `llvm.memcpy.p1.p4.i256` for `llvm.memcpy`.
+    pub const GENERIC_INTRINSICS: [&'static str; 43] = [
+        "llvm.abs",
+        "llvm.annotation",
+        "llvm.assume",
+        "llvm.bitreverse",
+        "llvm.bswap",
+        "llvm.ctlz",
+        "llvm.ctpop",
+        "llvm.cttz",
+        "llvm.dbg",
+        "llvm.donothing",
+        "llvm.expect",
+        "llvm.experimental.noalias.scope.decl",
+        "llvm.fshl",
+        "llvm.fshr",
+        "llvm.invariant.end",
+        "llvm.invariant.start",
+        "llvm.is.constant",
+        "llvm.launder.invariant.group",
+        "llvm.lifetime.end",
+        "llvm.lifetime.start",
+        "llvm.memcpy",
+        "llvm.memmove",
+        "llvm.memset",
+        "llvm.objectsize",
+        "llvm.ptr.annotation",
+        "llvm.sadd.sat",
+        "llvm.sadd.with.overflow",
+        "llvm.sideeffect",
+        "llvm.smax",
+        "llvm.smin",
+        "llvm.smul.with.overflow",
+        "llvm.ssub.sat",
+        "llvm.ssub.with.overflow",
+        "llvm.strip.invariant.group",
+        "llvm.trap",
+        "llvm.uadd.sat",
+        "llvm.uadd.with.overflow",
+        "llvm.umax",
+        "llvm.umin",
+        "llvm.umul.with.overflow",
+        "llvm.usub.sat",
+        "llvm.usub.with.overflow",
+        "llvm.var.annotation",
+    ];
+
     ///
     /// A shortcut constructor.
     ///
     pub fn new(path: String, source: String) -> Self {
-        Self { path, source }
+        Self {
+            path,
+            source,
+            deploy_source: None,
+        }
+    }
+
+    ///
+    /// Replaces the deploy and runtime code identifiers in the custom `deploy_source` with the
+    /// actual ones, which depend on the contract path.
+    ///
+    pub fn resolve_deploy_source(
+        deploy_source: &str,
+        deploy_code_identifier: &str,
+        runtime_code_identifier: &str,
+    ) -> String {
+        deploy_source
+            .replace(
+                format!("!\"{}\"", Self::DEPLOY_CODE_IDENTIFIER).as_str(),
+                format!("!\"{deploy_code_identifier}\"").as_str(),
+            )
+            .replace(
+                format!("!\"{}\"", Self::RUNTIME_CODE_IDENTIFIER).as_str(),
+                format!("!\"{runtime_code_identifier}\"").as_str(),
+            )
+    }
+
+    ///
+    /// Returns the path of the runtime code file that the deploy code file at `path` belongs to.
+    ///
+    /// Returns `None` if `path` is not a deploy code file path.
+    ///
+    pub fn runtime_code_path(path: &str) -> Option<String> {
+        path.strip_suffix(Self::DEPLOY_CODE_SUFFIX)
+            .map(|stem| format!("{stem}.{}", Self::RUNTIME_CODE_EXTENSION))
+    }
+
+    ///
+    /// Returns the path of the deploy code file that belongs to the runtime code file at `path`.
+    ///
+    pub fn deploy_code_path(path: &str) -> String {
+        let stem = path
+            .strip_suffix(format!(".{}", Self::RUNTIME_CODE_EXTENSION).as_str())
+            .unwrap_or(path);
+        format!("{stem}{}", Self::DEPLOY_CODE_SUFFIX)
+    }
+
+    ///
+    /// Validates the runtime and deploy code, returning all problems found.
+    ///
+    pub fn validate(
+        &self,
+        evm_version: Option<era_compiler_common::EVMVersion>,
+    ) -> Vec<solx_standard_json::OutputError> {
+        let mut errors =
+            Self::validate_source(self.path.as_str(), self.source.as_str(), evm_version);
+        if let Some(deploy_source) = self.deploy_source.as_deref() {
+            let deploy_path = Self::deploy_code_path(self.path.as_str());
+            errors.extend(Self::validate_source(
+                deploy_path.as_str(),
+                deploy_source,
+                evm_version,
+            ));
+        }
+        errors
+    }
+
+    ///
+    /// Validates the LLVM IR module in `source_code`, returning all problems found.
+    ///
+    /// Checks that the module can be parsed and verified, targets EVM, has a valid entry point,
+    /// only declares the intrinsics supported by the EVM backend and available in `evm_version`,
+    /// and only uses EVM address spaces.
+    ///
+    /// The entry point is the first function defined in the module. It must take no parameters
+    /// and must be visible outside of the module.
+    ///
+    pub fn validate_source(
+        path: &str,
+        source_code: &str,
+        evm_version: Option<era_compiler_common::EVMVersion>,
+    ) -> Vec<solx_standard_json::OutputError> {
+        let mut errors = Vec::new();
+
+        let llvm = inkwell::context::Context::create();
+        let memory_buffer = inkwell::memory_buffer::MemoryBuffer::create_from_memory_range_copy(
+            source_code.as_bytes(),
+            path,
+        );
+        let module = match llvm.create_module_from_ir(memory_buffer) {
+            Ok(module) => module,
+            Err(error) => {
+                let error = error.to_string();
+                let location = Self::parse_error_location(path, error.as_str())
+                    .map(|(line, column)| Self::line_offset(source_code, line) + column - 1);
+                errors.push(Self::error(
+                    path,
+                    source_code,
+                    location.map(|offset| (offset, offset + 1)),
+                    format!("Parsing: {error}"),
+                ));
+                return errors;
+            }
+        };
+
+        let target_triple = module.get_triple();
+        let target_triple = target_triple.as_str().to_string_lossy();
+        if !target_triple.is_empty()
+            && target_triple.split('-').next() != Some(Self::TARGET_ARCHITECTURE)
+        {
+            errors.push(Self::error(
+                path,
+                source_code,
+                Self::find(source_code, "target triple"),
+                format!(
+                    "Target triple `{target_triple}` is not supported. Expected the `{}` architecture",
+                    Self::TARGET_ARCHITECTURE
+                ),
+            ));
+        }
+        let data_layout = module.get_data_layout();
+        let data_layout = data_layout.as_str().to_string_lossy();
+        if !data_layout.is_empty() && data_layout != Self::DATA_LAYOUT {
+            errors.push(Self::error(
+                path,
+                source_code,
+                Self::find(source_code, "target datalayout"),
+                format!(
+                    "Data layout `{data_layout}` is not supported. Expected `{}`",
+                    Self::DATA_LAYOUT
+                ),
+            ));
+        }
+
+        let mut entry_point = None;
+        for function in module.get_functions() {
+            let name = function.get_name().to_string_lossy();
+            if function.count_basic_blocks() != 0 {
+                if entry_point.is_none() {
+                    entry_point = Some(function);
+                }
+                continue;
+            }
+
+            if name.starts_with(Self::INTRINSIC_PREFIX)
+                && !Self::is_supported_intrinsic(name.as_ref())
+            {
+                errors.push(Self::error(
+                    path,
+                    source_code,
+                    Self::find_function(source_code, "declare", name.as_ref()),
+                    format!("Intrinsic `@{name}` is not supported by the EVM target"),
+                ));
+                continue;
+            }
+
+            let Some(evm_version) = evm_version else {
+                continue;
+            };
+            if let Some(required_evm_version) = name
+                .strip_prefix(Self::EVM_INTRINSIC_PREFIX)
+                .and_then(solx_yul::evm_version::required)
+                .filter(|required_evm_version| *required_evm_version > evm_version)
+            {
+                errors.push(Self::error(
+                    path,
+                    source_code,
+                    Self::find_function(source_code, "declare", name.as_ref()),
+                    format!(
+                        "Intrinsic `@{name}` is only available since EVM version `{required_evm_version}`, but the target is `{evm_version}`"
+                    ),
+                ));
+            }
+        }
+        match entry_point {
+            Some(entry_point) => {
+                let name = entry_point.get_name().to_string_lossy();
+                if entry_point.count_params() != 0 {
+                    errors.push(Self::error(
+                        path,
+                        source_code,
+                        Self::find_function(source_code, "define", name.as_ref()),
+                        format!(
+                            "The entry point `@{name}` must not have parameters, as the EVM execution starts with an empty stack"
+                        ),
+                    ));
+                }
+                if matches!(
+                    entry_point.get_linkage(),
+                    inkwell::module::Linkage::Private | inkwell::module::Linkage::Internal
+                ) {
+                    errors.push(Self::error(
+                        path,
+                        source_code,
+                        Self::find_function(source_code, "define", name.as_ref()),
+                        format!(
+                            "The entry point `@{name}` must not have private or internal linkage, as it would be removed as unused"
+                        ),
+                    ));
+                }
+            }
+            None => {
+                errors.push(Self::error(
+                    path,
+                    source_code,
+                    None,
+                    "The module does not define any functions. At least one is required as the entry point",
+                ));
+            }
+        }
+
+        for global in module.get_globals() {
+            let name = global.get_name().to_string_lossy();
+            let unsupported_pointer_type = Self::unsupported_pointer_type(
+                global.as_pointer_value().get_type().as_any_type_enum(),
+            )
+            .or_else(|| Self::unsupported_pointer_type(global.get_value_type()));
+            if let Some(pointer_type) = unsupported_pointer_type {
+                errors.push(Self::address_space_error(
+                    path,
+                    source_code,
+                    Self::find(source_code, format!("@{name} =").as_str()),
+                    pointer_type,
+                ));
+            }
+        }
+        for function in module.get_functions() {
+            let name = function.get_name().to_string_lossy();
+            let keyword = if function.count_basic_blocks() == 0 {
+                "declare"
+            } else {
+                "define"
+            };
+            let function_range = Self::find_function(source_code, keyword, name.as_ref());
+            if let Some(pointer_type) =
+                Self::unsupported_pointer_type(function.get_type().as_any_type_enum())
+            {
+                errors.push(Self::address_space_error(
+                    path,
+                    source_code,
+                    function_range,
+                    pointer_type,
+                ));
+                continue;
+            }
+
+            'blocks: for block in function.get_basic_blocks() {
+                let mut instruction = block.get_first_instruction();
+                while let Some(current) = instruction {
+                    let unsupported_pointer_type =
+                        Self::unsupported_pointer_type(current.get_type()).or_else(|| {
+                            (0..current.get_num_operands())
+                                .filter_map(|index| current.get_operand(index))
+                                .filter_map(|operand| operand.left())
+                                .find_map(|operand| {
+                                    Self::unsupported_pointer_type(
+                                        operand.get_type().as_any_type_enum(),
+                                    )
+                                })
+                        });
+                    if let Some(pointer_type) = unsupported_pointer_type {
+                        let range = function_range
+                            .and_then(|(start, _)| {
+                                let instruction = current.print_to_string();
+                                let instruction = instruction.to_string_lossy();
+                                Self::find(&source_code[start..], instruction.trim())
+                                    .map(|(offset, end)| (start + offset, start + end))
+                            })
+                            .or(function_range);
+                        errors.push(Self::address_space_error(
+                            path,
+                            source_code,
+                            range,
+                            pointer_type,
+                        ));
+                        break 'blocks;
+                    }
+                    instruction = current.get_next_instruction();
+                }
+            }
+        }
+
+        if errors.is_empty() {
+            if let Err(error) = module.verify() {
+                errors.push(Self::error(
+                    path,
+                    source_code,
+                    None,
+                    format!("Verification: {error}"),
+                ));
+            }
+        }
+
+        errors
+    }
+
+    ///
+    /// Checks whether the intrinsic `name` is supported by the EVM backend.
+    ///
+    fn is_supported_intrinsic(name: &str) -> bool {
+        if name.starts_with(Self::EVM_INTRINSIC_PREFIX) {
+            return inkwell::intrinsics::Intrinsic::find(name).is_some();
+        }
+
+        Self::GENERIC_INTRINSICS.iter().any(|intrinsic| {
+            name.strip_prefix(intrinsic)
+                .is_some_and(|suffix| suffix.is_empty() || suffix.starts_with('.'))
+        })
+    }
+
+    ///
+    /// Returns the first pointer type in `r#type` that is not in an EVM address space.
+    ///
+    /// Aggregate, vector, and function types are searched recursively.
+    ///
+    fn unsupported_pointer_type(
+        r#type: inkwell::types::AnyTypeEnum<'_>,
+    ) -> Option<inkwell::types::PointerType<'_>> {
+        match r#type {
+            inkwell::types::AnyTypeEnum::PointerType(pointer_type) => {
+                let address_space = pointer_type.get_address_space();
+                (0..Self::ADDRESS_SPACES_COUNT as u16)
+                    .all(|supported| inkwell::AddressSpace::from(supported) != address_space)
+                    .then_some(pointer_type)
+            }
+            inkwell::types::AnyTypeEnum::ArrayType(array_type) => {
+                Self::unsupported_pointer_type(array_type.get_element_type().as_any_type_enum())
+            }
+            inkwell::types::AnyTypeEnum::VectorType(vector_type) => {
+                Self::unsupported_pointer_type(vector_type.get_element_type().as_any_type_enum())
+            }
+            inkwell::types::AnyTypeEnum::StructType(struct_type) => struct_type
+                .get_field_types()
+                .into_iter()
+                .find_map(|field_type| {
+                    Self::unsupported_pointer_type(field_type.as_any_type_enum())
+                }),
+            inkwell::types::AnyTypeEnum::FunctionType(function_type) => function_type
+                .get_return_type()
+                .into_iter()
+                .chain(function_type.get_param_types())
+                .find_map(|r#type| Self::unsupported_pointer_type(r#type.as_any_type_enum())),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Creates an error for the `pointer_type` that is not in an EVM address space.
+    ///
+    fn address_space_error(
+        path: &str,
+        source_code: &str,
+        range: Option<(usize, usize)>,
+        pointer_type: inkwell::types::PointerType<'_>,
+    ) -> solx_standard_json::OutputError {
+        let pointer_type = pointer_type.print_to_string();
+        let pointer_type = pointer_type.to_string_lossy();
+        let address_space = pointer_type
+            .strip_prefix("ptr addrspace(")
+            .and_then(|address_space| address_space.strip_suffix(')'))
+            .unwrap_or(pointer_type.as_ref());
+        Self::error(
+            path,
+            source_code,
+            range,
+            format!(
+                "Address space `{address_space}` is not supported. EVM address spaces are 0 to {}",
+                Self::ADDRESS_SPACES_COUNT - 1
+            ),
+        )
+    }
+
+    ///
+    /// Creates a validation error, pointing at the `range` of `source_code` if it is known.
+    ///
+    fn error<S>(
+        path: &str,
+        source_code: &str,
+        range: Option<(usize, usize)>,
+        message: S,
+    ) -> solx_standard_json::OutputError
+    where
+        S: std::fmt::Display,
+    {
+        let source_location = match range {
+            Some((start, end)) => solx_standard_json::OutputErrorSourceLocation::new_with_offsets(
+                path.to_owned(),
+                start as isize,
+                end as isize,
+            ),
+            None => solx_standard_json::OutputErrorSourceLocation::new(path.to_owned()),
+        };
+        let sources = BTreeMap::from([(
+            path.to_owned(),
+            solx_standard_json::InputSource::from(source_code.to_owned()),
+        )]);
+        solx_standard_json::OutputError::new_error(
+            None,
+            format!("LLVM IR validation: {message}"),
+            Some(source_location),
+            Some(&sources),
+        )
+    }
+
+    ///
+    /// Parses the 1-based line and column from an LLVM parser error message.
+    ///
+    /// The messages are formatted as `<path>:<line>:<column>: error: <message>`.
+    ///
+    fn parse_error_location(path: &str, error: &str) -> Option<(usize, usize)> {
+        let mut parts = error
+            .trim_start()
+            .strip_prefix(path)?
+            .strip_prefix(':')?
+            .splitn(3, ':');
+        let line = parts.next()?.parse::<usize>().ok()?;
+        let column = parts.next()?.parse::<usize>().ok()?;
+        Some((line, column.max(1)))
+    }
+
+    ///
+    /// Returns the byte offset of the 1-based `line` in `source_code`.
+    ///
+    fn line_offset(source_code: &str, line: usize) -> usize {
+        source_code
+            .split_inclusive('\n')
+            .take(line.saturating_sub(1))
+            .map(str::len)
+            .sum()
+    }
+
+    ///
+    /// Returns the byte range of the name of the function `name` in its declaration or definition
+    /// in `source_code`, depending on `keyword`.
+    ///
+    fn find_function(source_code: &str, keyword: &str, name: &str) -> Option<(usize, usize)> {
+        let pattern = format!("@{name}(");
+        let mut offset = 0;
+        for line in source_code.split_inclusive('\n') {
+            if line.trim_start().starts_with(keyword) {
+                if let Some(index) = line.find(pattern.as_str()) {
+                    return Some((offset + index, offset + index + pattern.len() - 1));
+                }
+            }
+            offset += line.len();
+        }
+        None
+    }
+
+    ///
+    /// Returns the byte range of the first occurrence of `pattern` in `source_code`.
+    ///
+    fn find(source_code: &str, pattern: &str) -> Option<(usize, usize)> {
+        source_code
+            .find(pattern)
+            .map(|offset| (offset, offset + pattern.len()))
     }
 }
@@ -2,10 +2,13 @@
 //! The contract Yul source code.
 //!
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
 use solx_yul::yul::lexer::Lexer;
 use solx_yul::yul::parser::statement::object::Object;
+use solx_yul::yul::visitor::function_calls::FunctionCalls;
+use solx_yul::yul::visitor::Visitor;
 
 use crate::yul::parser::dialect::era::EraDialect;
 use crate::yul::parser::wrapper::Wrap;
@@ -20,29 +23,61 @@ pub struct Yul {
 }
 
 impl Yul {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(object: Object<EraDialect>) -> Self {
+        Self {
+            object: object.wrap(),
+        }
+    }
+
     ///
     /// Transforms the `solc` standard JSON output contract into a Yul object.
     ///
+    /// Returns all syntax errors found in the source code, each pointing at its location.
+    ///
     pub fn try_from_source(
         path: &str,
         source_code: &str,
         debug_config: Option<&era_compiler_llvm_context::DebugConfig>,
-    ) -> anyhow::Result<Option<Self>> {
+    ) -> Result<Option<Self>, Vec<solx_standard_json::OutputError>> {
         if source_code.is_empty() {
             return Ok(None);
         };
 
-        if let Some(debug_config) = debug_config {
-            debug_config.dump_yul(path, source_code)?;
-        }
+        Self::dump(path, source_code, debug_config)?;
 
         let mut lexer = Lexer::new(source_code);
-        let object = Object::parse(&mut lexer, None, era_compiler_common::CodeSegment::Deploy)
-            .map_err(|error| anyhow::anyhow!("Yul parsing: {error:?}"))?;
+        let result = Object::parse(&mut lexer, None, era_compiler_common::CodeSegment::Deploy);
+        match result {
+            Ok(object) => Ok(Some(Self::new(object))),
+            Err(error) => Err(Self::syntax_errors(path, source_code, &mut lexer, error)),
+        }
+    }
 
-        Ok(Some(Self {
-            object: object.wrap(),
-        }))
+    ///
+    /// Transforms the Yul source code file into Yul objects, one per top-level object.
+    ///
+    /// Returns all syntax errors found in the source code, each pointing at its location.
+    ///
+    pub fn try_from_source_objects(
+        path: &str,
+        source_code: &str,
+        debug_config: Option<&era_compiler_llvm_context::DebugConfig>,
+    ) -> Result<Vec<Self>, Vec<solx_standard_json::OutputError>> {
+        if source_code.is_empty() {
+            return Ok(vec![]);
+        };
+
+        Self::dump(path, source_code, debug_config)?;
+
+        let mut lexer = Lexer::new(source_code);
+        let result = Object::parse_all(&mut lexer);
+        match result {
+            Ok(objects) => Ok(objects.into_iter().map(Self::new).collect()),
+            Err(error) => Err(Self::syntax_errors(path, source_code, &mut lexer, error)),
+        }
     }
 
     ///
@@ -52,6 +87,18 @@ impl Yul {
         self.object.0.inner_object.take().map(|object| *object)
     }
 
+    ///
+    /// Extracts the nested deploy objects from the Yul object and its runtime code, recursively.
+    ///
+    /// The objects are returned in the order of appearance, each with its own nested objects
+    /// already extracted.
+    ///
+    pub fn take_nested_objects(&mut self) -> Vec<Object<EraDialect>> {
+        let mut nested_objects = Vec::new();
+        Self::take_nested_objects_recursive(&mut self.object.0, &mut nested_objects);
+        nested_objects
+    }
+
     ///
     /// Get the list of unlinked deployable libraries.
     ///
@@ -59,6 +106,29 @@ impl Yul {
         self.object.0.get_unlinked_libraries()
     }
 
+    ///
+    /// Checks that the builtins of the deploy and runtime code are available in `evm_version`.
+    ///
+    pub fn check_evm_version(
+        &self,
+        evm_version: era_compiler_common::EVMVersion,
+    ) -> anyhow::Result<()> {
+        let mut function_calls = FunctionCalls::default();
+        function_calls.visit_object(&self.object.0);
+        for (name, location) in function_calls.calls.into_iter() {
+            let Some(required_evm_version) = name.evm_version() else {
+                continue;
+            };
+            if required_evm_version > evm_version {
+                anyhow::bail!(
+                    "{location} The `{}` instruction is only available since EVM version `{required_evm_version}`, but the target is `{evm_version}`",
+                    solx_yul::yul::printer::name_identifier(&name),
+                );
+            }
+        }
+        Ok(())
+    }
+
     ///
     /// Get the list of EVM dependencies.
     ///
@@ -68,6 +138,109 @@ impl Yul {
     ) -> solx_yul::Dependencies {
         self.object.0.get_evm_dependencies(runtime_code)
     }
+
+    ///
+    /// Get the EVM object dependencies of the deploy and runtime code, keyed by the object identifiers.
+    ///
+    /// References to data sections are not included, as they cannot depend on other objects.
+    ///
+    pub fn get_object_dependencies(&self) -> BTreeMap<String, Vec<String>> {
+        let deploy_code = &self.object.0;
+        let runtime_code = deploy_code.inner_object.as_deref();
+
+        let mut dependencies = BTreeMap::new();
+        for (object, runtime_code) in std::iter::once((deploy_code, runtime_code))
+            .chain(runtime_code.map(|runtime_code| (runtime_code, None)))
+        {
+            let data = object.get_evm_data();
+            let object_dependencies = object
+                .get_evm_dependencies(runtime_code)
+                .inner
+                .into_iter()
+                .filter(|dependency| !data.contains_key(dependency.as_str()))
+                .collect();
+            dependencies.insert(object.identifier.clone(), object_dependencies);
+        }
+        dependencies
+    }
+
+    ///
+    /// Dumps the Yul source code if requested by the debug configuration.
+    ///
+    fn dump(
+        path: &str,
+        source_code: &str,
+        debug_config: Option<&era_compiler_llvm_context::DebugConfig>,
+    ) -> Result<(), Vec<solx_standard_json::OutputError>> {
+        if let Some(debug_config) = debug_config {
+            debug_config.dump_yul(path, source_code).map_err(|error| {
+                vec![solx_standard_json::OutputError::new_error(
+                    None,
+                    error,
+                    Some(solx_standard_json::OutputErrorSourceLocation::new(
+                        path.to_owned(),
+                    )),
+                    None,
+                )]
+            })?;
+        }
+        Ok(())
+    }
+
+    ///
+    /// Converts the syntax errors recovered by the parser, or the fatal `error` if there are none,
+    /// into `solc`-compatible errors with source code snippets.
+    ///
+    fn syntax_errors(
+        path: &str,
+        source_code: &str,
+        lexer: &mut Lexer,
+        error: solx_yul::yul::error::Error,
+    ) -> Vec<solx_standard_json::OutputError> {
+        let mut errors = lexer.take_errors();
+        if errors.is_empty() {
+            errors.push(error);
+        }
+
+        let sources = BTreeMap::from([(
+            path.to_owned(),
+            solx_standard_json::InputSource::from(source_code.to_owned()),
+        )]);
+        errors
+            .into_iter()
+            .map(|error| {
+                let location = error.location();
+                let source_location =
+                    solx_standard_json::OutputErrorSourceLocation::new_with_offsets(
+                        path.to_owned(),
+                        location.offset as isize,
+                        (location.offset + error.length()) as isize,
+                    );
+                solx_standard_json::OutputError::new_error(
+                    None,
+                    format!("Yul parsing: {error}"),
+                    Some(source_location),
+                    Some(&sources),
+                )
+            })
+            .collect()
+    }
+
+    ///
+    /// Moves the nested objects of `object` into `nested_objects`, recursively.
+    ///
+    fn take_nested_objects_recursive(
+        object: &mut Object<EraDialect>,
+        nested_objects: &mut Vec<Object<EraDialect>>,
+    ) {
+        if let Some(inner_object) = object.inner_object.as_mut() {
+            Self::take_nested_objects_recursive(inner_object, nested_objects);
+        }
+        for mut nested_object in std::mem::take(&mut object.nested_objects).into_iter() {
+            Self::take_nested_objects_recursive(&mut nested_object, nested_objects);
+            nested_objects.push(nested_object);
+        }
+    }
 }
 
 impl era_compiler_llvm_context::EVMWriteLLVM for Yul {
@@ -19,6 +19,9 @@ pub struct Metadata<'a> {
     pub optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
     /// The LLVM extra arguments.
     pub llvm_options: &'a [String],
+    /// The `solc` codegen the contract was recompiled with after a stack-too-deep error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pipeline_fallback: Option<&'static str>,
 }
 
 impl<'a> Metadata<'a> {
@@ -28,6 +31,7 @@ impl<'a> Metadata<'a> {
     pub fn new(
         optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
         llvm_options: &'a [String],
+        pipeline_fallback: Option<&'static str>,
     ) -> Self {
         let solc_version = solx_solc::Compiler::default().version;
         Self {
@@ -36,6 +40,7 @@ impl<'a> Metadata<'a> {
             solx_version: crate::version().parse().expect("Always valid"),
             optimizer_settings,
             llvm_options,
+            pipeline_fallback,
         }
     }
 
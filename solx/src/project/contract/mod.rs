@@ -10,8 +10,11 @@ use std::fmt::Debug;
 
 use era_compiler_llvm_context::IContext;
 
+use crate::build::contract::function_debug_data::FunctionDebugData;
 use crate::build::contract::object::Object as EVMContractObject;
+use crate::build::contract::source_map::SourceMap;
 use crate::build::contract::Contract as EVMContractBuild;
+use crate::yul::error::Error as YulError;
 use crate::yul::parser::wrapper::Wrap;
 
 use self::ir::llvm_ir::LLVMIR;
@@ -45,9 +48,23 @@ pub struct Contract {
     pub legacy_assembly: Option<solx_evm_assembly::Assembly>,
     /// the solc optimized Yul IR assembly.
     pub ir_optimized: Option<String>,
+    /// Whether the contract is recompiled via the other `solc` codegen after a stack-too-deep error.
+    pub is_pipeline_fallback: bool,
 }
 
 impl Contract {
+    /// The metadata name of the `solc` IR codegen pipeline.
+    pub const PIPELINE_YUL: &'static str = "yul";
+
+    /// The metadata name of the `solc` EVM assembly codegen pipeline.
+    pub const PIPELINE_EVM_LEGACY_ASSEMBLY: &'static str = "evmla";
+
+    /// The prefix of the temporary directories the optimized LLVM IR is captured in.
+    pub const LLVM_IR_DIRECTORY_PREFIX: &'static str = "solx_llvm_ir";
+
+    /// The file name suffix of the optimized LLVM IR dumped by the build.
+    pub const LLVM_IR_OPTIMIZED_SUFFIX: &'static str = ".optimized.ll";
+
     ///
     /// A shortcut constructor.
     ///
@@ -76,6 +93,7 @@ impl Contract {
             transient_storage_layout,
             legacy_assembly,
             ir_optimized,
+            is_pipeline_fallback: false,
         }
     }
 
@@ -103,6 +121,7 @@ impl Contract {
         self,
         identifier_paths: BTreeMap<String, String>,
         output_selection: solx_standard_json::InputSelection,
+        evm_version: Option<era_compiler_common::EVMVersion>,
         metadata_hash_type: era_compiler_common::EVMMetadataHashType,
         optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
         llvm_options: Vec<String>,
@@ -116,9 +135,20 @@ impl Contract {
 
         let optimizer = era_compiler_llvm_context::Optimizer::new(optimizer_settings);
 
+        let pipeline_fallback = match self.ir {
+            IR::Yul(_) if self.is_pipeline_fallback => Some(Self::PIPELINE_YUL),
+            IR::EVMLegacyAssembly(_) if self.is_pipeline_fallback => {
+                Some(Self::PIPELINE_EVM_LEGACY_ASSEMBLY)
+            }
+            _ => None,
+        };
         let metadata = self.metadata.map(|metadata| {
-            Metadata::new(optimizer.settings().to_owned(), llvm_options.as_slice())
-                .insert_into(metadata.as_str())
+            Metadata::new(
+                optimizer.settings().to_owned(),
+                llvm_options.as_slice(),
+                pipeline_fallback,
+            )
+            .insert_into(metadata.as_str())
         });
         let metadata_bytes = metadata
             .as_ref()
@@ -136,17 +166,64 @@ impl Contract {
 
         match self.ir {
             IR::Yul(mut deploy_code) => {
+                if let Some(evm_version) = evm_version {
+                    deploy_code.check_evm_version(evm_version)?;
+                }
                 let runtime_code = deploy_code.take_runtime_code().ok_or_else(|| {
                     anyhow::anyhow!("Contract `{identifier}` has no runtime code")
                 })?;
 
                 let deploy_code_dependecies = deploy_code.get_evm_dependencies(Some(&runtime_code));
                 let runtime_code_dependecies = runtime_code.get_evm_dependencies(None);
+                let deploy_code_source_paths = deploy_code.object.0.source_paths.clone();
+                let runtime_code_source_paths = runtime_code.source_paths.clone();
+                let deploy_code_data = deploy_code.object.0.get_evm_data();
+                let runtime_code_data = runtime_code.get_evm_data();
+                let deploy_code_signatures = deploy_code.object.0.get_function_signatures();
+                let runtime_code_signatures = runtime_code.get_function_signatures();
+                let deploy_code_source_ranges = deploy_code.object.0.get_source_ranges();
+                let runtime_code_source_ranges = runtime_code.get_source_ranges();
                 let mut runtime_code = runtime_code.wrap();
 
                 let deploy_code_identifier = deploy_code.object.0.identifier.clone();
                 let runtime_code_identifier = runtime_code.0.identifier.clone();
 
+                let output_deploy_assembly = output_selection.check_selection(
+                    self.name.path.as_str(),
+                    self.name.name.as_deref(),
+                    solx_standard_json::InputSelector::BytecodeLLVMAssembly,
+                );
+                let output_deploy_function_debug_data = output_selection.check_selection(
+                    self.name.path.as_str(),
+                    self.name.name.as_deref(),
+                    solx_standard_json::InputSelector::BytecodeFunctionDebugData,
+                );
+                let output_deploy_source_map = output_selection.check_selection(
+                    self.name.path.as_str(),
+                    self.name.name.as_deref(),
+                    solx_standard_json::InputSelector::BytecodeSourceMap,
+                );
+                let output_runtime_assembly = output_selection.check_selection(
+                    self.name.path.as_str(),
+                    self.name.name.as_deref(),
+                    solx_standard_json::InputSelector::RuntimeBytecodeLLVMAssembly,
+                );
+                let output_runtime_function_debug_data = output_selection.check_selection(
+                    self.name.path.as_str(),
+                    self.name.name.as_deref(),
+                    solx_standard_json::InputSelector::RuntimeBytecodeFunctionDebugData,
+                );
+                let output_runtime_source_map = output_selection.check_selection(
+                    self.name.path.as_str(),
+                    self.name.name.as_deref(),
+                    solx_standard_json::InputSelector::RuntimeBytecodeSourceMap,
+                );
+
+                let runtime_llvm_ir_directory = Self::llvm_ir_optimized_directory(
+                    &self.name,
+                    runtime_code_segment,
+                    &output_selection,
+                )?;
                 let runtime_llvm = inkwell::context::Context::create();
                 let runtime_module = runtime_llvm.create_module(
                     format!("{}.{runtime_code_segment}", self.name.full_path).as_str(),
@@ -157,7 +234,7 @@ impl Contract {
                     llvm_options.clone(),
                     runtime_code_segment,
                     optimizer.clone(),
-                    debug_config.clone(),
+                    Self::debug_config(runtime_llvm_ir_directory.as_ref(), debug_config.as_ref()),
                 );
                 runtime_context.set_yul_data(era_compiler_llvm_context::EVMContextYulData::new(
                     identifier_paths.clone(),
@@ -166,31 +243,71 @@ impl Contract {
                 runtime_code
                     .into_llvm(&mut runtime_context)
                     .map_err(|error| {
-                        anyhow::anyhow!("{runtime_code_segment} code LLVM IR generator: {error}")
+                        YulError::resolve(
+                            error,
+                            &runtime_code_source_paths,
+                            format!("{runtime_code_segment} code LLVM IR generator"),
+                        )
                     })?;
+                let runtime_llvm_ir = Self::llvm_ir(
+                    &runtime_context,
+                    &self.name,
+                    runtime_code_segment,
+                    &output_selection,
+                );
                 let runtime_build = runtime_context.build(
-                    output_selection.check_selection(
-                        self.name.path.as_str(),
-                        self.name.name.as_deref(),
-                        solx_standard_json::InputSelector::RuntimeBytecodeLLVMAssembly,
-                    ),
+                    output_runtime_assembly
+                        || output_runtime_source_map
+                        || output_runtime_function_debug_data,
                     output_bytecode,
                     false,
                 )?;
+                let runtime_llvm_ir_optimized = Self::read_llvm_ir_optimized(
+                    runtime_llvm_ir_directory,
+                    debug_config.as_ref(),
+                    runtime_code_segment,
+                )?;
+                let runtime_source_map = runtime_build
+                    .assembly
+                    .as_deref()
+                    .filter(|_| output_runtime_source_map)
+                    .map(|assembly| {
+                        SourceMap::from_yul_assembly(assembly, &runtime_code_source_ranges)
+                            .to_string()
+                    });
+                let runtime_function_debug_data = runtime_build
+                    .assembly
+                    .as_deref()
+                    .filter(|_| output_runtime_function_debug_data)
+                    .map(|assembly| {
+                        FunctionDebugData::from_yul_assembly(assembly, runtime_code_signatures)
+                    });
                 let runtime_object = EVMContractObject::new(
                     runtime_code_identifier,
                     self.name.clone(),
                     runtime_build.assembly,
+                    runtime_llvm_ir,
+                    runtime_llvm_ir_optimized,
+                    None,
+                    runtime_source_map,
+                    runtime_function_debug_data,
                     runtime_build.bytecode,
+                    runtime_build.immutables.clone(),
                     true,
                     runtime_code_segment,
                     metadata_bytes,
                     runtime_code_dependecies,
+                    runtime_code_data,
                     runtime_build.warnings,
                 );
 
                 let immutables_map = runtime_build.immutables.unwrap_or_default();
 
+                let deploy_llvm_ir_directory = Self::llvm_ir_optimized_directory(
+                    &self.name,
+                    deploy_code_segment,
+                    &output_selection,
+                )?;
                 let deploy_llvm = inkwell::context::Context::create();
                 let deploy_module = deploy_llvm.create_module(self.name.full_path.as_str());
                 let mut deploy_context = era_compiler_llvm_context::EVMContext::new(
@@ -199,7 +316,7 @@ impl Contract {
                     llvm_options.clone(),
                     deploy_code_segment,
                     optimizer.clone(),
-                    debug_config.clone(),
+                    Self::debug_config(deploy_llvm_ir_directory.as_ref(), debug_config.as_ref()),
                 );
                 deploy_context.set_solidity_data(
                     era_compiler_llvm_context::EVMContextSolidityData::new(immutables_map),
@@ -211,26 +328,61 @@ impl Contract {
                 deploy_code
                     .into_llvm(&mut deploy_context)
                     .map_err(|error| {
-                        anyhow::anyhow!("{deploy_code_segment} code LLVM IR generator: {error}")
+                        YulError::resolve(
+                            error,
+                            &deploy_code_source_paths,
+                            format!("{deploy_code_segment} code LLVM IR generator"),
+                        )
                     })?;
+                let deploy_llvm_ir = Self::llvm_ir(
+                    &deploy_context,
+                    &self.name,
+                    deploy_code_segment,
+                    &output_selection,
+                );
                 let deploy_build = deploy_context.build(
-                    output_selection.check_selection(
-                        self.name.path.as_str(),
-                        self.name.name.as_deref(),
-                        solx_standard_json::InputSelector::BytecodeLLVMAssembly,
-                    ),
+                    output_deploy_assembly
+                        || output_deploy_source_map
+                        || output_deploy_function_debug_data,
                     output_bytecode,
                     false,
                 )?;
+                let deploy_llvm_ir_optimized = Self::read_llvm_ir_optimized(
+                    deploy_llvm_ir_directory,
+                    debug_config.as_ref(),
+                    deploy_code_segment,
+                )?;
+                let deploy_source_map = deploy_build
+                    .assembly
+                    .as_deref()
+                    .filter(|_| output_deploy_source_map)
+                    .map(|assembly| {
+                        SourceMap::from_yul_assembly(assembly, &deploy_code_source_ranges)
+                            .to_string()
+                    });
+                let deploy_function_debug_data = deploy_build
+                    .assembly
+                    .as_deref()
+                    .filter(|_| output_deploy_function_debug_data)
+                    .map(|assembly| {
+                        FunctionDebugData::from_yul_assembly(assembly, deploy_code_signatures)
+                    });
                 let deploy_object = EVMContractObject::new(
                     deploy_code_identifier,
                     self.name.clone(),
                     deploy_build.assembly,
+                    deploy_llvm_ir,
+                    deploy_llvm_ir_optimized,
+                    None,
+                    deploy_source_map,
+                    deploy_function_debug_data,
                     deploy_build.bytecode,
+                    None,
                     true,
                     deploy_code_segment,
                     None,
                     deploy_code_dependecies,
+                    deploy_code_data,
                     deploy_build.warnings,
                 );
 
@@ -242,6 +394,9 @@ impl Contract {
                 ))
             }
             IR::EVMLegacyAssembly(mut deploy_code) => {
+                if let Some(evm_version) = evm_version {
+                    deploy_code.check_evm_version(evm_version)?;
+                }
                 let mut runtime_code_assembly = deploy_code.assembly.runtime_code()?.to_owned();
                 runtime_code_assembly.set_full_path(deploy_code.assembly.full_path().to_owned());
 
@@ -256,9 +411,109 @@ impl Contract {
                     solx_yul::Dependencies::new(runtime_code_identifier.as_str());
                 runtime_code_assembly.accumulate_evm_dependencies(&mut runtime_code_dependecies);
 
+                let output_deploy_assembly = output_selection.check_selection(
+                    self.name.path.as_str(),
+                    self.name.name.as_deref(),
+                    solx_standard_json::InputSelector::BytecodeLLVMAssembly,
+                );
+                let output_deploy_source_map = output_selection.check_selection(
+                    self.name.path.as_str(),
+                    self.name.name.as_deref(),
+                    solx_standard_json::InputSelector::BytecodeSourceMap,
+                );
+                let output_runtime_assembly = output_selection.check_selection(
+                    self.name.path.as_str(),
+                    self.name.name.as_deref(),
+                    solx_standard_json::InputSelector::RuntimeBytecodeLLVMAssembly,
+                );
+                let output_runtime_source_map = output_selection.check_selection(
+                    self.name.path.as_str(),
+                    self.name.name.as_deref(),
+                    solx_standard_json::InputSelector::RuntimeBytecodeSourceMap,
+                );
+                let output_deploy_function_debug_data = output_selection.check_selection(
+                    self.name.path.as_str(),
+                    self.name.name.as_deref(),
+                    solx_standard_json::InputSelector::BytecodeFunctionDebugData,
+                );
+                let output_runtime_function_debug_data = output_selection.check_selection(
+                    self.name.path.as_str(),
+                    self.name.name.as_deref(),
+                    solx_standard_json::InputSelector::RuntimeBytecodeFunctionDebugData,
+                );
+                let output_deploy_ethir = output_selection.check_selection(
+                    self.name.path.as_str(),
+                    self.name.name.as_deref(),
+                    solx_standard_json::InputSelector::BytecodeEthIR,
+                );
+                let output_runtime_ethir = output_selection.check_selection(
+                    self.name.path.as_str(),
+                    self.name.name.as_deref(),
+                    solx_standard_json::InputSelector::RuntimeBytecodeEthIR,
+                );
+                let deploy_ethir = if output_deploy_ethir {
+                    Some(
+                        deploy_code
+                            .assembly
+                            .ethereal_ir(solc_version.default.to_owned())?
+                            .to_string(),
+                    )
+                } else {
+                    None
+                };
+                let runtime_ethir = if output_runtime_ethir {
+                    Some(
+                        runtime_code_assembly
+                            .ethereal_ir(solc_version.default.to_owned())?
+                            .to_string(),
+                    )
+                } else {
+                    None
+                };
+                let (deploy_instructions, runtime_instructions) =
+                    if output_deploy_source_map || output_runtime_source_map {
+                        (
+                            deploy_code.assembly.code.clone().unwrap_or_default(),
+                            runtime_code_assembly.code.clone().unwrap_or_default(),
+                        )
+                    } else {
+                        (vec![], vec![])
+                    };
+
+                let mut deploy_extra_metadata = deploy_code
+                    .assembly
+                    .extra_metadata
+                    .clone()
+                    .unwrap_or_default();
+                let mut runtime_extra_metadata = runtime_code_assembly
+                    .extra_metadata
+                    .clone()
+                    .unwrap_or_default();
+                if output_deploy_function_debug_data || output_runtime_function_debug_data {
+                    let deploy_internal_functions = deploy_code
+                        .assembly
+                        .internal_functions(solc_version.default.to_owned())?;
+                    let runtime_internal_functions = runtime_code_assembly
+                        .internal_functions(solc_version.default.to_owned())?;
+                    deploy_extra_metadata
+                        .recursive_functions
+                        .extend(deploy_internal_functions);
+                    deploy_extra_metadata
+                        .recursive_functions
+                        .extend(runtime_internal_functions.clone());
+                    runtime_extra_metadata
+                        .recursive_functions
+                        .extend(runtime_internal_functions);
+                }
+
                 let evmla_data =
                     era_compiler_llvm_context::EVMContextEVMLAData::new(solc_version.default);
 
+                let runtime_llvm_ir_directory = Self::llvm_ir_optimized_directory(
+                    &self.name,
+                    runtime_code_segment,
+                    &output_selection,
+                )?;
                 let runtime_llvm = inkwell::context::Context::create();
                 let runtime_module = runtime_llvm.create_module(runtime_code_identifier.as_str());
                 let mut runtime_context = era_compiler_llvm_context::EVMContext::new(
@@ -267,7 +522,7 @@ impl Contract {
                     llvm_options.clone(),
                     runtime_code_segment,
                     optimizer.clone(),
-                    debug_config.clone(),
+                    Self::debug_config(runtime_llvm_ir_directory.as_ref(), debug_config.as_ref()),
                 );
                 runtime_context.set_evmla_data(evmla_data.clone());
                 runtime_code_assembly.declare(&mut runtime_context)?;
@@ -276,29 +531,72 @@ impl Contract {
                     .map_err(|error| {
                         anyhow::anyhow!("{runtime_code_segment} code LLVM IR generator: {error}")
                     })?;
+                let runtime_llvm_ir = Self::llvm_ir(
+                    &runtime_context,
+                    &self.name,
+                    runtime_code_segment,
+                    &output_selection,
+                );
                 let runtime_build = runtime_context.build(
-                    output_selection.check_selection(
-                        self.name.path.as_str(),
-                        self.name.name.as_deref(),
-                        solx_standard_json::InputSelector::RuntimeBytecodeLLVMAssembly,
-                    ),
+                    output_runtime_assembly
+                        || output_runtime_source_map
+                        || output_runtime_function_debug_data,
                     output_bytecode,
                     false,
                 )?;
+                let runtime_llvm_ir_optimized = Self::read_llvm_ir_optimized(
+                    runtime_llvm_ir_directory,
+                    debug_config.as_ref(),
+                    runtime_code_segment,
+                )?;
+                let runtime_source_map = runtime_build
+                    .assembly
+                    .as_deref()
+                    .filter(|_| output_runtime_source_map)
+                    .map(|assembly| {
+                        SourceMap::from_evmla_assembly(
+                            assembly,
+                            runtime_instructions.as_slice(),
+                            runtime_instructions.as_slice(),
+                        )
+                        .to_string()
+                    });
+                let runtime_function_debug_data = runtime_build
+                    .assembly
+                    .as_deref()
+                    .filter(|_| output_runtime_function_debug_data)
+                    .map(|assembly| {
+                        FunctionDebugData::from_evmla_assembly(
+                            assembly,
+                            runtime_extra_metadata.get_functions(runtime_code_segment),
+                        )
+                    });
                 let runtime_object = EVMContractObject::new(
                     runtime_code_identifier,
                     self.name.clone(),
                     runtime_build.assembly,
+                    runtime_llvm_ir,
+                    runtime_llvm_ir_optimized,
+                    runtime_ethir,
+                    runtime_source_map,
+                    runtime_function_debug_data,
                     runtime_build.bytecode,
+                    runtime_build.immutables.clone(),
                     false,
                     runtime_code_segment,
                     metadata_bytes,
                     runtime_code_dependecies,
+                    BTreeMap::new(),
                     runtime_build.warnings,
                 );
 
                 let immutables_map = runtime_build.immutables.unwrap_or_default();
 
+                let deploy_llvm_ir_directory = Self::llvm_ir_optimized_directory(
+                    &self.name,
+                    deploy_code_segment,
+                    &output_selection,
+                )?;
                 let deploy_llvm = inkwell::context::Context::create();
                 let deploy_module = deploy_llvm.create_module(deploy_code_identifier.as_str());
                 let mut deploy_context = era_compiler_llvm_context::EVMContext::new(
@@ -307,7 +605,7 @@ impl Contract {
                     llvm_options.clone(),
                     deploy_code_segment,
                     optimizer.clone(),
-                    debug_config.clone(),
+                    Self::debug_config(deploy_llvm_ir_directory.as_ref(), debug_config.as_ref()),
                 );
                 deploy_context.set_solidity_data(
                     era_compiler_llvm_context::EVMContextSolidityData::new(immutables_map),
@@ -319,24 +617,62 @@ impl Contract {
                     .map_err(|error| {
                         anyhow::anyhow!("{deploy_code_segment} code LLVM IR generator: {error}")
                     })?;
+                let deploy_llvm_ir = Self::llvm_ir(
+                    &deploy_context,
+                    &self.name,
+                    deploy_code_segment,
+                    &output_selection,
+                );
                 let deploy_build = deploy_context.build(
-                    output_selection.check_selection(
-                        self.name.path.as_str(),
-                        self.name.name.as_deref(),
-                        solx_standard_json::InputSelector::BytecodeLLVMAssembly,
-                    ),
+                    output_deploy_assembly
+                        || output_deploy_source_map
+                        || output_deploy_function_debug_data,
                     output_bytecode,
                     false,
                 )?;
+                let deploy_llvm_ir_optimized = Self::read_llvm_ir_optimized(
+                    deploy_llvm_ir_directory,
+                    debug_config.as_ref(),
+                    deploy_code_segment,
+                )?;
+                let deploy_source_map = deploy_build
+                    .assembly
+                    .as_deref()
+                    .filter(|_| output_deploy_source_map)
+                    .map(|assembly| {
+                        SourceMap::from_evmla_assembly(
+                            assembly,
+                            deploy_instructions.as_slice(),
+                            runtime_instructions.as_slice(),
+                        )
+                        .to_string()
+                    });
+                let deploy_function_debug_data = deploy_build
+                    .assembly
+                    .as_deref()
+                    .filter(|_| output_deploy_function_debug_data)
+                    .map(|assembly| {
+                        let mut functions =
+                            deploy_extra_metadata.get_functions(deploy_code_segment);
+                        functions.extend(deploy_extra_metadata.get_functions(runtime_code_segment));
+                        FunctionDebugData::from_evmla_assembly(assembly, functions)
+                    });
                 let deploy_object = EVMContractObject::new(
                     deploy_code_identifier,
                     self.name.clone(),
                     deploy_build.assembly,
+                    deploy_llvm_ir,
+                    deploy_llvm_ir_optimized,
+                    deploy_ethir,
+                    deploy_source_map,
+                    deploy_function_debug_data,
                     deploy_build.bytecode,
+                    None,
                     false,
                     deploy_code_segment,
                     None,
                     deploy_code_dependencies,
+                    BTreeMap::new(),
                     deploy_build.warnings,
                 );
 
@@ -352,13 +688,18 @@ impl Contract {
                 let runtime_code_identifier =
                     format!("{}.{runtime_code_segment}", self.name.full_path);
 
-                let mut deploy_llvm_ir = LLVMIR::new(
-                    deploy_code_identifier.clone(),
-                    era_compiler_llvm_context::evm_minimal_deploy_code(
+                let deploy_source = match runtime_llvm_ir.deploy_source.take() {
+                    Some(deploy_source) => LLVMIR::resolve_deploy_source(
+                        deploy_source.as_str(),
                         deploy_code_identifier.as_str(),
                         runtime_code_identifier.as_str(),
                     ),
-                );
+                    None => era_compiler_llvm_context::evm_minimal_deploy_code(
+                        deploy_code_identifier.as_str(),
+                        runtime_code_identifier.as_str(),
+                    ),
+                };
+                let mut deploy_llvm_ir = LLVMIR::new(deploy_code_identifier.clone(), deploy_source);
                 deploy_llvm_ir.source.push(char::from(0));
                 let deploy_memory_buffer =
                     inkwell::memory_buffer::MemoryBuffer::create_from_memory_range(
@@ -381,69 +722,122 @@ impl Contract {
                 let runtime_code_dependencies =
                     solx_yul::Dependencies::new(runtime_code_identifier.as_str());
 
+                let runtime_llvm_ir_directory = Self::llvm_ir_optimized_directory(
+                    &self.name,
+                    runtime_code_segment,
+                    &output_selection,
+                )?;
                 let runtime_llvm = inkwell::context::Context::create();
                 let runtime_module = runtime_llvm
                     .create_module_from_ir(runtime_memory_buffer)
                     .map_err(|error| anyhow::anyhow!(error.to_string()))?;
+                runtime_module
+                    .verify()
+                    .map_err(|error| anyhow::anyhow!("LLVM IR verification: {error}"))?;
                 let runtime_context = era_compiler_llvm_context::EVMContext::new(
                     &runtime_llvm,
                     runtime_module,
                     llvm_options.clone(),
                     runtime_code_segment,
                     optimizer.clone(),
-                    debug_config.clone(),
+                    Self::debug_config(runtime_llvm_ir_directory.as_ref(), debug_config.as_ref()),
                 );
-                let runtime_build = runtime_context.build(
-                    output_selection.check_selection(
-                        self.name.path.as_str(),
-                        self.name.name.as_deref(),
-                        solx_standard_json::InputSelector::RuntimeBytecodeLLVMAssembly,
-                    ),
-                    output_bytecode,
-                    false,
+                let runtime_llvm_ir = Self::llvm_ir(
+                    &runtime_context,
+                    &self.name,
+                    runtime_code_segment,
+                    &output_selection,
+                );
+                let output_runtime_assembly = output_selection.check_selection(
+                    self.name.path.as_str(),
+                    self.name.name.as_deref(),
+                    solx_standard_json::InputSelector::RuntimeBytecodeLLVMAssembly,
+                );
+                let runtime_build =
+                    runtime_context.build(output_runtime_assembly, output_bytecode, false)?;
+                let runtime_llvm_ir_optimized = Self::read_llvm_ir_optimized(
+                    runtime_llvm_ir_directory,
+                    debug_config.as_ref(),
+                    runtime_code_segment,
                 )?;
                 let runtime_object = EVMContractObject::new(
                     runtime_code_identifier,
                     self.name.clone(),
                     runtime_build.assembly,
+                    runtime_llvm_ir,
+                    runtime_llvm_ir_optimized,
+                    None,
+                    None,
+                    None,
                     runtime_build.bytecode,
+                    runtime_build.immutables.clone(),
                     false,
                     runtime_code_segment,
                     metadata_bytes,
                     runtime_code_dependencies,
+                    BTreeMap::new(),
                     runtime_build.warnings,
                 );
 
+                let immutables_map = runtime_build.immutables.unwrap_or_default();
+
+                let deploy_llvm_ir_directory = Self::llvm_ir_optimized_directory(
+                    &self.name,
+                    deploy_code_segment,
+                    &output_selection,
+                )?;
                 let deploy_llvm = inkwell::context::Context::create();
                 let deploy_module = deploy_llvm
                     .create_module_from_ir(deploy_memory_buffer)
                     .map_err(|error| anyhow::anyhow!(error.to_string()))?;
-                let deploy_context = era_compiler_llvm_context::EVMContext::new(
+                deploy_module
+                    .verify()
+                    .map_err(|error| anyhow::anyhow!("LLVM IR verification: {error}"))?;
+                let mut deploy_context = era_compiler_llvm_context::EVMContext::new(
                     &deploy_llvm,
                     deploy_module,
-                    llvm_options,
+                    llvm_options.clone(),
                     deploy_code_segment,
-                    optimizer,
-                    debug_config,
+                    optimizer.clone(),
+                    Self::debug_config(deploy_llvm_ir_directory.as_ref(), debug_config.as_ref()),
                 );
-                let deploy_build = deploy_context.build(
-                    output_selection.check_selection(
-                        self.name.path.as_str(),
-                        self.name.name.as_deref(),
-                        solx_standard_json::InputSelector::BytecodeLLVMAssembly,
-                    ),
-                    output_bytecode,
-                    false,
+                deploy_context.set_solidity_data(
+                    era_compiler_llvm_context::EVMContextSolidityData::new(immutables_map),
+                );
+                let deploy_llvm_ir = Self::llvm_ir(
+                    &deploy_context,
+                    &self.name,
+                    deploy_code_segment,
+                    &output_selection,
+                );
+                let output_deploy_assembly = output_selection.check_selection(
+                    self.name.path.as_str(),
+                    self.name.name.as_deref(),
+                    solx_standard_json::InputSelector::BytecodeLLVMAssembly,
+                );
+                let deploy_build =
+                    deploy_context.build(output_deploy_assembly, output_bytecode, false)?;
+                let deploy_llvm_ir_optimized = Self::read_llvm_ir_optimized(
+                    deploy_llvm_ir_directory,
+                    debug_config.as_ref(),
+                    deploy_code_segment,
                 )?;
                 let deploy_object = EVMContractObject::new(
                     deploy_code_identifier,
                     self.name.clone(),
                     deploy_build.assembly,
+                    deploy_llvm_ir,
+                    deploy_llvm_ir_optimized,
+                    None,
+                    None,
+                    None,
                     deploy_build.bytecode,
+                    None,
                     false,
                     deploy_code_segment,
                     None,
                     deploy_code_dependencies,
+                    BTreeMap::new(),
                     deploy_build.warnings,
                 );
 
@@ -456,4 +850,112 @@ impl Contract {
             }
         }
     }
+
+    ///
+    /// Returns the unoptimized LLVM IR of the `context` module, if selected.
+    ///
+    fn llvm_ir(
+        context: &era_compiler_llvm_context::EVMContext,
+        name: &era_compiler_common::ContractName,
+        code_segment: era_compiler_common::CodeSegment,
+        output_selection: &solx_standard_json::InputSelection,
+    ) -> Option<String> {
+        let selector = match code_segment {
+            era_compiler_common::CodeSegment::Deploy => {
+                solx_standard_json::InputSelector::BytecodeLLVMIR
+            }
+            era_compiler_common::CodeSegment::Runtime => {
+                solx_standard_json::InputSelector::RuntimeBytecodeLLVMIR
+            }
+        };
+        output_selection
+            .check_selection(name.path.as_str(), name.name.as_deref(), selector)
+            .then(|| context.module().print_to_string().to_string())
+    }
+
+    ///
+    /// Creates a temporary directory for the optimized LLVM IR of `code_segment`, if selected.
+    ///
+    /// The build dumps the optimized LLVM IR there, so it is captured after the very optimization
+    /// pass the bytecode is generated from.
+    ///
+    fn llvm_ir_optimized_directory(
+        name: &era_compiler_common::ContractName,
+        code_segment: era_compiler_common::CodeSegment,
+        output_selection: &solx_standard_json::InputSelection,
+    ) -> anyhow::Result<Option<tempfile::TempDir>> {
+        let selector = match code_segment {
+            era_compiler_common::CodeSegment::Deploy => {
+                solx_standard_json::InputSelector::BytecodeLLVMIROptimized
+            }
+            era_compiler_common::CodeSegment::Runtime => {
+                solx_standard_json::InputSelector::RuntimeBytecodeLLVMIROptimized
+            }
+        };
+        if !output_selection.check_selection(name.path.as_str(), name.name.as_deref(), selector) {
+            return Ok(None);
+        }
+        tempfile::TempDir::with_prefix(Self::LLVM_IR_DIRECTORY_PREFIX)
+            .map(Some)
+            .map_err(|error| anyhow::anyhow!("{code_segment} code LLVM IR directory: {error}"))
+    }
+
+    ///
+    /// Returns the debug configuration of a code segment context.
+    ///
+    /// If the optimized LLVM IR is captured, the artifacts are dumped to its `directory` first.
+    ///
+    fn debug_config(
+        directory: Option<&tempfile::TempDir>,
+        debug_config: Option<&era_compiler_llvm_context::DebugConfig>,
+    ) -> Option<era_compiler_llvm_context::DebugConfig> {
+        match directory {
+            Some(directory) => Some(era_compiler_llvm_context::DebugConfig::new(
+                directory.path().to_owned(),
+            )),
+            None => debug_config.cloned(),
+        }
+    }
+
+    ///
+    /// Reads the optimized LLVM IR dumped by the build to `directory`, if it is captured.
+    ///
+    /// If the build is retried with the size fallback, the module is dumped once per attempt, so
+    /// the latest dump is taken, as the bytecode is generated from it. The dumped artifacts are
+    /// copied to the debug output directory, if it is set.
+    ///
+    fn read_llvm_ir_optimized(
+        directory: Option<tempfile::TempDir>,
+        debug_config: Option<&era_compiler_llvm_context::DebugConfig>,
+        code_segment: era_compiler_common::CodeSegment,
+    ) -> anyhow::Result<Option<String>> {
+        let Some(directory) = directory else {
+            return Ok(None);
+        };
+
+        let mut dumps = Vec::new();
+        for entry in std::fs::read_dir(directory.path())? {
+            let entry = entry?;
+            let path = entry.path();
+            if path
+                .to_string_lossy()
+                .ends_with(Self::LLVM_IR_OPTIMIZED_SUFFIX)
+            {
+                dumps.push((entry.metadata()?.modified()?, path.clone()));
+            }
+            if let Some(debug_config) = debug_config {
+                std::fs::copy(
+                    path.as_path(),
+                    debug_config
+                        .output_directory
+                        .join(path.file_name().expect("Always exists")),
+                )?;
+            }
+        }
+        let (_, path) = dumps
+            .into_iter()
+            .max()
+            .ok_or_else(|| anyhow::anyhow!("{code_segment} code optimized LLVM IR not found"))?;
+        Ok(Some(std::fs::read_to_string(path.as_path())?))
+    }
 }
@@ -5,6 +5,7 @@
 pub mod contract;
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 use std::path::PathBuf;
 
 use rayon::iter::IntoParallelIterator;
@@ -12,7 +13,9 @@ use rayon::iter::ParallelIterator;
 
 use crate::build::contract::Contract as EVMContractBuild;
 use crate::build::Build as EVMBuild;
+use crate::cache::Cache;
 use crate::process::input::Input as EVMProcessInput;
+use crate::process::mode::Mode as EVMProcessMode;
 use crate::process::output::Output as EVMOutput;
 
 use self::contract::ir::evmla::EVMLegacyAssembly as ContractEVMLegacyAssembly;
@@ -71,6 +74,8 @@ impl Project {
     pub fn try_from_solc_output(
         libraries: era_compiler_common::Libraries,
         via_ir: bool,
+        ethir_limits: solx_evm_assembly::EtherealIRLimits,
+        recover_internal_functions: bool,
         solc_output: &mut solx_standard_json::Output,
         debug_config: Option<&era_compiler_llvm_context::DebugConfig>,
     ) -> anyhow::Result<Self> {
@@ -105,6 +110,11 @@ impl Project {
             .iter_mut()
             .map(|(path, source)| (path.to_owned(), source.ast.take()))
             .collect::<BTreeMap<String, Option<serde_json::Value>>>();
+        let function_definitions = if !via_ir && recover_internal_functions {
+            Self::function_definitions(&ast_jsons)
+        } else {
+            BTreeMap::new()
+        };
 
         let mut input_contracts = Vec::with_capacity(solc_output.contracts.len());
         for path in solc_output
@@ -146,11 +156,27 @@ impl Project {
                         contract.ir_optimized.as_deref()?,
                         debug_config,
                     )
-                    .map(|yul| yul.map(ContractIR::from))
+                    .map_err(|errors| {
+                        anyhow::anyhow!(errors
+                            .into_iter()
+                            .map(|error| error.formatted_message)
+                            .collect::<Vec<String>>()
+                            .join("\n"))
+                    })
+                    .map(|yul| {
+                        yul.map(|mut yul| {
+                            // Nested objects duplicate the upper-level contracts in `solc` output.
+                            let _ = yul.take_nested_objects();
+                            ContractIR::from(yul)
+                        })
+                    })
                 } else {
                     Ok(ContractEVMLegacyAssembly::try_from_contract(
                         legacy_assembly.clone()?,
                         extra_metadata,
+                        &function_definitions,
+                        ethir_limits,
+                        recover_internal_functions,
                     )
                     .map(ContractIR::from))
                 };
@@ -238,10 +264,20 @@ impl Project {
     ) -> anyhow::Result<Self> {
         let results = sources
             .into_par_iter()
-            .filter_map(|(path, mut source)| {
+            .flat_map(|(path, mut source)| {
                 let source_code = match source.try_resolve() {
                     Ok(()) => source.take_content().expect("Always exists"),
-                    Err(error) => return Some((path, Err(error))),
+                    Err(error) => {
+                        let error = solx_standard_json::OutputError::new_error(
+                            None,
+                            error,
+                            Some(solx_standard_json::OutputErrorSourceLocation::new(
+                                path.clone(),
+                            )),
+                            None,
+                        );
+                        return vec![(path, Err(vec![error]))];
+                    }
                 };
 
                 let metadata = if output_selection.check_selection(
@@ -260,49 +296,82 @@ impl Project {
                     None
                 };
 
-                let ir = match ContractYul::try_from_source(
+                let objects = match ContractYul::try_from_source_objects(
                     path.as_str(),
                     source_code.as_str(),
                     debug_config,
                 ) {
-                    Ok(ir) => ir?,
-                    Err(error) => return Some((path, Err(error))),
+                    Ok(objects) => objects,
+                    Err(errors) => return vec![(path, Err(errors))],
                 };
 
-                let name = era_compiler_common::ContractName::new(
-                    path.clone(),
-                    Some(ir.object.0.identifier.clone()),
-                );
-                let full_path = name.full_path.clone();
-                let contract = Contract::new(
-                    name,
-                    ir.into(),
-                    metadata,
-                    None,
-                    None,
-                    None,
-                    None,
-                    None,
-                    None,
-                    None,
-                    None,
-                );
-                Some((full_path, Ok(contract)))
+                let mut contracts = Vec::with_capacity(objects.len());
+                for mut ir in objects.into_iter() {
+                    let nested_objects = ir.take_nested_objects();
+                    for (ir, metadata) in std::iter::once((ir, metadata.clone())).chain(
+                        nested_objects
+                            .into_iter()
+                            .map(|object| (ContractYul::new(object), None)),
+                    ) {
+                        let name = era_compiler_common::ContractName::new(
+                            path.clone(),
+                            Some(ir.object.0.identifier.clone()),
+                        );
+                        let full_path = name.full_path.clone();
+                        let contract = Contract::new(
+                            name,
+                            ir.into(),
+                            metadata,
+                            None,
+                            None,
+                            None,
+                            None,
+                            None,
+                            None,
+                            None,
+                            None,
+                        );
+                        contracts.push((full_path, Ok(contract)));
+                    }
+                }
+                contracts
             })
-            .collect::<BTreeMap<String, anyhow::Result<Contract>>>();
+            .collect::<Vec<(
+                String,
+                Result<Contract, Vec<solx_standard_json::OutputError>>,
+            )>>();
 
         let mut contracts = BTreeMap::new();
+        let mut duplicate_paths = BTreeSet::new();
         for (path, result) in results.into_iter() {
             match result {
                 Ok(contract) => {
+                    if contracts.contains_key(path.as_str()) {
+                        duplicate_paths.insert(path);
+                        continue;
+                    }
                     contracts.insert(path, contract);
                 }
-                Err(error) => match solc_output {
-                    Some(ref mut solc_output) => solc_output.push_error(Some(path), error),
-                    None => anyhow::bail!(error),
+                Err(errors) => match solc_output {
+                    Some(ref mut solc_output) => solc_output.errors.extend(errors),
+                    None => anyhow::bail!(errors
+                        .into_iter()
+                        .map(|error| error.formatted_message)
+                        .collect::<Vec<String>>()
+                        .join("\n")),
                 },
             }
         }
+
+        if let Err(error) = Self::check_duplicate_object_identifiers(&contracts, &duplicate_paths)
+            .and_then(|()| Self::check_cyclic_object_references(&contracts))
+        {
+            match solc_output {
+                Some(solc_output) => solc_output.push_error(None, error),
+                None => anyhow::bail!(error),
+            }
+        }
+
         Ok(Self::new(
             solx_standard_json::InputLanguage::Yul,
             contracts,
@@ -318,6 +387,7 @@ impl Project {
         paths: &[PathBuf],
         libraries: era_compiler_common::Libraries,
         output_selection: &solx_standard_json::InputSelection,
+        evm_version: Option<era_compiler_common::EVMVersion>,
         solc_output: Option<&mut solx_standard_json::Output>,
     ) -> anyhow::Result<Self> {
         let sources = paths
@@ -335,7 +405,13 @@ impl Project {
             })
             .collect::<anyhow::Result<BTreeMap<String, solx_standard_json::InputSource>>>()?;
 
-        Self::try_from_llvm_ir_sources(sources, libraries, output_selection, solc_output)
+        Self::try_from_llvm_ir_sources(
+            sources,
+            libraries,
+            output_selection,
+            evm_version,
+            solc_output,
+        )
     }
 
     ///
@@ -345,15 +421,56 @@ impl Project {
         sources: BTreeMap<String, solx_standard_json::InputSource>,
         libraries: era_compiler_common::Libraries,
         output_selection: &solx_standard_json::InputSelection,
+        evm_version: Option<era_compiler_common::EVMVersion>,
         mut solc_output: Option<&mut solx_standard_json::Output>,
     ) -> anyhow::Result<Self> {
-        let results = sources
+        let (deploy_sources, sources): (BTreeMap<_, _>, BTreeMap<_, _>) = sources
+            .into_iter()
+            .partition(|(path, _)| ContractLLVMIR::runtime_code_path(path.as_str()).is_some());
+
+        let mut deploy_errors = Vec::new();
+        let mut deploy_source_codes = BTreeMap::new();
+        for (path, mut source) in deploy_sources.into_iter() {
+            let runtime_code_path =
+                ContractLLVMIR::runtime_code_path(path.as_str()).expect("Always exists");
+            if !sources.contains_key(runtime_code_path.as_str()) {
+                deploy_errors.push((
+                    path.clone(),
+                    anyhow::anyhow!(
+                        "Deploy code `{path}` has no runtime code: `{runtime_code_path}` is missing"
+                    ),
+                ));
+                continue;
+            }
+            match source.try_resolve() {
+                Ok(()) => {
+                    deploy_source_codes.insert(
+                        runtime_code_path,
+                        source.take_content().expect("Always exists"),
+                    );
+                }
+                Err(error) => deploy_errors.push((path, error)),
+            }
+        }
+
+        let mut results = sources
             .into_par_iter()
             .map(|(path, mut source)| {
                 let source_code = match source.try_resolve() {
                     Ok(()) => source.take_content().expect("Always exists"),
-                    Err(error) => return (path, Err(error)),
+                    Err(error) => {
+                        let error = solx_standard_json::OutputError::new_error(
+                            None,
+                            error,
+                            Some(solx_standard_json::OutputErrorSourceLocation::new(
+                                path.clone(),
+                            )),
+                            None,
+                        );
+                        return (path, Err(vec![error]));
+                    }
                 };
+                let deploy_source_code = deploy_source_codes.get(path.as_str()).cloned();
 
                 let metadata = if output_selection.check_selection(
                     path.as_str(),
@@ -362,18 +479,31 @@ impl Project {
                 ) {
                     let source_hash =
                         era_compiler_common::Keccak256Hash::from_slice(source_code.as_bytes());
-                    let metadata_json = serde_json::json!({
+                    let mut metadata_json = serde_json::json!({
                         "source_hash": source_hash.to_string(),
                         "llvm_version": era_compiler_llvm_context::LLVM_VERSION,
                     });
+                    if let Some(deploy_source_code) = deploy_source_code.as_ref() {
+                        let deploy_source_hash = era_compiler_common::Keccak256Hash::from_slice(
+                            deploy_source_code.as_bytes(),
+                        );
+                        metadata_json["deploy_source_hash"] =
+                            serde_json::Value::String(deploy_source_hash.to_string());
+                    }
                     Some(serde_json::to_string(&metadata_json).expect("Always valid"))
                 } else {
                     None
                 };
 
+                let mut llvm_ir = ContractLLVMIR::new(path.clone(), source_code);
+                llvm_ir.deploy_source = deploy_source_code;
+                let errors = llvm_ir.validate(evm_version);
+                if !errors.is_empty() {
+                    return (path, Err(errors));
+                }
                 let contract = Contract::new(
                     era_compiler_common::ContractName::new(path.clone(), None),
-                    ContractLLVMIR::new(path.clone(), source_code).into(),
+                    llvm_ir.into(),
                     metadata,
                     None,
                     None,
@@ -387,7 +517,18 @@ impl Project {
 
                 (path, Ok(contract))
             })
-            .collect::<BTreeMap<String, anyhow::Result<Contract>>>();
+            .collect::<BTreeMap<String, Result<Contract, Vec<solx_standard_json::OutputError>>>>();
+        for (path, error) in deploy_errors.into_iter() {
+            let error = solx_standard_json::OutputError::new_error(
+                None,
+                error,
+                Some(solx_standard_json::OutputErrorSourceLocation::new(
+                    path.clone(),
+                )),
+                None,
+            );
+            results.insert(path, Err(vec![error]));
+        }
 
         let mut contracts = BTreeMap::new();
         for (path, result) in results.into_iter() {
@@ -395,9 +536,13 @@ impl Project {
                 Ok(contract) => {
                     contracts.insert(path, contract);
                 }
-                Err(error) => match solc_output {
-                    Some(ref mut solc_output) => solc_output.push_error(Some(path), error),
-                    None => anyhow::bail!(error),
+                Err(errors) => match solc_output {
+                    Some(ref mut solc_output) => solc_output.errors.extend(errors),
+                    None => anyhow::bail!(errors
+                        .into_iter()
+                        .map(|error| error.formatted_message)
+                        .collect::<Vec<String>>()
+                        .join("\n")),
                 },
             }
         }
@@ -409,57 +554,426 @@ impl Project {
         ))
     }
 
+    ///
+    /// Reads the EVM assembly JSON `paths` and returns an EVM assembly project.
+    ///
+    pub fn try_from_evm_assembly_paths(
+        paths: &[PathBuf],
+        libraries: era_compiler_common::Libraries,
+        output_selection: &solx_standard_json::InputSelection,
+        ethir_limits: solx_evm_assembly::EtherealIRLimits,
+        recover_internal_functions: bool,
+        solc_output: Option<&mut solx_standard_json::Output>,
+    ) -> anyhow::Result<Self> {
+        let sources = paths
+            .iter()
+            .map(|path| {
+                let source = solx_standard_json::InputSource::try_from_path(path.as_path())?;
+                let path = if path.to_string_lossy()
+                    == solx_standard_json::InputSource::STDIN_INPUT_IDENTIFIER
+                {
+                    solx_standard_json::InputSource::STDIN_OUTPUT_IDENTIFIER.to_owned()
+                } else {
+                    path.to_string_lossy().to_string()
+                };
+                Ok((path, source))
+            })
+            .collect::<anyhow::Result<BTreeMap<String, solx_standard_json::InputSource>>>()?;
+
+        Self::try_from_evm_assembly_sources(
+            sources,
+            libraries,
+            output_selection,
+            ethir_limits,
+            recover_internal_functions,
+            solc_output,
+        )
+    }
+
+    ///
+    /// Parses the EVM assembly JSON `sources` and returns an EVM assembly project.
+    ///
+    /// Each source contains the deploy code assembly of a single contract, as printed by
+    /// `solc --asm-json`, with the optional extra metadata under the `extraMetadata` key.
+    /// The contracts are named after their file names, and the factory dependencies are
+    /// resolved among all sources.
+    ///
+    pub fn try_from_evm_assembly_sources(
+        sources: BTreeMap<String, solx_standard_json::InputSource>,
+        libraries: era_compiler_common::Libraries,
+        output_selection: &solx_standard_json::InputSelection,
+        ethir_limits: solx_evm_assembly::EtherealIRLimits,
+        recover_internal_functions: bool,
+        mut solc_output: Option<&mut solx_standard_json::Output>,
+    ) -> anyhow::Result<Self> {
+        let results = sources
+            .into_par_iter()
+            .map(|(path, mut source)| {
+                let result = source.try_resolve().and_then(|()| {
+                    let source_code = source.take_content().expect("Always exists");
+                    let mut assembly = era_compiler_common::deserialize_from_str::<
+                        solx_evm_assembly::Assembly,
+                    >(source_code.as_str())
+                    .map_err(|error| anyhow::anyhow!("EVM assembly parsing: {error}"))?;
+                    assembly.runtime_code()?;
+                    let extra_metadata = assembly.extra_metadata.take();
+                    Ok((source_code, assembly, extra_metadata))
+                });
+                (path, result)
+            })
+            .collect::<BTreeMap<String, anyhow::Result<_>>>();
+
+        let mut errors = Vec::new();
+        let mut parsed = BTreeMap::new();
+        for (path, result) in results.into_iter() {
+            match result {
+                Ok(parsed_source) => {
+                    parsed.insert(path, parsed_source);
+                }
+                Err(error) => errors.push(solx_standard_json::OutputError::new_error(
+                    None,
+                    error,
+                    Some(solx_standard_json::OutputErrorSourceLocation::new(path)),
+                    None,
+                )),
+            }
+        }
+
+        let legacy_assemblies: BTreeMap<
+            String,
+            BTreeMap<String, &mut solx_evm_assembly::Assembly>,
+        > = parsed
+            .iter_mut()
+            .map(|(path, (_, assembly, _))| {
+                (
+                    path.to_owned(),
+                    BTreeMap::from([(
+                        ContractEVMLegacyAssembly::contract_name(path.as_str()),
+                        assembly,
+                    )]),
+                )
+            })
+            .collect();
+        if let Err(error) = solx_evm_assembly::Assembly::preprocess_dependencies(legacy_assemblies)
+        {
+            errors.push(solx_standard_json::OutputError::new_error(
+                None, error, None, None,
+            ));
+            parsed.clear();
+        }
+
+        let mut contracts = BTreeMap::new();
+        for (path, (source_code, assembly, extra_metadata)) in parsed.into_iter() {
+            let name = era_compiler_common::ContractName::new(
+                path.clone(),
+                Some(ContractEVMLegacyAssembly::contract_name(path.as_str())),
+            );
+
+            let metadata = if output_selection.check_selection(
+                name.path.as_str(),
+                name.name.as_deref(),
+                solx_standard_json::InputSelector::Metadata,
+            ) {
+                let source_hash =
+                    era_compiler_common::Keccak256Hash::from_slice(source_code.as_bytes());
+                let metadata_json = serde_json::json!({
+                    "source_hash": source_hash.to_string(),
+                    "llvm_version": era_compiler_llvm_context::LLVM_VERSION,
+                });
+                Some(serde_json::to_string(&metadata_json).expect("Always valid"))
+            } else {
+                None
+            };
+
+            let ir = ContractEVMLegacyAssembly::try_from_contract(
+                assembly,
+                extra_metadata,
+                &BTreeMap::new(),
+                ethir_limits,
+                recover_internal_functions,
+            )
+            .expect("Always exists");
+            let full_path = name.full_path.clone();
+            let contract = Contract::new(
+                name,
+                ir.into(),
+                metadata,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            );
+            contracts.insert(full_path, contract);
+        }
+
+        if !errors.is_empty() {
+            match solc_output {
+                Some(ref mut solc_output) => solc_output.errors.extend(errors),
+                None => anyhow::bail!(errors
+                    .into_iter()
+                    .map(|error| error.formatted_message)
+                    .collect::<Vec<String>>()
+                    .join("\n")),
+            }
+        }
+        Ok(Self::new(
+            solx_standard_json::InputLanguage::EVMAssembly,
+            contracts,
+            None,
+            libraries,
+        ))
+    }
+
     ///
     /// Compiles all contracts to EVM, returning their build artifacts.
     ///
+    /// If `cache` is set, the contracts compiled before with the same settings are reused.
+    ///
+    /// In the in-process mode, the contracts are compiled on the shared in-process thread pool,
+    /// whose threads have enough stack for LLVM and the IR translators.
+    ///
     pub fn compile_to_evm(
         self,
         messages: &mut Vec<solx_standard_json::OutputError>,
         output_selection: &solx_standard_json::InputSelection,
+        evm_version: Option<era_compiler_common::EVMVersion>,
         metadata_hash_type: era_compiler_common::EVMMetadataHashType,
         optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
         llvm_options: Vec<String>,
         debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+        cache: Option<&Cache>,
+        process_mode: EVMProcessMode,
     ) -> anyhow::Result<EVMBuild> {
-        let results = self
-            .contracts
-            .into_par_iter()
-            .map(|(path, mut contract)| {
-                let abi = contract.abi.take();
-                let method_identifiers = contract.method_identifiers.take();
-                let userdoc = contract.userdoc.take();
-                let devdoc = contract.devdoc.take();
-                let storage_layout = contract.storage_layout.take();
-                let transient_storage_layout = contract.transient_storage_layout.take();
-                let legacy_assembly = contract.legacy_assembly.take();
-                let ir_optimized = contract.ir_optimized.take();
-
-                let input = EVMProcessInput::new(
-                    contract,
-                    self.identifier_paths.clone(),
-                    output_selection.to_owned(),
-                    metadata_hash_type,
-                    optimizer_settings.clone(),
-                    llvm_options.clone(),
-                    debug_config.clone(),
+        let compile = |(path, mut contract): (String, Contract)| {
+            let abi = contract.abi.take();
+            let method_identifiers = contract.method_identifiers.take();
+            let userdoc = contract.userdoc.take();
+            let devdoc = contract.devdoc.take();
+            let storage_layout = contract.storage_layout.take();
+            let transient_storage_layout = contract.transient_storage_layout.take();
+            let legacy_assembly = contract.legacy_assembly.take();
+            let ir_optimized = contract.ir_optimized.take();
+
+            let input = EVMProcessInput::new(
+                contract,
+                self.identifier_paths.clone(),
+                output_selection.to_owned(),
+                evm_version,
+                metadata_hash_type,
+                optimizer_settings.clone(),
+                llvm_options.clone(),
+                debug_config.clone(),
+            );
+            let cache_key = cache.and_then(|cache| cache.key(&input));
+            let cached_output = cache
+                .zip(cache_key.as_deref())
+                .and_then(|(cache, key)| cache.get(key));
+            let result: crate::Result<EVMOutput> = match cached_output {
+                Some(output) => Ok(output),
+                None => {
+                    let result = match process_mode {
+                        EVMProcessMode::Subprocess => crate::process::call(path.as_str(), input),
+                        EVMProcessMode::InProcess => {
+                            crate::process::call_in_process(path.as_str(), input)
+                        }
+                    };
+                    if let (Ok(output), Some(cache), Some(key)) =
+                        (result.as_ref(), cache, cache_key.as_deref())
+                    {
+                        cache.insert(key, output);
+                    }
+                    result
+                }
+            };
+            let result = result.map(|mut output| {
+                output.build.abi = abi;
+                output.build.method_identifiers = method_identifiers;
+                output.build.userdoc = userdoc;
+                output.build.devdoc = devdoc;
+                output.build.storage_layout = storage_layout;
+                output.build.transient_storage_layout = transient_storage_layout;
+                output.build.legacy_assembly = legacy_assembly;
+                output.build.ir_optimized = ir_optimized;
+                output.build
+            });
+            (path, result)
+        };
+        let results: BTreeMap<String, Result<EVMContractBuild, solx_standard_json::OutputError>> =
+            match process_mode {
+                EVMProcessMode::Subprocess => self.contracts.into_par_iter().map(compile).collect(),
+                EVMProcessMode::InProcess => crate::process::thread_pool()
+                    .install(|| self.contracts.into_par_iter().map(compile).collect()),
+            };
+
+        let mut build = EVMBuild::new(results, self.ast_jsons, messages);
+        build.identifier_paths = self.identifier_paths;
+        Ok(build)
+    }
+
+    ///
+    /// Keeps only the contracts from `paths`, marking them as recompiled via the other `solc` codegen.
+    ///
+    /// The identifiers of the removed contracts are kept, as the remaining ones may depend on them.
+    ///
+    pub fn retain_pipeline_fallback(&mut self, paths: &BTreeSet<String>) {
+        self.contracts
+            .retain(|path, _contract| paths.contains(path));
+        for contract in self.contracts.values_mut() {
+            contract.is_pipeline_fallback = true;
+        }
+        self.ast_jsons = None;
+    }
+
+    ///
+    /// Checks that the Yul object identifiers are unique across all files.
+    ///
+    /// The objects are referenced via `dataoffset` and `datasize` by their identifiers only, so
+    /// references to duplicate objects would be ambiguous. `duplicate_paths` are the full paths
+    /// of the objects defined more than once in the same file.
+    ///
+    fn check_duplicate_object_identifiers(
+        contracts: &BTreeMap<String, Contract>,
+        duplicate_paths: &BTreeSet<String>,
+    ) -> anyhow::Result<()> {
+        if let Some(path) = duplicate_paths.first() {
+            anyhow::bail!("Object `{path}` is defined more than once in the same file");
+        }
+
+        let mut identifier_paths: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+        for (path, contract) in contracts.iter() {
+            identifier_paths
+                .entry(contract.identifier())
+                .or_default()
+                .push(path.as_str());
+        }
+
+        for (identifier, paths) in identifier_paths.into_iter() {
+            if paths.len() > 1 {
+                anyhow::bail!(
+                    "Object `{identifier}` is defined more than once: {}",
+                    paths
+                        .into_iter()
+                        .map(|path| format!("`{path}`"))
+                        .collect::<Vec<String>>()
+                        .join(", ")
                 );
-                let result: crate::Result<EVMOutput> = crate::process::call(path.as_str(), input);
-                let result = result.map(|mut output| {
-                    output.build.abi = abi;
-                    output.build.method_identifiers = method_identifiers;
-                    output.build.userdoc = userdoc;
-                    output.build.devdoc = devdoc;
-                    output.build.storage_layout = storage_layout;
-                    output.build.transient_storage_layout = transient_storage_layout;
-                    output.build.legacy_assembly = legacy_assembly;
-                    output.build.ir_optimized = ir_optimized;
-                    output.build
-                });
-                (path, result)
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// Checks the Yul objects for cyclic references via `dataoffset` and `datasize`.
+    ///
+    /// Such objects cannot be assembled, as each of them must contain the bytecode of another.
+    ///
+    fn check_cyclic_object_references(
+        contracts: &BTreeMap<String, Contract>,
+    ) -> anyhow::Result<()> {
+        let graph = contracts
+            .values()
+            .filter_map(|contract| match contract.ir {
+                ContractIR::Yul(ref yul) => Some(yul.get_object_dependencies()),
+                _ => None,
             })
-            .collect::<BTreeMap<String, Result<EVMContractBuild, solx_standard_json::OutputError>>>(
-            );
+            .flatten()
+            .collect::<BTreeMap<String, Vec<String>>>();
+
+        let mut visited = BTreeSet::new();
+        for identifier in graph.keys() {
+            if let Some(cycle) =
+                Self::find_object_reference_cycle(identifier, &graph, &mut visited, &mut vec![])
+            {
+                anyhow::bail!(
+                    "Cyclic object references: {}",
+                    cycle
+                        .into_iter()
+                        .map(|identifier| format!("`{identifier}`"))
+                        .collect::<Vec<String>>()
+                        .join(" -> ")
+                );
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// Searches the object reference `graph` depth-first, starting from `identifier`.
+    ///
+    /// Returns the first cycle found, with its first identifier repeated at the end.
+    ///
+    fn find_object_reference_cycle<'a>(
+        identifier: &'a str,
+        graph: &'a BTreeMap<String, Vec<String>>,
+        visited: &mut BTreeSet<&'a str>,
+        stack: &mut Vec<&'a str>,
+    ) -> Option<Vec<&'a str>> {
+        if let Some(position) = stack.iter().position(|entry| *entry == identifier) {
+            let mut cycle = stack[position..].to_vec();
+            cycle.push(identifier);
+            return Some(cycle);
+        }
+        if !visited.insert(identifier) {
+            return None;
+        }
+
+        stack.push(identifier);
+        for dependency in graph.get(identifier).into_iter().flatten() {
+            if let Some(cycle) =
+                Self::find_object_reference_cycle(dependency.as_str(), graph, visited, stack)
+            {
+                return Some(cycle);
+            }
+        }
+        stack.pop();
+        None
+    }
+
+    ///
+    /// Collects the Solidity function definitions from the `ast_jsons`.
+    ///
+    /// The definitions are keyed by their source index, begin, and end, and named like the `solc`
+    /// Yul codegen does, that is, `fun_<name>_<AST ID>`.
+    ///
+    fn function_definitions(
+        ast_jsons: &BTreeMap<String, Option<serde_json::Value>>,
+    ) -> BTreeMap<(isize, isize, isize), String> {
+        let mut function_definitions = BTreeMap::new();
+        for ast_json in ast_jsons.values().flatten() {
+            Self::collect_function_definitions(ast_json, &mut function_definitions);
+        }
+        function_definitions
+    }
 
-        Ok(EVMBuild::new(results, self.ast_jsons, messages))
+    ///
+    /// Collects the function definitions from the `nodes` of the AST `node` recursively.
+    ///
+    fn collect_function_definitions(
+        node: &serde_json::Value,
+        function_definitions: &mut BTreeMap<(isize, isize, isize), String>,
+    ) {
+        if node["nodeType"].as_str() == Some("FunctionDefinition")
+            && matches!(node["kind"].as_str(), Some("function" | "freeFunction"))
+        {
+            let location = node["src"].as_str().and_then(|location| {
+                let mut parts = location.split(':').map(|part| part.parse::<isize>().ok());
+                let (begin, length, source) = (parts.next()??, parts.next()??, parts.next()??);
+                Some((source, begin, begin + length))
+            });
+            if let (Some(location), Some(name), Some(id)) =
+                (location, node["name"].as_str(), node["id"].as_u64())
+            {
+                function_definitions.insert(location, format!("fun_{name}_{id}"));
+            }
+            return;
+        }
+
+        for node in node["nodes"].as_array().into_iter().flatten() {
+            Self::collect_function_definitions(node, function_definitions);
+        }
     }
 }
@@ -64,6 +64,7 @@ pub struct Arguments {
 
     /// EVM version `solc` will produce Yul or EVM assembly for.
     /// The default is chosen by `solc`.
+    /// In Yul and LLVM IR modes, the instructions are checked against this version.
     #[arg(long)]
     pub evm_version: Option<era_compiler_common::EVMVersion>,
 
@@ -81,23 +82,66 @@ pub struct Arguments {
     #[arg(short, long)]
     pub threads: Option<usize>,
 
+    /// Compile contracts in the current process on dedicated large-stack threads,
+    /// instead of spawning a child process per contract.
+    /// If compilation of a contract panics, it is retried in a child process.
+    /// LLVM aborts and segmentation faults terminate the whole process.
+    #[arg(long)]
+    pub in_process: bool,
+
     /// Switch to Yul mode.
-    /// Only one input Yul file is allowed.
+    /// Several input Yul files with several top-level objects each are allowed.
     /// Cannot be used with standard JSON mode.
     #[arg(long, alias = "strict-assembly")]
     pub yul: bool,
 
     /// Switch to LLVM IR mode.
-    /// Only one input LLVM IR file is allowed.
+    /// Custom deploy code can be passed in a `.deploy.ll` file next to its runtime code `.ll` file.
     /// Cannot be used with standard JSON mode.
-    /// Use this mode at your own risk, as LLVM IR input validation is not implemented.
+    /// The input is validated before compilation.
     #[arg(long)]
     pub llvm_ir: bool,
 
+    /// Switch to EVM assembly mode.
+    /// Each input file must contain the EVM assembly JSON of a contract, as printed by `solc --asm-json`,
+    /// and the contract is named after the file.
+    /// Cannot be used with standard JSON mode.
+    #[arg(long)]
+    pub evm_assembly: bool,
+
+    /// Switch to link mode.
+    /// Links the library addresses specified with `--libraries` into the input bytecode files,
+    /// which must contain hexadecimal bytecode with library placeholders.
+    /// The files are modified in place.
+    #[arg(long)]
+    pub link: bool,
+
     /// Enable the `solc` IR codegen.
     #[arg(long)]
     pub via_ir: bool,
 
+    /// Recompile contracts that fail with stack-too-deep errors via the other `solc` codegen.
+    /// The contracts that switched codegen are reported and recorded in their metadata.
+    #[arg(long)]
+    pub pipeline_fallback: bool,
+
+    /// Set the maximum number of instances of an Ethereal IR block in a function.
+    /// Blocks are duplicated for each initial stack state.
+    /// The default is 2048.
+    #[arg(long)]
+    pub ethir_max_block_instances: Option<usize>,
+
+    /// Set the maximum number of Ethereal IR elements in a contract.
+    /// The default is 2000000.
+    #[arg(long)]
+    pub ethir_max_elements: Option<usize>,
+
+    /// Recover the internal functions from the `solc` jump annotations in the EVM assembly codegen,
+    /// leaving their inlining to LLVM instead of inlining them into Ethereal IR.
+    /// Experimental: the effect on the bytecode size is not established yet.
+    #[arg(long)]
+    pub recover_internal_functions: bool,
+
     /// Set the metadata hash type.
     /// Available types: `none`, `ipfs`.
     /// The default is `ipfs`.
@@ -129,6 +173,15 @@ pub struct Arguments {
     #[arg(long = "asm")]
     pub output_assembly: bool,
 
+    /// Emit LLVM IR of the compiled contracts, both before and after optimization.
+    #[arg(long = "emit-llvm-ir")]
+    pub output_llvm_ir: bool,
+
+    /// Emit Ethereal IR of the compiled contracts.
+    /// Only available with the EVM assembly codegen, that is, without `--via-ir`.
+    #[arg(long = "ethir")]
+    pub output_ethir: bool,
+
     /// Emit metadata of the compiled project.
     #[arg(long = "metadata")]
     pub output_metadata: bool,
@@ -169,6 +222,28 @@ pub struct Arguments {
     #[arg(long = "ir-optimized")]
     pub output_ir_optimized: bool,
 
+    /// Kill a contract compilation child process after the specified number of seconds.
+    /// Cannot be used with `--in-process`.
+    #[arg(long)]
+    pub process_timeout: Option<u64>,
+
+    /// Dump the inputs of failed contract compilation child processes to the specified directory.
+    /// A dumped input can be replayed with `solx --recursive-process <path> < <input>`.
+    /// Cannot be used with `--in-process`.
+    #[arg(long)]
+    pub crash_reproducer_dir: Option<PathBuf>,
+
+    /// Reuse the contracts compiled before with the same settings from the specified directory,
+    /// and store the newly compiled ones there.
+    /// In standard JSON mode, overrides the `cacheDirectory` setting.
+    #[arg(long)]
+    pub cache_dir: Option<PathBuf>,
+
+    /// Print the compilation cache hits, misses, and evictions to stderr.
+    /// Can only be used together with `--cache-dir`.
+    #[arg(long)]
+    pub cache_stats: bool,
+
     /// Dump all IRs to files in the specified directory.
     /// Only for testing and debugging.
     #[arg(long)]
@@ -206,20 +281,96 @@ impl Arguments {
             ));
         }
 
-        let modes_count = [self.yul, self.llvm_ir, self.standard_json.is_some()]
-            .iter()
-            .filter(|&&x| x)
-            .count();
+        let modes_count = [
+            self.yul,
+            self.llvm_ir,
+            self.evm_assembly,
+            self.standard_json.is_some(),
+            self.link,
+        ]
+        .iter()
+        .filter(|&&x| x)
+        .count();
         if modes_count > 1 {
             messages.push(solx_standard_json::OutputError::new_error(
                 None,
-                "Only one mode is allowed at the same time: Yul, LLVM IR, standard JSON.",
+                "Only one mode is allowed at the same time: Yul, LLVM IR, EVM assembly, standard JSON, link.",
                 None,
                 None,
             ));
         }
 
-        if self.yul || self.llvm_ir {
+        if self.link {
+            if self.inputs.is_empty() {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    None,
+                    "At least one bytecode file must be specified in link mode.",
+                    None,
+                    None,
+                ));
+            }
+            if self.output_bytecode
+                || self.output_bytecode_runtime
+                || self.output_assembly
+                || self.output_llvm_ir
+                || self.output_ethir
+                || self.output_metadata
+                || self.output_abi
+                || self.output_hashes
+                || self.output_userdoc
+                || self.output_devdoc
+                || self.output_storage_layout
+                || self.output_transient_storage_layout
+                || self.output_ast_json
+                || self.output_asm_solc_json
+                || self.output_ir_optimized
+                || self.output_dir.is_some()
+            {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    None,
+                    "No output can be requested in link mode, as the input files are modified in place.",
+                    None,
+                    None,
+                ));
+            }
+        }
+
+        if self.cache_stats && self.cache_dir.is_none() {
+            messages.push(solx_standard_json::OutputError::new_error(
+                None,
+                "`cache-stats` can only be used together with `cache-dir`.",
+                None,
+                None,
+            ));
+        }
+
+        if self.in_process && self.process_timeout.is_some() {
+            messages.push(solx_standard_json::OutputError::new_error(
+                None,
+                "`process-timeout` cannot be used with `in-process`, as no child processes are spawned.",
+                None,
+                None,
+            ));
+        }
+        if self.in_process && self.crash_reproducer_dir.is_some() {
+            messages.push(solx_standard_json::OutputError::new_error(
+                None,
+                "`crash-reproducer-dir` cannot be used with `in-process`, as no child processes are spawned.",
+                None,
+                None,
+            ));
+        }
+
+        if self.output_ethir && self.via_ir {
+            messages.push(solx_standard_json::OutputError::new_error(
+                None,
+                "Ethereal IR is only produced by the EVM assembly codegen, so it cannot be emitted with `--via-ir`.",
+                None,
+                None,
+            ));
+        }
+
+        if self.yul || self.llvm_ir || self.evm_assembly {
             if self.base_path.is_some() {
                 messages.push(solx_standard_json::OutputError::new_error(
                     None,
@@ -254,28 +405,46 @@ impl Arguments {
                 || self.output_ast_json
                 || self.output_asm_solc_json
                 || self.output_ir_optimized
+                || (self.output_ethir && !self.evm_assembly)
             {
                 messages.push(solx_standard_json::OutputError::new_error(
                     None,
-                    "ABI, hashes, userdoc, devdoc, storage layout, transient storage layout, AST, EVM assembly, Yul can be only emitted for Solidity contracts.",
+                    "ABI, hashes, userdoc, devdoc, storage layout, transient storage layout, AST, EVM assembly, Yul, Ethereal IR can be only emitted for Solidity contracts.",
                     None,
                     None,
                 ));
             }
 
-            if self.evm_version.is_some() {
+            if self.via_ir {
                 messages.push(solx_standard_json::OutputError::new_error(
                     None,
-                    "EVM version is only allowed in Solidity mode.",
+                    "IR codegen settings are only available in Solidity mode.",
                     None,
                     None,
                 ));
             }
-
-            if self.via_ir {
+            if self.pipeline_fallback {
                 messages.push(solx_standard_json::OutputError::new_error(
                     None,
-                    "IR codegen settings are only available in Solidity mode.",
+                    "Codegen pipeline fallback is only available in Solidity mode.",
+                    None,
+                    None,
+                ));
+            }
+            if (self.ethir_max_block_instances.is_some() || self.ethir_max_elements.is_some())
+                && !self.evm_assembly
+            {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    None,
+                    "Ethereal IR limits are only available in Solidity and EVM assembly modes.",
+                    None,
+                    None,
+                ));
+            }
+            if self.recover_internal_functions && !self.evm_assembly {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    None,
+                    "Internal function recovery is only available in Solidity and EVM assembly modes.",
                     None,
                     None,
                 ));
@@ -286,6 +455,8 @@ impl Arguments {
             if self.output_bytecode
                 || self.output_bytecode_runtime
                 || self.output_assembly
+                || self.output_llvm_ir
+                || self.output_ethir
                 || self.output_metadata
                 || self.output_abi
                 || self.output_hashes
@@ -330,6 +501,30 @@ impl Arguments {
                     None,
                 ));
             }
+            if self.pipeline_fallback {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    None,
+                    "Codegen pipeline fallback must be passed via standard JSON input.",
+                    None,
+                    None,
+                ));
+            }
+            if self.ethir_max_block_instances.is_some() || self.ethir_max_elements.is_some() {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    None,
+                    "Ethereal IR limits must be passed via standard JSON input.",
+                    None,
+                    None,
+                ));
+            }
+            if self.recover_internal_functions {
+                messages.push(solx_standard_json::OutputError::new_error(
+                    None,
+                    "Internal function recovery must be passed via standard JSON input.",
+                    None,
+                    None,
+                ));
+            }
             if self.evm_version.is_some() {
                 messages.push(solx_standard_json::OutputError::new_error(
                     None,
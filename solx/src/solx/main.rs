@@ -7,6 +7,7 @@ pub mod arguments;
 use std::collections::BTreeSet;
 use std::io::Write;
 use std::path::PathBuf;
+use std::time::Duration;
 
 use clap::Parser;
 
@@ -100,8 +101,25 @@ fn main_inner(
         return solx::run_recursive();
     }
 
+    if let Some(timeout) = arguments.process_timeout {
+        let _ = solx::process::TIMEOUT.set(Duration::from_secs(timeout));
+    }
+    if let Some(ref crash_reproducer_directory) = arguments.crash_reproducer_dir {
+        let _ = solx::process::REPRODUCER_DIRECTORY.set(crash_reproducer_directory.to_owned());
+    }
+
     let (input_files, remappings) = arguments.split_input_files_and_remappings()?;
 
+    if arguments.link {
+        solx::link_evm(
+            input_files.as_slice(),
+            arguments.libraries.as_slice(),
+            messages,
+        )?;
+        writeln!(std::io::stdout(), "Linking completed.")?;
+        return Ok(());
+    }
+
     let mut optimizer_settings = match arguments.optimization {
         Some(mode) => era_compiler_llvm_context::OptimizerSettings::try_from_cli(mode)?,
         None => era_compiler_llvm_context::OptimizerSettings::cycles(),
@@ -123,6 +141,16 @@ fn main_inner(
         selectors.insert(solx_standard_json::InputSelector::BytecodeLLVMAssembly);
         selectors.insert(solx_standard_json::InputSelector::RuntimeBytecodeLLVMAssembly);
     }
+    if arguments.output_llvm_ir {
+        selectors.insert(solx_standard_json::InputSelector::BytecodeLLVMIR);
+        selectors.insert(solx_standard_json::InputSelector::BytecodeLLVMIROptimized);
+        selectors.insert(solx_standard_json::InputSelector::RuntimeBytecodeLLVMIR);
+        selectors.insert(solx_standard_json::InputSelector::RuntimeBytecodeLLVMIROptimized);
+    }
+    if arguments.output_ethir {
+        selectors.insert(solx_standard_json::InputSelector::BytecodeEthIR);
+        selectors.insert(solx_standard_json::InputSelector::RuntimeBytecodeEthIR);
+    }
     if arguments.output_metadata {
         selectors.insert(solx_standard_json::InputSelector::Metadata);
     }
@@ -176,11 +204,33 @@ fn main_inner(
         None => None,
     };
 
+    let cache = match arguments.cache_dir {
+        Some(cache_directory) => Some(solx::Cache::try_new(
+            cache_directory,
+            arguments.cache_stats,
+        )?),
+        None => None,
+    };
+
+    let process_mode = if arguments.in_process {
+        solx::EVMProcessMode::InProcess
+    } else {
+        solx::EVMProcessMode::Subprocess
+    };
+
     let metadata_hash_type = arguments
         .metadata_hash
         .unwrap_or(era_compiler_common::EVMMetadataHashType::IPFS);
     let append_cbor = !arguments.no_cbor_metadata;
     let use_import_callback = !arguments.no_import_callback;
+    let ethir_limits = solx_evm_assembly::EtherealIRLimits::new(
+        arguments
+            .ethir_max_block_instances
+            .unwrap_or(solx_evm_assembly::EtherealIRLimits::DEFAULT_MAX_BLOCK_INSTANCES),
+        arguments
+            .ethir_max_elements
+            .unwrap_or(solx_evm_assembly::EtherealIRLimits::DEFAULT_MAX_ELEMENTS),
+    );
 
     let build = if arguments.yul {
         solx::yul_to_evm(
@@ -188,11 +238,14 @@ fn main_inner(
             arguments.libraries.as_slice(),
             &output_selection,
             messages,
+            arguments.evm_version,
             metadata_hash_type,
             append_cbor,
             optimizer_settings,
             llvm_options,
             debug_config,
+            cache,
+            process_mode,
         )
     } else if arguments.llvm_ir {
         solx::llvm_ir_to_evm(
@@ -200,11 +253,31 @@ fn main_inner(
             arguments.libraries.as_slice(),
             &output_selection,
             messages,
+            arguments.evm_version,
+            metadata_hash_type,
+            append_cbor,
+            optimizer_settings,
+            llvm_options,
+            debug_config,
+            cache,
+            process_mode,
+        )
+    } else if arguments.evm_assembly {
+        solx::evm_assembly_to_evm(
+            input_files.as_slice(),
+            arguments.libraries.as_slice(),
+            &output_selection,
+            messages,
+            arguments.evm_version,
+            ethir_limits,
+            arguments.recover_internal_functions,
             metadata_hash_type,
             append_cbor,
             optimizer_settings,
             llvm_options,
             debug_config,
+            cache,
+            process_mode,
         )
     } else if let Some(standard_json) = arguments.standard_json {
         return solx::standard_json_evm(
@@ -214,7 +287,10 @@ fn main_inner(
             arguments.include_path,
             arguments.allow_paths,
             use_import_callback,
+            None,
             debug_config,
+            cache,
+            process_mode,
         );
     } else if !output_selection.is_empty() {
         solx::standard_output_evm(
@@ -224,6 +300,9 @@ fn main_inner(
             messages,
             arguments.evm_version,
             arguments.via_ir,
+            arguments.pipeline_fallback,
+            ethir_limits,
+            arguments.recover_internal_functions,
             metadata_hash_type,
             arguments.metadata_literal,
             append_cbor,
@@ -231,10 +310,13 @@ fn main_inner(
             arguments.include_path,
             arguments.allow_paths,
             use_import_callback,
+            None,
             remappings,
             optimizer_settings,
             llvm_options,
             debug_config,
+            cache,
+            process_mode,
         )
     } else {
         writeln!(
@@ -0,0 +1,88 @@
+//!
+//! The Yul to LLVM IR translation error.
+//!
+
+use std::collections::BTreeMap;
+
+use solx_yul::yul::lexer::token::annotation::source::Source;
+
+///
+/// The Yul to LLVM IR translation error.
+///
+/// Attributes an error to the original source code range of the Yul statement it has been
+/// raised from, as described by the `@src` annotations, so it can be reported with a Solidity
+/// source location instead of a Yul one.
+///
+#[derive(Debug)]
+pub struct Error {
+    /// The original source location of the failed statement.
+    pub source: Source,
+    /// The original source path, resolved with the `@use-src` annotation of the object.
+    pub path: Option<String>,
+    /// The original error.
+    pub inner: anyhow::Error,
+}
+
+impl Error {
+    ///
+    /// Attaches the original source location to `error`, unless an inner statement has already
+    /// done so.
+    ///
+    pub fn attach(error: anyhow::Error, source: Option<Source>) -> anyhow::Error {
+        match source {
+            Some(source) if !error.is::<Self>() => anyhow::Error::new(Self {
+                source,
+                path: None,
+                inner: error,
+            }),
+            _ => error,
+        }
+    }
+
+    ///
+    /// Prefixes the `error` message with `context`, resolving the original source path of the
+    /// attached location with `source_paths`.
+    ///
+    pub fn resolve<C>(
+        error: anyhow::Error,
+        source_paths: &BTreeMap<usize, String>,
+        context: C,
+    ) -> anyhow::Error
+    where
+        C: std::fmt::Display,
+    {
+        match error.downcast::<Self>() {
+            Ok(error) => anyhow::Error::new(Self {
+                source: error.source,
+                path: source_paths.get(&error.source.index).cloned(),
+                inner: anyhow::anyhow!("{context}: {}", error.inner),
+            }),
+            Err(error) => anyhow::anyhow!("{context}: {error}"),
+        }
+    }
+
+    ///
+    /// Returns the resolved original source location of `error`, if any.
+    ///
+    pub fn source_location(
+        error: &anyhow::Error,
+    ) -> Option<solx_standard_json::OutputErrorSourceLocation> {
+        let error = error.downcast_ref::<Self>()?;
+        let path = error.path.as_ref()?;
+        Some(
+            solx_standard_json::OutputErrorSourceLocation::new_with_offsets(
+                path.to_owned(),
+                error.source.start as isize,
+                error.source.end as isize,
+            ),
+        )
+    }
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.inner)
+    }
+}
+
+impl std::error::Error for Error {}
@@ -2,4 +2,6 @@
 //! The Yul IR compiling tools.
 //!
 
+pub mod error;
 pub mod parser;
+pub mod validation;
@@ -66,7 +66,7 @@ mod tests {
 
     fn identifier_of(name: &str) -> Identifier {
         Identifier {
-            location: Location { line: 0, column: 0 },
+            location: Location::new(0, 0),
             inner: name.to_string(),
             r#type: None,
         }
@@ -132,7 +132,7 @@ $llvm_Hot_Cold_MinSize_BogusAttr_llvm$
 "#;
 
         let values = BTreeSet::from(["BogusAttr".into()]);
-        let location = Location { line: 0, column: 0 };
+        let location = Location::new(0, 0);
         let expected = YulError::Parser(ParserError::InvalidAttributes { location, values });
         let result = get_llvm_attributes(&identifier_of(input))
             .expect_err("LLVM attributes parser should not mask unknown attributes");
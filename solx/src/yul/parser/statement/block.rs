@@ -6,6 +6,7 @@ use era_compiler_llvm_context::IContext;
 use solx_yul::yul::parser::statement::Statement;
 
 use crate::declare_wrapper;
+use crate::yul::error::Error as YulError;
 use crate::yul::parser::dialect::era::EraDialect;
 use crate::yul::parser::wrapper::Wrap;
 
@@ -43,18 +44,23 @@ impl era_compiler_llvm_context::EVMWriteLLVM for Block {
                 break;
             }
 
-            match statement {
-                Statement::Block(block) => {
-                    block.wrap().into_llvm(context)?;
-                }
+            let location = statement.location();
+            if let Some(source_id) = location.source_id {
+                // The original source range is encoded as its 1-based identifier, as the line and
+                // column cannot hold a full range. The ranges are restored from the Yul object.
+                context.set_debug_location((source_id + 1) as u32, 0, None)?;
+            }
+
+            let result = match statement {
+                Statement::Block(block) => block.wrap().into_llvm(context),
                 Statement::Expression(expression) => {
-                    expression.wrap().into_llvm(context)?;
+                    expression.wrap().into_llvm(context).map(|_| ())
                 }
-                Statement::VariableDeclaration(statement) => statement.wrap().into_llvm(context)?,
-                Statement::Assignment(statement) => statement.wrap().into_llvm(context)?,
-                Statement::IfConditional(statement) => statement.wrap().into_llvm(context)?,
-                Statement::Switch(statement) => statement.wrap().into_llvm(context)?,
-                Statement::ForLoop(statement) => statement.wrap().into_llvm(context)?,
+                Statement::VariableDeclaration(statement) => statement.wrap().into_llvm(context),
+                Statement::Assignment(statement) => statement.wrap().into_llvm(context),
+                Statement::IfConditional(statement) => statement.wrap().into_llvm(context),
+                Statement::Switch(statement) => statement.wrap().into_llvm(context),
+                Statement::ForLoop(statement) => statement.wrap().into_llvm(context),
                 Statement::Continue(_location) => {
                     context.build_unconditional_branch(context.r#loop().continue_block)?;
                     break;
@@ -73,7 +79,8 @@ impl era_compiler_llvm_context::EVMWriteLLVM for Block {
                     "{} Unexpected local statement: {statement:?}",
                     statement.location(),
                 ),
-            }
+            };
+            result.map_err(|error| YulError::attach(error, location.source))?;
         }
 
         Ok(())
@@ -670,7 +670,7 @@ impl FunctionCall {
                 let object_name = arguments[0].original.take().ok_or_else(|| {
                     anyhow::anyhow!("{} `dataoffset` literal is missing", location)
                 })?;
-                let object_name = object_name.split('.').next_back().expect("Always exists");
+                let object_name = self.0.dependency_identifier(object_name.as_str());
                 era_compiler_llvm_context::evm_code::data_offset(context, object_name).map(Some)
             }
             Name::DataSize => {
@@ -679,7 +679,7 @@ impl FunctionCall {
                     .original
                     .take()
                     .ok_or_else(|| anyhow::anyhow!("{} `datasize` literal is missing", location))?;
-                let object_name = object_name.split('.').next_back().expect("Always exists");
+                let object_name = self.0.dependency_identifier(object_name.as_str());
                 era_compiler_llvm_context::evm_code::data_size(context, object_name).map(Some)
             }
             Name::DataCopy => {
@@ -747,6 +747,12 @@ impl FunctionCall {
                 era_compiler_llvm_context::evm_contract_context::block_hash(context, index)
                     .map(Some)
             }
+            Name::BlobHash => {
+                let arguments = self.pop_arguments_llvm::<1>(context)?;
+                let index = arguments[0].into_int_value();
+
+                era_compiler_llvm_context::evm_contract_context::blob_hash(context, index).map(Some)
+            }
             Name::Difficulty | Name::Prevrandao => {
                 era_compiler_llvm_context::evm_contract_context::difficulty(context).map(Some)
             }
@@ -756,6 +762,9 @@ impl FunctionCall {
             Name::BaseFee => {
                 era_compiler_llvm_context::evm_contract_context::basefee(context).map(Some)
             }
+            Name::BlobBaseFee => {
+                era_compiler_llvm_context::evm_contract_context::blob_base_fee(context).map(Some)
+            }
             Name::MSize => {
                 era_compiler_llvm_context::evm_contract_context::msize(context).map(Some)
             }
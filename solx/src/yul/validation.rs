@@ -0,0 +1,208 @@
+//!
+//! The Yul source code validation.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use solx_yul::yul::lexer::Lexer;
+use solx_yul::yul::parser::statement::object::Object;
+
+use crate::yul::parser::dialect::era::EraDialect;
+
+///
+/// Validates the Yul sources of `solc_input` with `solc`.
+///
+/// `solc` only accepts a single source file with a single top-level object, so the others are
+/// validated one object at a time. Each object is passed in a copy of its file with the other
+/// top-level objects blanked out, so the error locations stay the same. References to objects
+/// outside of the validated one are resolved by `solx`, so `solc` errors located at the
+/// references that `solx` resolves are discarded.
+///
+pub fn validate(
+    solc_compiler: &solx_solc::Compiler,
+    solc_input: &mut solx_standard_json::Input,
+    messages: &mut Vec<solx_standard_json::OutputError>,
+) -> anyhow::Result<solx_standard_json::Output> {
+    let mut parsed_objects = Vec::with_capacity(solc_input.sources.len());
+    let mut units = Vec::with_capacity(solc_input.sources.len());
+    for (path, source) in solc_input.sources.iter_mut() {
+        if source.try_resolve().is_err() {
+            continue;
+        }
+        let source_code = source.content().expect("Always exists");
+
+        let mut lexer = Lexer::new(source_code);
+        let objects = match Object::<EraDialect>::parse_all(&mut lexer) {
+            Ok(objects) => objects,
+            Err(_) => {
+                units.push((path.to_owned(), source_code.to_owned()));
+                continue;
+            }
+        };
+        if objects.len() <= 1 {
+            units.push((path.to_owned(), source_code.to_owned()));
+            parsed_objects.push((path.to_owned(), objects));
+            continue;
+        }
+
+        for (index, object) in objects.iter().enumerate() {
+            let start = object.location.offset;
+            let end = objects
+                .get(index + 1)
+                .map(|object| object.location.offset)
+                .unwrap_or(source_code.len());
+            let mut blanked_source_code = String::with_capacity(source_code.len());
+            for (offset, character) in source_code.char_indices() {
+                if (start..end).contains(&offset) || character == '\n' {
+                    blanked_source_code.push(character);
+                } else {
+                    blanked_source_code.push_str(" ".repeat(character.len_utf8()).as_str());
+                }
+            }
+            units.push((path.to_owned(), blanked_source_code));
+        }
+        parsed_objects.push((path.to_owned(), objects));
+    }
+
+    if solc_input.sources.len() == 1 && units.len() == 1 {
+        return solc_compiler.validate_yul_standard_json(solc_input, messages);
+    }
+
+    let top_level_objects = parsed_objects
+        .iter()
+        .flat_map(|(_path, objects)| objects.iter())
+        .map(|object| (object.identifier.as_str(), object))
+        .collect::<BTreeMap<&str, &Object<EraDialect>>>();
+    let mut resolved_references = BTreeSet::new();
+    for (path, objects) in parsed_objects.iter() {
+        for object in objects.iter() {
+            collect_resolved_references(
+                path.as_str(),
+                object,
+                &top_level_objects,
+                &mut resolved_references,
+            );
+        }
+    }
+
+    let mut solc_output = solx_standard_json::Output::new(&solc_input.sources, messages);
+    for (path, source_code) in units.into_iter() {
+        let mut unit_input = solx_standard_json::Input::from_yul_sources(
+            BTreeMap::from([(path, solx_standard_json::InputSource::from(source_code))]),
+            solc_input.settings.libraries.clone(),
+            solx_standard_json::InputOptimizer::default(),
+            &solx_standard_json::InputSelection::default(),
+            solx_standard_json::InputMetadata::default(),
+            vec![],
+        );
+        unit_input.settings.evm_version = solc_input.settings.evm_version;
+        let unit_output = solc_compiler.validate_yul_standard_json(&mut unit_input, &mut vec![])?;
+        solc_output.errors.extend(
+            unit_output
+                .errors
+                .into_iter()
+                .filter(|error| !is_resolved_object_reference(error, &resolved_references)),
+        );
+    }
+    Ok(solc_output)
+}
+
+///
+/// Collects the locations of the object references in `object` and all objects inside it that
+/// are resolved by `solx`.
+///
+/// The references are collected as pairs of the source path and the byte offset of the literal.
+///
+fn collect_resolved_references(
+    path: &str,
+    object: &Object<EraDialect>,
+    top_level_objects: &BTreeMap<&str, &Object<EraDialect>>,
+    references: &mut BTreeSet<(String, usize)>,
+) {
+    let mut dependencies = solx_yul::Dependencies::new(object.identifier.as_str());
+    object.code.accumulate_evm_dependencies(&mut dependencies);
+    for (reference, location) in dependencies.references.iter() {
+        if is_resolved_object_path(reference.as_str(), object, top_level_objects) {
+            references.insert((path.to_owned(), location.offset));
+        }
+    }
+
+    for inner_object in object
+        .inner_object
+        .as_deref()
+        .into_iter()
+        .chain(object.nested_objects.iter())
+    {
+        collect_resolved_references(path, inner_object, top_level_objects, references);
+    }
+}
+
+///
+/// Checks whether the dotted object `path` referenced from `object` resolves against the object
+/// tree.
+///
+/// The first segment is either `object` itself, an object or data section inside it, or a
+/// top-level object from any source. Each of the following segments is an object or data section
+/// inside the previous one, where data sections may only be the last segment.
+///
+fn is_resolved_object_path(
+    path: &str,
+    object: &Object<EraDialect>,
+    top_level_objects: &BTreeMap<&str, &Object<EraDialect>>,
+) -> bool {
+    let mut segments = path.split('.');
+    let first_segment = segments.next().expect("Always exists");
+    let mut current_object = if first_segment == object.identifier.as_str() {
+        object
+    } else if let Some(inner_object) = find_inner_object(object, first_segment) {
+        inner_object
+    } else if let Some(top_level_object) = top_level_objects.get(first_segment) {
+        *top_level_object
+    } else {
+        return path == first_segment && object.data.contains_key(first_segment);
+    };
+
+    let segments = segments.collect::<Vec<&str>>();
+    for (index, segment) in segments.iter().enumerate() {
+        match find_inner_object(current_object, segment) {
+            Some(inner_object) => current_object = inner_object,
+            None => {
+                return index + 1 == segments.len() && current_object.data.contains_key(*segment)
+            }
+        }
+    }
+    true
+}
+
+///
+/// Finds the object with `identifier` directly inside `object`.
+///
+fn find_inner_object<'a>(
+    object: &'a Object<EraDialect>,
+    identifier: &str,
+) -> Option<&'a Object<EraDialect>> {
+    object
+        .inner_object
+        .as_deref()
+        .into_iter()
+        .chain(object.nested_objects.iter())
+        .find(|inner_object| inner_object.identifier.as_str() == identifier)
+}
+
+///
+/// Checks whether `error` is located at one of the object references resolved by `solx`.
+///
+fn is_resolved_object_reference(
+    error: &solx_standard_json::OutputError,
+    references: &BTreeSet<(String, usize)>,
+) -> bool {
+    error
+        .source_location
+        .as_ref()
+        .and_then(|location| {
+            let start = usize::try_from(location.start).ok()?;
+            Some(references.contains(&(location.file.to_owned(), start)))
+        })
+        .unwrap_or_default()
+}
@@ -0,0 +1,73 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let cache_directory = TempDir::with_prefix("solx_cache")?;
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--cache-dir",
+        cache_directory.path().to_str().expect("Always valid"),
+    ];
+
+    let first = crate::cli::execute_solx(args)?.success();
+    assert!(
+        std::fs::read_dir(cache_directory.path())?.next().is_some(),
+        "The cache directory is empty"
+    );
+
+    let second = crate::cli::execute_solx(args)?.success();
+    assert_eq!(
+        first.get_output().stdout,
+        second.get_output().stdout,
+        "The cached output differs from the compiled one"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let cache_directory = TempDir::with_prefix("solx_cache")?;
+
+    let args = &[
+        "--bin",
+        "--yul",
+        crate::common::TEST_YUL_CONTRACT_PATH,
+        "--cache-dir",
+        cache_directory.path().to_str().expect("Always valid"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success();
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let cache_directory = TempDir::with_prefix("solx_cache")?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_PATH,
+        "--cache-dir",
+        cache_directory.path().to_str().expect("Always valid"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success();
+
+    Ok(())
+}
@@ -0,0 +1,64 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let reproducer_directory = TempDir::with_prefix("solx_reproducer")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--process-timeout",
+        "0",
+        "--crash-reproducer-dir",
+        reproducer_directory.path().to_str().expect("Always valid"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("solx --recursive-process"));
+
+    let reproducer = std::fs::read_dir(reproducer_directory.path())?
+        .next()
+        .expect("The reproducer is missing")?;
+    let args = &[
+        "--recursive-process",
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+    ];
+    let result = crate::cli::execute_solx_with_stdin(
+        args,
+        reproducer.path().to_str().expect("Always valid"),
+    )?;
+    result.success();
+
+    Ok(())
+}
+
+#[test]
+fn in_process() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let reproducer_directory = TempDir::with_prefix("solx_reproducer")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--crash-reproducer-dir",
+        reproducer_directory.path().to_str().expect("Always valid"),
+        "--in-process",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "`crash-reproducer-dir` cannot be used with `in-process`",
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,128 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[crate::common::TEST_SOLIDITY_CONTRACT_PATH, "--emit-llvm-ir"];
+
+    let result = crate::cli::execute_solx(args)?;
+
+    result
+        .success()
+        .stdout(predicate::str::contains("Deploy LLVM IR").count(1))
+        .stdout(predicate::str::contains("Deploy optimized LLVM IR").count(1))
+        .stdout(predicate::str::contains("Runtime LLVM IR").count(1))
+        .stdout(predicate::str::contains("Runtime optimized LLVM IR").count(1));
+
+    Ok(())
+}
+
+#[test]
+fn yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--yul",
+        crate::common::TEST_YUL_CONTRACT_PATH,
+        "--emit-llvm-ir",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+
+    result
+        .success()
+        .stdout(predicate::str::contains("Deploy LLVM IR").count(1))
+        .stdout(predicate::str::contains("Runtime optimized LLVM IR").count(1));
+
+    Ok(())
+}
+
+#[test]
+fn output_dir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_output")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--emit-llvm-ir",
+        "--output-dir",
+        output_directory.path().to_str().expect("Always valid"),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success();
+
+    let file_names = std::fs::read_dir(output_directory.path())?
+        .map(|entry| Ok(entry?.file_name().to_string_lossy().to_string()))
+        .collect::<anyhow::Result<Vec<String>>>()?;
+    for suffix in [
+        ".ll",
+        "_optimized.ll",
+        ".ll-runtime",
+        "_optimized.ll-runtime",
+    ] {
+        assert!(
+            file_names.iter().any(|name| name.ends_with(suffix)),
+            "No `{suffix}` file among {file_names:?}"
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn debug_output_dir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let debug_output_directory = TempDir::with_prefix("debug_output")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--emit-llvm-ir",
+        "--debug-output-dir",
+        debug_output_directory.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Runtime optimized LLVM IR").count(1));
+
+    let optimized_files = std::fs::read_dir(debug_output_directory.path())?
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            entry
+                .file_name()
+                .to_string_lossy()
+                .ends_with(".optimized.ll")
+        })
+        .count();
+    assert_eq!(optimized_files, 2);
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_PATH,
+        "--emit-llvm-ir",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Cannot output data outside of JSON in standard JSON mode.",
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,117 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[crate::common::TEST_SOLIDITY_CONTRACT_PATH, "--ethir"];
+
+    let result = crate::cli::execute_solx(args)?;
+
+    result
+        .success()
+        .stdout(predicate::str::contains("Deploy Ethereal IR").count(1))
+        .stdout(predicate::str::contains("Runtime Ethereal IR").count(1))
+        .stdout(predicate::str::contains("function main {"))
+        .stdout(predicate::str::contains("stack_usage: "));
+
+    Ok(())
+}
+
+#[test]
+fn parse_back() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[crate::common::TEST_SOLIDITY_CONTRACT_PATH, "--ethir"];
+
+    let result = crate::cli::execute_solx(args)?;
+    let stdout = String::from_utf8(result.success().get_output().stdout.to_owned())?;
+
+    let runtime_ethir = stdout
+        .split("Runtime Ethereal IR:\n")
+        .nth(1)
+        .and_then(|output| output.split("\n======= ").next())
+        .expect("Always exists");
+    let text: solx_evm_assembly::ethereal_ir::text::Text = runtime_ethir.parse()?;
+    assert!(!text.functions.is_empty(), "No functions parsed");
+    assert!(
+        !text.functions[0].blocks.is_empty(),
+        "No blocks parsed in the entry function"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn internal_function_names() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_INTERNAL_FUNCTION_PATH,
+        "--ethir",
+        "--recover-internal-functions",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains(
+            "recursive function fun_increment_",
+        ))
+        .stdout(predicate::str::contains("recursive function internal_").not());
+
+    Ok(())
+}
+
+#[test]
+fn internal_function_recovery_disabled() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_INTERNAL_FUNCTION_PATH,
+        "--ethir",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("recursive function fun_increment_").not());
+
+    Ok(())
+}
+
+#[test]
+fn via_ir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--ethir",
+        "--via-ir",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Ethereal IR is only produced by the EVM assembly codegen",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["--yul", crate::common::TEST_YUL_CONTRACT_PATH, "--ethir"];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "can be only emitted for Solidity contracts",
+    ));
+
+    Ok(())
+}
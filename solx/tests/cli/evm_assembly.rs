@@ -0,0 +1,342 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+use test_case::test_case;
+
+/// The instructions with Yul builtin counterparts, with their builtin names, input sizes, and
+/// output sizes.
+const OPCODES: [(&str, &str, usize, usize); 83] = [
+    ("STOP", "stop", 0, 0),
+    ("ADD", "add", 2, 1),
+    ("MUL", "mul", 2, 1),
+    ("SUB", "sub", 2, 1),
+    ("DIV", "div", 2, 1),
+    ("SDIV", "sdiv", 2, 1),
+    ("MOD", "mod", 2, 1),
+    ("SMOD", "smod", 2, 1),
+    ("ADDMOD", "addmod", 3, 1),
+    ("MULMOD", "mulmod", 3, 1),
+    ("EXP", "exp", 2, 1),
+    ("SIGNEXTEND", "signextend", 2, 1),
+    ("LT", "lt", 2, 1),
+    ("GT", "gt", 2, 1),
+    ("SLT", "slt", 2, 1),
+    ("SGT", "sgt", 2, 1),
+    ("EQ", "eq", 2, 1),
+    ("ISZERO", "iszero", 1, 1),
+    ("AND", "and", 2, 1),
+    ("OR", "or", 2, 1),
+    ("XOR", "xor", 2, 1),
+    ("NOT", "not", 1, 1),
+    ("BYTE", "byte", 2, 1),
+    ("SHL", "shl", 2, 1),
+    ("SHR", "shr", 2, 1),
+    ("SAR", "sar", 2, 1),
+    ("SHA3", "keccak256", 2, 1),
+    ("KECCAK256", "keccak256", 2, 1),
+    ("ADDRESS", "address", 0, 1),
+    ("BALANCE", "balance", 1, 1),
+    ("ORIGIN", "origin", 0, 1),
+    ("CALLER", "caller", 0, 1),
+    ("CALLVALUE", "callvalue", 0, 1),
+    ("CALLDATALOAD", "calldataload", 1, 1),
+    ("CALLDATASIZE", "calldatasize", 0, 1),
+    ("CALLDATACOPY", "calldatacopy", 3, 0),
+    ("CODESIZE", "codesize", 0, 1),
+    ("CODECOPY", "codecopy", 3, 0),
+    ("GASPRICE", "gasprice", 0, 1),
+    ("EXTCODESIZE", "extcodesize", 1, 1),
+    ("EXTCODECOPY", "extcodecopy", 4, 0),
+    ("RETURNDATASIZE", "returndatasize", 0, 1),
+    ("RETURNDATACOPY", "returndatacopy", 3, 0),
+    ("EXTCODEHASH", "extcodehash", 1, 1),
+    ("BLOCKHASH", "blockhash", 1, 1),
+    ("COINBASE", "coinbase", 0, 1),
+    ("TIMESTAMP", "timestamp", 0, 1),
+    ("NUMBER", "number", 0, 1),
+    ("DIFFICULTY", "difficulty", 0, 1),
+    ("PREVRANDAO", "prevrandao", 0, 1),
+    ("GASLIMIT", "gaslimit", 0, 1),
+    ("CHAINID", "chainid", 0, 1),
+    ("SELFBALANCE", "selfbalance", 0, 1),
+    ("BASEFEE", "basefee", 0, 1),
+    ("BLOBHASH", "blobhash", 1, 1),
+    ("BLOBBASEFEE", "blobbasefee", 0, 1),
+    ("POP", "pop", 1, 0),
+    ("MLOAD", "mload", 1, 1),
+    ("MSTORE", "mstore", 2, 0),
+    ("MSTORE8", "mstore8", 2, 0),
+    ("SLOAD", "sload", 1, 1),
+    ("SSTORE", "sstore", 2, 0),
+    ("PC", "pc", 0, 1),
+    ("MSIZE", "msize", 0, 1),
+    ("GAS", "gas", 0, 1),
+    ("TLOAD", "tload", 1, 1),
+    ("TSTORE", "tstore", 2, 0),
+    ("MCOPY", "mcopy", 3, 0),
+    ("LOG0", "log0", 2, 0),
+    ("LOG1", "log1", 3, 0),
+    ("LOG2", "log2", 4, 0),
+    ("LOG3", "log3", 5, 0),
+    ("LOG4", "log4", 6, 0),
+    ("CREATE", "create", 3, 1),
+    ("CALL", "call", 7, 1),
+    ("CALLCODE", "callcode", 7, 1),
+    ("RETURN", "return", 2, 0),
+    ("DELEGATECALL", "delegatecall", 6, 1),
+    ("CREATE2", "create2", 4, 1),
+    ("STATICCALL", "staticcall", 6, 1),
+    ("REVERT", "revert", 2, 0),
+    ("INVALID", "invalid", 0, 0),
+    ("SELFDESTRUCT", "selfdestruct", 1, 0),
+];
+
+#[test]
+fn bin() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_EVM_ASSEMBLY_CONTRACT_PATH,
+        "--evm-assembly",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Test.json:Test"))
+        .stdout(predicate::str::contains("Binary"));
+
+    Ok(())
+}
+
+#[test]
+fn factory_dependency() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_EVM_ASSEMBLY_CONTRACT_FACTORY_PATH,
+        crate::common::TEST_EVM_ASSEMBLY_CONTRACT_PATH,
+        "--evm-assembly",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary").count(2));
+
+    Ok(())
+}
+
+#[test]
+fn factory_dependency_missing() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_EVM_ASSEMBLY_CONTRACT_FACTORY_PATH,
+        "--evm-assembly",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Contract path not found for hash"));
+
+    Ok(())
+}
+
+#[test]
+fn ethir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_EVM_ASSEMBLY_CONTRACT_PATH,
+        "--evm-assembly",
+        "--ethir",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Runtime Ethereal IR").count(1));
+
+    Ok(())
+}
+
+#[test_case("--ast-json")]
+#[test_case("--abi")]
+#[test_case("--hashes")]
+#[test_case("--userdoc")]
+#[test_case("--devdoc")]
+#[test_case("--storage-layout")]
+#[test_case("--transient-storage-layout")]
+#[test_case("--asm-solc-json")]
+#[test_case("--ir-optimized")]
+fn unavailable(flag: &str) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_EVM_ASSEMBLY_CONTRACT_PATH,
+        "--evm-assembly",
+        flag,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "can be only emitted for Solidity contracts",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_input_runtime_code_missing() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_EVM_ASSEMBLY_CONTRACT_INVALID_PATH,
+        "--evm-assembly",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Runtime code data not found"));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_input_solidity() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--evm-assembly",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("EVM assembly parsing"));
+
+    Ok(())
+}
+
+#[test]
+fn excess_mode_yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_EVM_ASSEMBLY_CONTRACT_PATH,
+        "--evm-assembly",
+        "--yul",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Only one mode is allowed at the same time",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_EVM_ASSEMBLY_STANDARD_JSON_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"Factory\""))
+        .stdout(predicate::str::contains("bytecode"))
+        .stdout(predicate::str::contains("ethir"));
+
+    Ok(())
+}
+
+#[test]
+fn yul_builtin_parity() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let template: serde_json::Value = serde_json::from_str(
+        std::fs::read_to_string(crate::common::TEST_EVM_ASSEMBLY_CONTRACT_TRANSIENT_PATH)?.as_str(),
+    )?;
+    let instruction = |name: &str, value: Option<&str>| {
+        let mut instruction = serde_json::json!({
+            "begin": 0,
+            "end": 0,
+            "name": name,
+        });
+        if let Some(value) = value {
+            instruction["value"] = serde_json::Value::from(value);
+        }
+        instruction
+    };
+
+    let input_directory = TempDir::with_prefix("solx_opcodes")?;
+    let mut evm_assembly_paths = Vec::with_capacity(OPCODES.len());
+    let mut yul_paths = Vec::with_capacity(OPCODES.len());
+    for (name, builtin, input_size, output_size) in OPCODES.into_iter() {
+        let mut runtime_code = vec![instruction("PUSH", Some("0")); input_size];
+        runtime_code.push(instruction(name, None));
+        runtime_code.extend(vec![instruction("POP", None); output_size]);
+        runtime_code.push(instruction("STOP", None));
+        let mut evm_assembly = template.clone();
+        evm_assembly[".data"]["0"][".code"] = serde_json::Value::from(runtime_code);
+        let evm_assembly_path = input_directory.path().join(format!("{name}.json"));
+        std::fs::write(
+            evm_assembly_path.as_path(),
+            serde_json::to_string(&evm_assembly)?,
+        )?;
+        evm_assembly_paths.push(evm_assembly_path.to_string_lossy().into_owned());
+
+        let arguments = vec!["0"; input_size].join(", ");
+        let call = match output_size {
+            0 => format!("{builtin}({arguments})"),
+            _ => format!("pop({builtin}({arguments}))"),
+        };
+        let yul = format!(
+            r#"object "{name}" {{
+    code {{
+        datacopy(0, dataoffset("{name}_deployed"), datasize("{name}_deployed"))
+        return(0, datasize("{name}_deployed"))
+    }}
+    object "{name}_deployed" {{
+        code {{
+            {call}
+        }}
+    }}
+}}
+"#
+        );
+        let yul_path = input_directory.path().join(format!("{name}.yul"));
+        std::fs::write(yul_path.as_path(), yul)?;
+        yul_paths.push(yul_path.to_string_lossy().into_owned());
+    }
+
+    let evm_version = era_compiler_common::EVMVersion::Cancun.to_string();
+    for (mode, paths) in [("--evm-assembly", evm_assembly_paths), ("--yul", yul_paths)] {
+        let mut args = vec!["--evm-version", evm_version.as_str(), mode, "--bin"];
+        args.extend(paths.iter().map(String::as_str));
+
+        let result = crate::cli::execute_solx(args.as_slice())?;
+        result
+            .success()
+            .stdout(predicate::str::contains("Binary").count(OPCODES.len()));
+    }
+
+    Ok(())
+}
@@ -3,6 +3,7 @@
 //!
 
 use predicates::prelude::*;
+use test_case::test_case;
 
 #[test]
 fn default() -> anyhow::Result<()> {
@@ -24,6 +25,30 @@ fn default() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test_case(false)]
+#[test_case(true)]
+fn cancun_opcodes(via_ir: bool) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let evm_version = era_compiler_common::EVMVersion::Cancun.to_string();
+    let mut args = vec![
+        "--evm-version",
+        evm_version.as_str(),
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_CANCUN_PATH,
+    ];
+    if via_ir {
+        args.push("--via-ir");
+    }
+
+    let result = crate::cli::execute_solx(args.as_slice())?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"));
+
+    Ok(())
+}
+
 #[test]
 fn yul() -> anyhow::Result<()> {
     crate::common::setup()?;
@@ -38,9 +63,9 @@ fn yul() -> anyhow::Result<()> {
     ];
 
     let result = crate::cli::execute_solx(args)?;
-    result.failure().stderr(predicate::str::contains(
-        "EVM version is only allowed in Solidity mode",
-    ));
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"));
 
     Ok(())
 }
@@ -58,9 +83,72 @@ fn llvm_ir() -> anyhow::Result<()> {
         crate::common::TEST_LLVM_IR_CONTRACT_PATH,
     ];
 
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"));
+
+    Ok(())
+}
+
+#[test]
+fn yul_unavailable_instruction() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let evm_version = era_compiler_common::EVMVersion::Shanghai.to_string();
+    let args = &[
+        "--evm-version",
+        evm_version.as_str(),
+        "--yul",
+        "--bin",
+        crate::common::TEST_YUL_CONTRACT_TRANSIENT_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "The `tstore` instruction is only available since EVM version `cancun`, but the target is `shanghai`",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn llvm_ir_unavailable_intrinsic() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let evm_version = era_compiler_common::EVMVersion::Shanghai.to_string();
+    let args = &[
+        "--evm-version",
+        evm_version.as_str(),
+        "--llvm-ir",
+        "--bin",
+        crate::common::TEST_LLVM_IR_CONTRACT_TRANSIENT_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Intrinsic `@llvm.evm.tstore` is only available since EVM version `cancun`, but the target is `shanghai`",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn evm_assembly_unavailable_instruction() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let evm_version = era_compiler_common::EVMVersion::Shanghai.to_string();
+    let args = &[
+        "--evm-version",
+        evm_version.as_str(),
+        "--evm-assembly",
+        "--bin",
+        crate::common::TEST_EVM_ASSEMBLY_CONTRACT_TRANSIENT_PATH,
+    ];
+
     let result = crate::cli::execute_solx(args)?;
     result.failure().stderr(predicate::str::contains(
-        "EVM version is only allowed in Solidity mode",
+        "the `TSTORE` instruction at index 2 is only available since EVM version `cancun`, but the target is `shanghai`",
     ));
 
     Ok(())
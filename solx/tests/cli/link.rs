@@ -0,0 +1,109 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use std::path::PathBuf;
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+///
+/// Compiles the contract with an unlinked library, and returns the runtime bytecode file path.
+///
+fn compile_unlinked(output_directory: &TempDir) -> anyhow::Result<PathBuf> {
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH,
+        "--bin-runtime",
+        "--output-dir",
+        output_directory.path().to_str().expect("Always valid"),
+    ];
+    crate::cli::execute_solx(args)?.success();
+
+    let path = std::fs::read_dir(output_directory.path())?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .find(|path| {
+            path.to_string_lossy()
+                .ends_with("SimpleContract.bin-runtime")
+        })
+        .expect("Always exists");
+    Ok(path)
+}
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_output")?;
+    let path = compile_unlinked(&output_directory)?;
+    let library = format!(
+        "{}:SimpleLibrary=0x00000000000000000000000000000000deadbeef",
+        crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH
+    );
+
+    let args = &[
+        "--link",
+        path.to_str().expect("Always valid"),
+        "--libraries",
+        library.as_str(),
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Linking completed."));
+
+    let bytecode_hex = std::fs::read_to_string(path)?;
+    assert!(!bytecode_hex.contains("__$"));
+    assert!(bytecode_hex.contains("7300000000000000000000000000000000deadbeef"));
+
+    Ok(())
+}
+
+#[test]
+fn unresolved() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let output_directory = TempDir::with_prefix("solx_output")?;
+    let path = compile_unlinked(&output_directory)?;
+
+    let args = &["--link", path.to_str().expect("Always valid")];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("is still unresolved"));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_bytecode() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["--link", crate::common::TEST_SOLIDITY_CONTRACT_PATH];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Invalid bytecode hexadecimal"));
+
+    Ok(())
+}
+
+#[test]
+fn excess_mode_standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--link",
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Only one mode is allowed at the same time",
+    ));
+
+    Ok(())
+}
@@ -148,6 +148,269 @@ fn invalid_input_llvm_ir() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn unsupported_intrinsic() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--llvm-ir",
+        "--bin",
+        crate::common::TEST_LLVM_IR_CONTRACT_UNSUPPORTED_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains(
+            "LLVM IR validation: Intrinsic `@llvm.x86.sse2.pause` is not supported by the EVM target",
+        ))
+        .stderr(predicate::str::contains("Unsupported.ll:11:"));
+
+    Ok(())
+}
+
+#[test]
+fn unknown_intrinsic() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--llvm-ir",
+        "--bin",
+        crate::common::TEST_LLVM_IR_CONTRACT_UNKNOWN_INTRINSIC_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains(
+            "LLVM IR validation: Intrinsic `@llvm.evm.unknown` is not supported by the EVM target",
+        ))
+        .stderr(predicate::str::contains("UnknownIntrinsic.ll:11:"));
+
+    Ok(())
+}
+
+#[test]
+fn target_triple() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--llvm-ir",
+        "--bin",
+        crate::common::TEST_LLVM_IR_CONTRACT_TARGET_TRIPLE_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains(
+            "LLVM IR validation: Target triple `x86_64-unknown-linux-gnu` is not supported. Expected the `evm` architecture",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn data_layout() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--llvm-ir",
+        "--bin",
+        crate::common::TEST_LLVM_IR_CONTRACT_DATA_LAYOUT_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "LLVM IR validation: Data layout `e-m:e-p:64:64-i64:64-n32:64-S128` is not supported",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn address_space() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--llvm-ir",
+        "--bin",
+        crate::common::TEST_LLVM_IR_CONTRACT_ADDRESS_SPACE_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains(
+            "LLVM IR validation: Address space `9` is not supported. EVM address spaces are 0 to 6",
+        ))
+        .stderr(predicate::str::contains("AddressSpace.ll:6:"));
+
+    Ok(())
+}
+
+#[test]
+fn address_space_global() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--llvm-ir",
+        "--bin",
+        crate::common::TEST_LLVM_IR_CONTRACT_ADDRESS_SPACE_GLOBAL_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains(
+            "LLVM IR validation: Address space `8` is not supported. EVM address spaces are 0 to 6",
+        ))
+        .stderr(predicate::str::contains("AddressSpaceGlobal.ll:4:"));
+
+    Ok(())
+}
+
+#[test]
+fn verification() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--llvm-ir",
+        "--bin",
+        crate::common::TEST_LLVM_IR_CONTRACT_VERIFICATION_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains(
+            "LLVM IR validation: Verification: ",
+        ))
+        .stderr(predicate::str::contains(
+            "Instruction does not dominate all uses!",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn entry_point_parameters() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--llvm-ir",
+        "--bin",
+        crate::common::TEST_LLVM_IR_CONTRACT_ENTRY_POINT_PARAMETERS_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "LLVM IR validation: The entry point `@entry` must not have parameters",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn entry_point_private() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--llvm-ir",
+        "--bin",
+        crate::common::TEST_LLVM_IR_CONTRACT_ENTRY_POINT_PRIVATE_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "LLVM IR validation: The entry point `@entry` must not have private or internal linkage",
+    ));
+
+    Ok(())
+}
+
+#[test_case(false)]
+#[test_case(true)]
+fn deploy_code(absolute_paths: bool) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let mut paths = vec![
+        crate::common::TEST_LLVM_IR_CONTRACT_CONSTRUCTOR_PATH.to_owned(),
+        crate::common::TEST_LLVM_IR_CONTRACT_CONSTRUCTOR_DEPLOY_PATH.to_owned(),
+    ];
+    if absolute_paths {
+        for path in paths.iter_mut() {
+            *path = std::fs::canonicalize(path.as_str())?
+                .to_string_lossy()
+                .into_owned();
+        }
+    }
+    let mut args: Vec<&str> = paths.iter().map(String::as_str).collect();
+    args.extend(["--llvm-ir", "--asm", "--bin", "--bin-runtime"]);
+
+    let result = crate::cli::execute_solx(args.as_slice())?;
+    let output = Output::try_from(&result.success())?;
+    assert!(
+        output.deploy_assembly.contains("SSTORE"),
+        "The custom deploy code is missing"
+    );
+    assert!(
+        !output.runtime_assembly.contains("SSTORE"),
+        "The deploy code leaked into the runtime code"
+    );
+    output.check_runtime_code_embedding();
+
+    Ok(())
+}
+
+#[test]
+fn deploy_code_constructor_arguments() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_LLVM_IR_CONTRACT_CONSTRUCTOR_ARGUMENTS_PATH,
+        crate::common::TEST_LLVM_IR_CONTRACT_CONSTRUCTOR_ARGUMENTS_DEPLOY_PATH,
+        "--llvm-ir",
+        "--asm",
+        "--bin",
+        "--bin-runtime",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    let output = Output::try_from(&result.success())?;
+    for instruction in ["CODESIZE", "CODECOPY", "SSTORE"] {
+        assert!(
+            output.deploy_assembly.contains(instruction),
+            "The deploy code does not contain `{instruction}`"
+        );
+    }
+    assert!(
+        output.runtime_assembly.contains("SLOAD"),
+        "The runtime code does not contain `SLOAD`"
+    );
+    output.check_runtime_code_embedding();
+
+    Ok(())
+}
+
+#[test]
+fn deploy_code_without_runtime_code() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_LLVM_IR_CONTRACT_CONSTRUCTOR_DEPLOY_PATH,
+        "--llvm-ir",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("has no runtime code"));
+
+    Ok(())
+}
+
 #[test]
 fn missing_file() -> anyhow::Result<()> {
     crate::common::setup()?;
@@ -231,3 +494,62 @@ fn standard_json_missing_file() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+///
+/// The `--asm --bin --bin-runtime` output of a single contract.
+///
+struct Output {
+    /// The deploy code assembly.
+    deploy_assembly: String,
+    /// The runtime code assembly.
+    runtime_assembly: String,
+    /// The deploy bytecode.
+    bytecode: String,
+    /// The runtime bytecode.
+    runtime_bytecode: String,
+}
+
+impl Output {
+    ///
+    /// Checks that the deploy bytecode consists of the deploy code followed by the runtime code.
+    ///
+    fn check_runtime_code_embedding(&self) {
+        assert!(
+            !self.runtime_bytecode.is_empty(),
+            "The runtime bytecode is empty"
+        );
+        let runtime_code_offset = self
+            .bytecode
+            .find(self.runtime_bytecode.as_str())
+            .expect("The runtime bytecode is not embedded into the deploy bytecode");
+        assert!(runtime_code_offset > 0, "The deploy code is empty");
+    }
+}
+
+impl TryFrom<&assert_cmd::assert::Assert> for Output {
+    type Error = anyhow::Error;
+
+    fn try_from(assert: &assert_cmd::assert::Assert) -> Result<Self, Self::Error> {
+        let stdout = String::from_utf8(assert.get_output().stdout.to_owned())?;
+        let section = |header: &str, next_header: &str| -> anyhow::Result<String> {
+            let (_, section) = stdout
+                .split_once(header)
+                .ok_or_else(|| anyhow::anyhow!("`{header}` is missing"))?;
+            let section = match section.split_once(next_header) {
+                Some((section, _)) => section,
+                None => section,
+            };
+            Ok(section.trim().to_owned())
+        };
+
+        Ok(Self {
+            deploy_assembly: section(
+                "Deploy LLVM EVM assembly:\n",
+                "Runtime LLVM EVM assembly:\n",
+            )?,
+            runtime_assembly: section("Runtime LLVM EVM assembly:\n", "Binary:\n")?,
+            bytecode: section("Binary:\n", "Binary of the runtime part:\n")?,
+            runtime_bytecode: section("Binary of the runtime part:\n", "\n")?,
+        })
+    }
+}
@@ -17,13 +17,23 @@ mod ast_json;
 mod base_path;
 mod bin;
 mod bin_runtime;
+mod cache_dir;
+mod cache_stats;
+mod crash_reproducer_dir;
 mod debug_output_dir;
 mod devdoc;
+mod emit_llvm_ir;
+mod ethir;
+mod ethir_max_block_instances;
+mod ethir_max_elements;
+mod evm_assembly;
 mod evm_version;
 mod hashes;
+mod in_process;
 mod include_path;
 mod ir_optimized;
 mod libraries;
+mod link;
 mod llvm_ir;
 mod llvm_options;
 mod metadata;
@@ -35,6 +45,8 @@ mod optimization;
 mod optimization_size_fallback;
 mod output_dir;
 mod overwrite;
+mod pipeline_fallback;
+mod process_timeout;
 mod recursive_process;
 mod remappings;
 mod standard_json;
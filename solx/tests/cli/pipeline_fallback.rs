@@ -0,0 +1,147 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--pipeline-fallback",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"))
+        .stderr(predicate::str::contains("stack-too-deep").not());
+
+    Ok(())
+}
+
+#[test]
+fn stack_too_deep() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_STACK_TOO_DEEP_PATH,
+        "--via-ir",
+        "--pipeline-fallback",
+        "--bin",
+        "--metadata",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"))
+        .stdout(predicate::str::contains("\"pipeline_fallback\":\"evmla\""))
+        .stderr(predicate::str::contains(
+            "StackTooDeep.sol:StackTooDeep` failed with a stack-too-deep error via Yul IR, so it was recompiled via EVM assembly.",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn stack_too_deep_disabled() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_STACK_TOO_DEEP_PATH,
+        "--via-ir",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("recompiled").not());
+
+    Ok(())
+}
+
+#[test]
+fn ethir_limits() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--ethir-max-elements",
+        "1",
+        "--pipeline-fallback",
+        "--bin",
+        "--metadata",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"))
+        .stdout(predicate::str::contains("\"pipeline_fallback\":\"yul\""))
+        .stderr(predicate::str::contains(
+            "Test.sol:Test` exceeded the Ethereal IR limits via EVM assembly, so it was recompiled via Yul IR.",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn ethir_limits_disabled() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--ethir-max-elements",
+        "1",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "The Ethereal IR limit of 1 elements is exceeded",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_YUL_CONTRACT_PATH,
+        "--yul",
+        "--pipeline-fallback",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Codegen pipeline fallback is only available in Solidity mode.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_PATH,
+        "--pipeline-fallback",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Codegen pipeline fallback must be passed via standard JSON input.",
+    ));
+
+    Ok(())
+}
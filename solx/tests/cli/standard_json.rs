@@ -107,6 +107,24 @@ fn yul_urls() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn yul_multiple_files() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_YUL_STANDARD_JSON_MULTIPLE_FILES_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"Proxy\""))
+        .stdout(predicate::str::contains("\"Return\""));
+
+    Ok(())
+}
+
 #[test]
 fn yul_urls_invalid() -> anyhow::Result<()> {
     crate::common::setup()?;
@@ -352,6 +370,30 @@ fn select_evm_bytecode() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn select_evm_source_map_via_ir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_SOURCE_MAP_VIA_IR_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    let stdout = String::from_utf8(result.success().get_output().stdout.to_owned())?;
+    let output: serde_json::Value = serde_json::from_str(stdout.as_str())?;
+    let source_map = output["contracts"]["A"]["C"]["evm"]["deployedBytecode"]["sourceMap"]
+        .as_str()
+        .expect("Always exists");
+    assert!(source_map
+        .parse::<solx::build::contract::source_map::SourceMap>()?
+        .entries
+        .iter()
+        .any(|entry| entry.source_index == 0 && entry.length > 0));
+
+    Ok(())
+}
+
 #[test]
 fn select_evm_deployed_bytecode() -> anyhow::Result<()> {
     crate::common::setup()?;
@@ -389,6 +431,44 @@ fn select_evm_bytecode_opcodes() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn select_evm_llvm_ir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_LLVM_IR_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"llvmIr\"").count(1))
+        .stdout(predicate::str::contains("\"llvmIrOptimized\"").count(1))
+        .stdout(predicate::str::contains("\"object\"").not());
+
+    Ok(())
+}
+
+#[test]
+fn select_evm_ethir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_ETHIR_PATH,
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"ethir\"").count(2))
+        .stdout(predicate::str::contains("function main {"))
+        .stdout(predicate::str::contains("\"object\"").not());
+
+    Ok(())
+}
+
 #[test]
 fn select_evm_deployed_bytecode_link_references() -> anyhow::Result<()> {
     crate::common::setup()?;
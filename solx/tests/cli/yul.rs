@@ -102,6 +102,117 @@ fn object_naming() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn data() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[crate::common::TEST_YUL_CONTRACT_DATA_PATH, "--yul", "--bin"];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("c0ffee0123456789"));
+
+    Ok(())
+}
+
+#[test]
+fn nested_objects() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[crate::common::TEST_YUL_CONTRACT_DATA_PATH, "--yul", "--bin"];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Data.yul:Factory"))
+        .stdout(predicate::str::contains("Data.yul:Child"))
+        .stdout(predicate::str::contains("Binary").count(2));
+
+    Ok(())
+}
+
+#[test]
+fn multiple_files() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_YUL_CONTRACT_PATH,
+        crate::common::TEST_YUL_CONTRACT_DEPLOYER_PATH,
+        "--yul",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Test.yul:Return"))
+        .stdout(predicate::str::contains("Deployer.yul:Deployer"))
+        .stdout(predicate::str::contains("Deployer.yul:Proxy"))
+        .stdout(predicate::str::contains("Binary").count(3));
+
+    Ok(())
+}
+
+#[test]
+fn cyclic_references() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_YUL_CONTRACT_CYCLIC_PATH,
+        "--yul",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Cyclic object references"));
+
+    Ok(())
+}
+
+#[test]
+fn duplicate_objects() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_YUL_CONTRACT_PATH,
+        crate::common::TEST_YUL_CONTRACT_DUPLICATE_PATH,
+        "--yul",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains(
+            "Object `Return` is defined more than once",
+        ))
+        .stderr(predicate::str::contains("Duplicate.yul:Return"))
+        .stderr(predicate::str::contains("Test.yul:Return"));
+
+    Ok(())
+}
+
+#[test]
+fn unknown_reference() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_YUL_CONTRACT_UNKNOWN_REFERENCE_PATH,
+        "--yul",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("First.Second_deployed"));
+
+    Ok(())
+}
+
 #[test]
 fn solc() -> anyhow::Result<()> {
     crate::common::setup()?;
@@ -128,6 +239,32 @@ fn invalid_input() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn syntax_errors() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_YUL_CONTRACT_SYNTAX_ERRORS_PATH,
+        "--yul",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_solx(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains(format!(
+            "{}:4:22",
+            crate::common::TEST_YUL_CONTRACT_SYNTAX_ERRORS_PATH
+        )))
+        .stderr(predicate::str::contains(format!(
+            "{}:14:17",
+            crate::common::TEST_YUL_CONTRACT_SYNTAX_ERRORS_PATH
+        )))
+        .stderr(predicate::str::contains("^^"));
+
+    Ok(())
+}
+
 #[test]
 fn standard_json() -> anyhow::Result<()> {
     crate::common::setup()?;
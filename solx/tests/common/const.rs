@@ -28,6 +28,10 @@ pub const TEST_SOLIDITY_CONTRACT_LINKER_MIXED_DEPS_PATH: &str =
 pub const TEST_SOLIDITY_CONTRACT_LINKER_MIXED_DEPS_MULTI_LEVEL_PATH: &str =
     "tests/data/contracts/solidity/LinkedMixedDepsMultiLevel.sol";
 
+/// A test input file.
+pub const TEST_SOLIDITY_CONTRACT_INTERNAL_FUNCTION_PATH: &str =
+    "tests/data/contracts/solidity/InternalFunction.sol";
+
 /// A test input file.
 pub const TEST_SOLIDITY_CONTRACT_OPTIMIZED_PATH: &str =
     "tests/data/contracts/solidity/Optimized.sol";
@@ -36,12 +40,52 @@ pub const TEST_SOLIDITY_CONTRACT_OPTIMIZED_PATH: &str =
 pub const TEST_SOLIDITY_CONTRACT_INTERFACE_EMPTY_YUL_PATH: &str =
     "tests/data/contracts/solidity/InterfaceEmptyYul.sol";
 
+/// A test input file.
+pub const TEST_SOLIDITY_CONTRACT_IMMUTABLES_PATH: &str =
+    "tests/data/contracts/solidity/Immutables.sol";
+
+/// A test input file.
+pub const TEST_SOLIDITY_CONTRACT_STACK_TOO_DEEP_PATH: &str =
+    "tests/data/contracts/solidity/StackTooDeep.sol";
+
+/// A test input file.
+pub const TEST_SOLIDITY_CONTRACT_CANCUN_PATH: &str = "tests/data/contracts/solidity/Cancun.sol";
+
+/// A test input file.
+pub const TEST_SOLIDITY_CONTRACT_STORAGE_PATH: &str = "tests/data/contracts/solidity/Storage.sol";
+
+/// A test input file.
+pub const TEST_SOLIDITY_CONTRACT_SELF_DESTRUCT_PATH: &str =
+    "tests/data/contracts/solidity/SelfDestruct.sol";
+
 /// A test input file.
 pub const TEST_YUL_CONTRACT_PATH: &str = "tests/data/contracts/yul/Test.yul";
 
 /// A test input file.
 pub const TEST_YUL_CONTRACT_OBJECT_NAMING_PATH: &str = "tests/data/contracts/yul/ObjectNaming.yul";
 
+/// A test input file.
+pub const TEST_YUL_CONTRACT_DATA_PATH: &str = "tests/data/contracts/yul/Data.yul";
+
+/// A test input file.
+pub const TEST_YUL_CONTRACT_DEPLOYER_PATH: &str = "tests/data/contracts/yul/Deployer.yul";
+
+/// A test input file.
+pub const TEST_YUL_CONTRACT_CYCLIC_PATH: &str = "tests/data/contracts/yul/Cyclic.yul";
+
+/// A test input file.
+pub const TEST_YUL_CONTRACT_DUPLICATE_PATH: &str = "tests/data/contracts/yul/Duplicate.yul";
+
+/// A test input file.
+pub const TEST_YUL_CONTRACT_UNKNOWN_REFERENCE_PATH: &str =
+    "tests/data/contracts/yul/UnknownReference.yul";
+
+/// A test input file.
+pub const TEST_YUL_CONTRACT_SYNTAX_ERRORS_PATH: &str = "tests/data/contracts/yul/SyntaxErrors.yul";
+
+/// A test input file.
+pub const TEST_YUL_CONTRACT_TRANSIENT_PATH: &str = "tests/data/contracts/yul/Transient.yul";
+
 /// A test input file.
 pub const TEST_LLVM_IR_CONTRACT_PATH: &str = "tests/data/contracts/llvm_ir/Test.ll";
 
@@ -52,6 +96,76 @@ pub const TEST_LLVM_IR_CONTRACT_INVALID_PATH: &str = "tests/data/contracts/llvm_
 pub const TEST_LLVM_IR_CONTRACT_LINKER_ERROR_PATH: &str =
     "tests/data/contracts/llvm_ir/LinkerError.ll";
 
+/// A test input file.
+pub const TEST_LLVM_IR_CONTRACT_UNSUPPORTED_PATH: &str =
+    "tests/data/contracts/llvm_ir/Unsupported.ll";
+
+/// A test input file.
+pub const TEST_LLVM_IR_CONTRACT_TARGET_TRIPLE_PATH: &str =
+    "tests/data/contracts/llvm_ir/TargetTriple.ll";
+
+/// A test input file.
+pub const TEST_LLVM_IR_CONTRACT_DATA_LAYOUT_PATH: &str =
+    "tests/data/contracts/llvm_ir/DataLayout.ll";
+
+/// A test input file.
+pub const TEST_LLVM_IR_CONTRACT_ADDRESS_SPACE_PATH: &str =
+    "tests/data/contracts/llvm_ir/AddressSpace.ll";
+
+/// A test input file.
+pub const TEST_LLVM_IR_CONTRACT_ADDRESS_SPACE_GLOBAL_PATH: &str =
+    "tests/data/contracts/llvm_ir/AddressSpaceGlobal.ll";
+
+/// A test input file.
+pub const TEST_LLVM_IR_CONTRACT_VERIFICATION_PATH: &str =
+    "tests/data/contracts/llvm_ir/Verification.ll";
+
+/// A test input file.
+pub const TEST_LLVM_IR_CONTRACT_UNKNOWN_INTRINSIC_PATH: &str =
+    "tests/data/contracts/llvm_ir/UnknownIntrinsic.ll";
+
+/// A test input file.
+pub const TEST_LLVM_IR_CONTRACT_ENTRY_POINT_PARAMETERS_PATH: &str =
+    "tests/data/contracts/llvm_ir/EntryPointParameters.ll";
+
+/// A test input file.
+pub const TEST_LLVM_IR_CONTRACT_ENTRY_POINT_PRIVATE_PATH: &str =
+    "tests/data/contracts/llvm_ir/EntryPointPrivate.ll";
+
+/// A test input file.
+pub const TEST_LLVM_IR_CONTRACT_TRANSIENT_PATH: &str = "tests/data/contracts/llvm_ir/Transient.ll";
+
+/// A test input file.
+pub const TEST_LLVM_IR_CONTRACT_CONSTRUCTOR_PATH: &str =
+    "tests/data/contracts/llvm_ir/Constructor.ll";
+
+/// A test input file.
+pub const TEST_LLVM_IR_CONTRACT_CONSTRUCTOR_DEPLOY_PATH: &str =
+    "tests/data/contracts/llvm_ir/Constructor.deploy.ll";
+
+/// A test input file.
+pub const TEST_LLVM_IR_CONTRACT_CONSTRUCTOR_ARGUMENTS_PATH: &str =
+    "tests/data/contracts/llvm_ir/ConstructorArguments.ll";
+
+/// A test input file.
+pub const TEST_LLVM_IR_CONTRACT_CONSTRUCTOR_ARGUMENTS_DEPLOY_PATH: &str =
+    "tests/data/contracts/llvm_ir/ConstructorArguments.deploy.ll";
+
+/// A test input file.
+pub const TEST_EVM_ASSEMBLY_CONTRACT_PATH: &str = "tests/data/contracts/evm_assembly/Test.json";
+
+/// A test input file.
+pub const TEST_EVM_ASSEMBLY_CONTRACT_FACTORY_PATH: &str =
+    "tests/data/contracts/evm_assembly/Factory.json";
+
+/// A test input file.
+pub const TEST_EVM_ASSEMBLY_CONTRACT_TRANSIENT_PATH: &str =
+    "tests/data/contracts/evm_assembly/Transient.json";
+
+/// A test input file.
+pub const TEST_EVM_ASSEMBLY_CONTRACT_INVALID_PATH: &str =
+    "tests/data/contracts/evm_assembly/Invalid.json";
+
 /// A test input file.
 pub const TEST_SOLIDITY_STANDARD_JSON_NON_EXISTENT_PATH: &str =
     "tests/data/standard_json_input/non_existent.json";
@@ -91,10 +205,22 @@ pub const TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_PATH: &str =
 pub const TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_BYTECODE_PATH: &str =
     "tests/data/standard_json_input/select_evm_bytecode.json";
 
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_SOURCE_MAP_VIA_IR_PATH: &str =
+    "tests/data/standard_json_input/select_evm_source_map_via_ir.json";
+
 /// A test input file.
 pub const TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_BYTECODE_OPCODES_PATH: &str =
     "tests/data/standard_json_input/select_evm_bytecode_opcodes.json";
 
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_LLVM_IR_PATH: &str =
+    "tests/data/standard_json_input/select_evm_llvm_ir.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_ETHIR_PATH: &str =
+    "tests/data/standard_json_input/select_evm_ethir.json";
+
 /// A test input file.
 pub const TEST_SOLIDITY_STANDARD_JSON_SELECT_EVM_DEPLOYED_BYTECODE_PATH: &str =
     "tests/data/standard_json_input/select_evm_deployed_bytecode.json";
@@ -125,6 +251,10 @@ pub const TEST_YUL_STANDARD_JSON_PATH: &str = "tests/data/standard_json_input/yu
 /// A test input file.
 pub const TEST_YUL_STANDARD_JSON_URLS_PATH: &str = "tests/data/standard_json_input/yul_urls.json";
 
+/// A test input file.
+pub const TEST_YUL_STANDARD_JSON_MULTIPLE_FILES_PATH: &str =
+    "tests/data/standard_json_input/yul_multiple_files.json";
+
 /// A test input file.
 pub const TEST_YUL_STANDARD_JSON_URLS_INVALID_PATH: &str =
     "tests/data/standard_json_input/yul_urls_invalid.json";
@@ -133,6 +263,10 @@ pub const TEST_YUL_STANDARD_JSON_URLS_INVALID_PATH: &str =
 pub const TEST_LLVM_IR_STANDARD_JSON_PATH: &str =
     "tests/data/standard_json_input/llvm_ir_urls.json";
 
+/// A test input file.
+pub const TEST_LLVM_IR_STANDARD_JSON_IMMUTABLES_PATH: &str =
+    "tests/data/standard_json_input/llvm_ir_immutables.json";
+
 /// A test input file.
 pub const TEST_LLVM_IR_STANDARD_JSON_INVALID_PATH: &str =
     "tests/data/standard_json_input/llvm_ir_urls_invalid.json";
@@ -141,6 +275,10 @@ pub const TEST_LLVM_IR_STANDARD_JSON_INVALID_PATH: &str =
 pub const TEST_LLVM_IR_STANDARD_JSON_MISSING_FILE_PATH: &str =
     "tests/data/standard_json_input/llvm_ir_urls_missing_file.json";
 
+/// A test input file.
+pub const TEST_EVM_ASSEMBLY_STANDARD_JSON_PATH: &str =
+    "tests/data/standard_json_input/evm_assembly_urls.json";
+
 /// A test input file.
 pub const TEST_JSON_METADATA_HASH_IPFS_AND_METADATA: &str =
     "tests/data/standard_json_input/metadata_hash_ipfs_and_metadata.json";
@@ -160,6 +298,9 @@ pub const TEST_JSON_METADATA_HASH_NONE_NO_METADATA: &str =
 /// A test input file.
 pub const TEST_JSON_NO_CBOR_METADATA: &str = "tests/data/standard_json_input/no_cbor_metadata.json";
 
+/// The vendored ethdebug format schemas.
+pub const TEST_ETHDEBUG_SCHEMAS_PATH: &str = "tests/data/ethdebug_schemas";
+
 /// The broken input file path.
 pub const TEST_BROKEN_INPUT_PATH: &str = "tests/data/broken.bad";
 
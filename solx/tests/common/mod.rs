@@ -80,6 +80,7 @@ pub fn build_solidity_standard_json(
     selectors.insert(solx_standard_json::InputSelector::BytecodeSourceMap);
     selectors.insert(solx_standard_json::InputSelector::BytecodeGeneratedSources);
     selectors.insert(solx_standard_json::InputSelector::BytecodeFunctionDebugData);
+    selectors.insert(solx_standard_json::InputSelector::BytecodeEthdebug);
     selectors.insert(solx_standard_json::InputSelector::RuntimeBytecodeObject);
     selectors.insert(solx_standard_json::InputSelector::RuntimeBytecodeLinkReferences);
     selectors.insert(solx_standard_json::InputSelector::RuntimeBytecodeImmutableReferences);
@@ -88,6 +89,7 @@ pub fn build_solidity_standard_json(
     selectors.insert(solx_standard_json::InputSelector::RuntimeBytecodeSourceMap);
     selectors.insert(solx_standard_json::InputSelector::RuntimeBytecodeGeneratedSources);
     selectors.insert(solx_standard_json::InputSelector::RuntimeBytecodeFunctionDebugData);
+    selectors.insert(solx_standard_json::InputSelector::RuntimeBytecodeEthdebug);
     selectors.insert(solx_standard_json::InputSelector::AST);
     selectors.insert(solx_standard_json::InputSelector::ABI);
     selectors.insert(solx_standard_json::InputSelector::Metadata);
@@ -117,23 +119,34 @@ pub fn build_solidity_standard_json(
 
     let mut output = {
         let _lock = UNIT_TEST_LOCK.lock();
-        solc_compiler.standard_json(&mut input, &mut vec![], true, None, vec![], None)
+        solc_compiler.standard_json(&mut input, &mut vec![], true, None, vec![], None, None)
     }?;
     output.check_errors()?;
 
     let linker_symbols = libraries.as_linker_symbols()?;
 
-    let project = Project::try_from_solc_output(libraries, via_ir, &mut output, None)?;
+    let project = Project::try_from_solc_output(
+        libraries,
+        via_ir,
+        input.settings.ethir_limits,
+        input.settings.recover_internal_functions,
+        &mut output,
+        None,
+    )?;
     output.check_errors()?;
 
-    let build = project.compile_to_evm(
+    let mut build = project.compile_to_evm(
         &mut vec![],
         &input.settings.output_selection,
+        input.settings.evm_version,
         metadata_hash_type,
         optimizer_settings,
         vec![],
         None,
+        None,
+        solx::EVMProcessMode::Subprocess,
     )?;
+    build.format_errors_with_sources(&input.sources);
     build.check_errors()?;
 
     let cbor_data = vec![
@@ -196,10 +209,13 @@ pub fn build_yul_standard_json(
     let build = project.compile_to_evm(
         &mut vec![],
         &input.settings.output_selection,
+        input.settings.evm_version,
         era_compiler_common::EVMMetadataHashType::IPFS,
         optimizer_settings,
         vec![],
         None,
+        None,
+        solx::EVMProcessMode::Subprocess,
     )?;
     build.check_errors()?;
 
@@ -250,15 +266,19 @@ pub fn build_llvm_ir_standard_json(
         input.sources,
         input.settings.libraries,
         &input.settings.output_selection,
+        input.settings.evm_version,
         Some(&mut output),
     )?;
     let build = project.compile_to_evm(
         &mut vec![],
         &input.settings.output_selection,
+        input.settings.evm_version,
         era_compiler_common::EVMMetadataHashType::IPFS,
         optimizer_settings,
         vec![],
         None,
+        None,
+        solx::EVMProcessMode::Subprocess,
     )?;
     build.check_errors()?;
 
@@ -0,0 +1,11 @@
+target datalayout = "E-p:256:256-i256:256:256-S256-a:256:256"
+target triple = "evm-unknown-unknown"
+
+define void @entry() noreturn {
+entry:
+  store i256 42, ptr addrspace(9) null, align 1
+  call void @llvm.evm.return(ptr addrspace(1) null, i256 0)
+  unreachable
+}
+
+declare void @llvm.evm.return(ptr addrspace(1), i256)
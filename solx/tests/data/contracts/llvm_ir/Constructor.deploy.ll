@@ -0,0 +1,18 @@
+target datalayout = "E-p:256:256-i256:256:256-S256-a:256:256"
+target triple = "evm-unknown-unknown"
+
+define void @constructor() noreturn {
+entry:
+  store i256 42, ptr addrspace(5) null, align 1
+  %size = call i256 @llvm.evm.datasize(metadata !"runtime")
+  %offset = call i256 @llvm.evm.dataoffset(metadata !"runtime")
+  %source = inttoptr i256 %offset to ptr addrspace(4)
+  call void @llvm.memcpy.p1.p4.i256(ptr addrspace(1) null, ptr addrspace(4) %source, i256 %size, i1 false)
+  call void @llvm.evm.return(ptr addrspace(1) null, i256 %size)
+  unreachable
+}
+
+declare i256 @llvm.evm.datasize(metadata)
+declare i256 @llvm.evm.dataoffset(metadata)
+declare void @llvm.memcpy.p1.p4.i256(ptr addrspace(1) noalias nocapture writeonly, ptr addrspace(4) noalias nocapture readonly, i256, i1 immarg)
+declare void @llvm.evm.return(ptr addrspace(1), i256)
@@ -0,0 +1,10 @@
+target datalayout = "e-m:e-p:64:64-i64:64-n32:64-S128"
+target triple = "evm-unknown-unknown"
+
+define void @entry() noreturn {
+entry:
+  call void @llvm.evm.return(ptr addrspace(1) null, i256 0)
+  unreachable
+}
+
+declare void @llvm.evm.return(ptr addrspace(1), i256)
@@ -0,0 +1,10 @@
+target datalayout = "E-p:256:256-i256:256:256-S256-a:256:256"
+target triple = "evm-unknown-unknown"
+
+define private void @entry() noreturn {
+entry:
+  call void @llvm.evm.return(ptr addrspace(1) null, i256 0)
+  unreachable
+}
+
+declare void @llvm.evm.return(ptr addrspace(1), i256)
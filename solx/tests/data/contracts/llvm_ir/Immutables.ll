@@ -0,0 +1,13 @@
+target datalayout = "E-p:256:256-i256:256:256-S256-a:256:256"
+target triple = "evm-unknown-unknown"
+
+define void @runtime() noreturn {
+entry:
+  %value = call i256 @llvm.evm.loadimmutable(metadata !"value")
+  store i256 %value, ptr addrspace(1) null, align 1
+  call void @llvm.evm.return(ptr addrspace(1) null, i256 32)
+  unreachable
+}
+
+declare i256 @llvm.evm.loadimmutable(metadata)
+declare void @llvm.evm.return(ptr addrspace(1), i256)
@@ -0,0 +1,12 @@
+target datalayout = "E-p:256:256-i256:256:256-S256-a:256:256"
+target triple = "evm-unknown-unknown"
+
+define void @entry() noreturn {
+entry:
+  call void @llvm.evm.tstore(i256 0, i256 42)
+  call void @llvm.evm.return(ptr addrspace(1) null, i256 0)
+  unreachable
+}
+
+declare void @llvm.evm.tstore(i256, i256)
+declare void @llvm.evm.return(ptr addrspace(1), i256)
@@ -0,0 +1,17 @@
+target datalayout = "E-p:256:256-i256:256:256-S256-a:256:256"
+target triple = "evm-unknown-unknown"
+
+define void @entry() noreturn {
+entry:
+  br label %exit
+
+dead:
+  %value = add i256 1, 2
+  br label %exit
+
+exit:
+  call void @llvm.evm.return(ptr addrspace(1) null, i256 %value)
+  unreachable
+}
+
+declare void @llvm.evm.return(ptr addrspace(1), i256)
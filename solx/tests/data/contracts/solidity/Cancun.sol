@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.8.24;
+
+contract Cancun {
+    function blob(uint256 index) external view returns (bytes32 hash, uint256 fee) {
+        hash = blobhash(index);
+        fee = block.blobbasefee;
+    }
+
+    function transient(uint256 value) external returns (uint256 result) {
+        assembly {
+            tstore(0, value)
+            result := tload(0)
+        }
+    }
+
+    function copy(bytes memory data) external pure returns (bytes memory result) {
+        result = new bytes(data.length);
+        assembly {
+            mcopy(add(result, 32), add(data, 32), mload(data))
+        }
+    }
+}
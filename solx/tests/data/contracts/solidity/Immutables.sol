@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.6.5;
+
+contract Immutables {
+    uint256 immutable first;
+    address immutable second;
+
+    constructor(uint256 _first) {
+        first = _first;
+        second = msg.sender;
+    }
+
+    function get() public view returns(uint256, address) {
+        return (first, second);
+    }
+
+    function sum(uint256 value) public view returns(uint256) {
+        return first + value;
+    }
+}
@@ -0,0 +1,17 @@
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.8.0;
+
+contract InternalFunction {
+    function first(uint256 x) public pure returns (uint256) {
+        return increment(x);
+    }
+
+    function second(uint256 x) public pure returns (uint256) {
+        return increment(x) * 2;
+    }
+
+    function increment(uint256 x) internal pure returns (uint256) {
+        return x + 1;
+    }
+}
@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.8.0;
+
+contract StackTooDeep {
+    function mix(
+        uint256 a1,
+        uint256 a2,
+        uint256 a3,
+        uint256 a4,
+        uint256 a5,
+        uint256 a6,
+        uint256 a7,
+        uint256 a8,
+        uint256 a9,
+        uint256 a10,
+        uint256 a11,
+        uint256 a12
+    ) external pure returns (uint256 result) {
+        // Not memory-safe, so the values cannot be spilled to memory.
+        assembly {
+            mstore(0x80, a1)
+        }
+        result = (a1 * a12 + a2 * a11 + a3 * a10 + a4 * a9 + a5 * a8 + a6 * a7) ^
+            (a12 * a2 + a11 * a3 + a10 * a4 + a9 * a5 + a8 * a6 + a7 * a1);
+    }
+}
@@ -0,0 +1,17 @@
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.8.0;
+
+contract Storage {
+    uint256 public value;
+    uint8 small;
+    bool flag;
+    mapping(address => uint256) balances;
+
+    function set(uint256 _value) public {
+        value = _value;
+        small = uint8(_value);
+        flag = _value != 0;
+        balances[msg.sender] = _value;
+    }
+}
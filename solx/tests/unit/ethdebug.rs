@@ -0,0 +1,367 @@
+//!
+//! Unit tests for ethdebug.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use test_case::test_case;
+
+use solx::build::contract::ethdebug::Program;
+
+/// The identifier of the `ethdebug/format/program` schema.
+const PROGRAM_SCHEMA_ID: &str = "schema:ethdebug/format/program";
+
+///
+/// Reads the vendored ethdebug schemas from `directory` recursively, keyed by their IDs.
+///
+fn read_schemas(directory: &Path, schemas: &mut BTreeMap<String, serde_json::Value>) {
+    for entry in std::fs::read_dir(directory).expect("Always valid") {
+        let path = entry.expect("Always valid").path();
+        if path.is_dir() {
+            read_schemas(path.as_path(), schemas);
+            continue;
+        }
+        let schema = std::fs::read_to_string(path.as_path()).expect("Always valid");
+        let schema: serde_json::Value =
+            serde_json::from_str(schema.as_str()).expect("Always valid");
+        let id = schema["$id"].as_str().expect("Always exists").to_owned();
+        schemas.insert(id, schema);
+    }
+}
+
+///
+/// Validates the `instance` against the `schema`, pushing the violations to `errors`.
+///
+/// Only the JSON Schema keywords used by the ethdebug schemas are supported, except `pattern`,
+/// which is not checked.
+///
+fn validate(
+    schema: &serde_json::Value,
+    instance: &serde_json::Value,
+    schemas: &BTreeMap<String, serde_json::Value>,
+    path: &str,
+    errors: &mut Vec<String>,
+) {
+    let schema = match schema {
+        serde_json::Value::Bool(true) => return,
+        serde_json::Value::Bool(false) => {
+            errors.push(format!("{path}: no value is allowed"));
+            return;
+        }
+        serde_json::Value::Object(schema) => schema,
+        _ => panic!("Invalid schema at `{path}`"),
+    };
+    let is_valid = |schema: &serde_json::Value| {
+        let mut errors = Vec::new();
+        validate(schema, instance, schemas, path, &mut errors);
+        errors.is_empty()
+    };
+
+    if let Some(reference) = schema.get("$ref").and_then(serde_json::Value::as_str) {
+        let schema = schemas
+            .get(reference)
+            .unwrap_or_else(|| panic!("Schema `{reference}` not found"));
+        validate(schema, instance, schemas, path, errors);
+    }
+    if let Some(r#type) = schema.get("type") {
+        let types = match r#type {
+            serde_json::Value::Array(types) => types.iter().collect(),
+            r#type => vec![r#type],
+        };
+        let is_matched =
+            types
+                .into_iter()
+                .any(|r#type| match r#type.as_str().expect("Always valid") {
+                    "object" => instance.is_object(),
+                    "array" => instance.is_array(),
+                    "string" => instance.is_string(),
+                    "number" => instance.is_number(),
+                    "integer" => instance.is_u64() || instance.is_i64(),
+                    "boolean" => instance.is_boolean(),
+                    "null" => instance.is_null(),
+                    r#type => panic!("Unsupported type `{type}`"),
+                });
+        if !is_matched {
+            errors.push(format!("{path}: {instance} is not of type {type}"));
+            return;
+        }
+    }
+    if let Some(value) = schema.get("const") {
+        if value != instance {
+            errors.push(format!("{path}: {instance} is not {value}"));
+        }
+    }
+    if let Some(values) = schema.get("enum").and_then(serde_json::Value::as_array) {
+        if !values.contains(instance) {
+            errors.push(format!("{path}: {instance} is not one of {values:?}"));
+        }
+    }
+    if let (Some(minimum), Some(number)) = (
+        schema.get("minimum").and_then(serde_json::Value::as_f64),
+        instance.as_f64(),
+    ) {
+        if number < minimum {
+            errors.push(format!("{path}: {number} is less than {minimum}"));
+        }
+    }
+    if let (Some(length), Some(string)) = (
+        schema.get("minLength").and_then(serde_json::Value::as_u64),
+        instance.as_str(),
+    ) {
+        if (string.chars().count() as u64) < length {
+            errors.push(format!("{path}: {instance} is shorter than {length}"));
+        }
+    }
+
+    if let Some(items) = instance.as_array() {
+        if let Some(length) = schema.get("minItems").and_then(serde_json::Value::as_u64) {
+            if (items.len() as u64) < length {
+                errors.push(format!("{path}: fewer than {length} items"));
+            }
+        }
+        if let Some(length) = schema.get("maxItems").and_then(serde_json::Value::as_u64) {
+            if (items.len() as u64) > length {
+                errors.push(format!("{path}: more than {length} items"));
+            }
+        }
+        if let Some(item_schema) = schema.get("items") {
+            for (index, item) in items.iter().enumerate() {
+                validate(
+                    item_schema,
+                    item,
+                    schemas,
+                    format!("{path}/{index}").as_str(),
+                    errors,
+                );
+            }
+        }
+    }
+
+    if let Some(object) = instance.as_object() {
+        if let Some(count) = schema
+            .get("minProperties")
+            .and_then(serde_json::Value::as_u64)
+        {
+            if (object.len() as u64) < count {
+                errors.push(format!("{path}: fewer than {count} properties"));
+            }
+        }
+        if let Some(count) = schema
+            .get("maxProperties")
+            .and_then(serde_json::Value::as_u64)
+        {
+            if (object.len() as u64) > count {
+                errors.push(format!("{path}: more than {count} properties"));
+            }
+        }
+        for required in schema
+            .get("required")
+            .and_then(serde_json::Value::as_array)
+            .into_iter()
+            .flatten()
+        {
+            let required = required.as_str().expect("Always valid");
+            if !object.contains_key(required) {
+                errors.push(format!("{path}: property `{required}` is missing"));
+            }
+        }
+        let properties = schema
+            .get("properties")
+            .and_then(serde_json::Value::as_object);
+        for (key, value) in object.iter() {
+            let value_path = format!("{path}/{key}");
+            if let Some(key_schema) = schema.get("propertyNames") {
+                validate(
+                    key_schema,
+                    &serde_json::Value::String(key.to_owned()),
+                    schemas,
+                    value_path.as_str(),
+                    errors,
+                );
+            }
+            match (
+                properties.and_then(|properties| properties.get(key)),
+                schema.get("additionalProperties"),
+            ) {
+                (Some(property_schema), _) | (None, Some(property_schema)) => {
+                    validate(property_schema, value, schemas, value_path.as_str(), errors)
+                }
+                (None, None) => {}
+            }
+        }
+    }
+
+    for subschema in schema
+        .get("allOf")
+        .and_then(serde_json::Value::as_array)
+        .into_iter()
+        .flatten()
+    {
+        validate(subschema, instance, schemas, path, errors);
+    }
+    if let Some(subschemas) = schema.get("anyOf").and_then(serde_json::Value::as_array) {
+        if !subschemas.iter().any(is_valid) {
+            errors.push(format!("{path}: no `anyOf` subschema is matched"));
+        }
+    }
+    if let Some(subschemas) = schema.get("oneOf").and_then(serde_json::Value::as_array) {
+        let matched = subschemas
+            .iter()
+            .filter(|subschema| is_valid(subschema))
+            .count();
+        if matched != 1 {
+            errors.push(format!(
+                "{path}: {matched} `oneOf` subschemas are matched instead of one"
+            ));
+        }
+    }
+    if let Some(condition) = schema.get("if") {
+        let branch = if is_valid(condition) {
+            schema.get("then")
+        } else {
+            schema.get("else")
+        };
+        if let Some(branch) = branch {
+            validate(branch, instance, schemas, path, errors);
+        }
+    }
+}
+
+///
+/// Validates the program against the `ethdebug/format/program` schema.
+///
+fn validate_program(program: &serde_json::Value) {
+    let mut schemas = BTreeMap::new();
+    read_schemas(
+        Path::new(crate::common::TEST_ETHDEBUG_SCHEMAS_PATH),
+        &mut schemas,
+    );
+
+    let mut errors = Vec::new();
+    validate(
+        &serde_json::json!({ "$ref": PROGRAM_SCHEMA_ID }),
+        program,
+        &schemas,
+        "",
+        &mut errors,
+    );
+    assert!(errors.is_empty(), "Invalid ethdebug program: {errors:#?}");
+}
+
+#[test_case(false)]
+#[test_case(true)]
+fn program(via_ir: bool) {
+    let sources =
+        crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_STORAGE_PATH]);
+
+    let output = crate::common::build_solidity_standard_json(
+        sources,
+        era_compiler_common::Libraries::default(),
+        era_compiler_common::EVMMetadataHashType::IPFS,
+        BTreeSet::new(),
+        via_ir,
+        era_compiler_llvm_context::OptimizerSettings::cycles(),
+    )
+    .expect("Test failure");
+
+    let compilation = &output.ethdebug.as_ref().expect("Always exists")["compilation"];
+    assert_eq!(compilation["compiler"]["name"], serde_json::json!("solx"));
+    assert_eq!(
+        compilation["sources"][0]["path"],
+        serde_json::json!(crate::common::TEST_SOLIDITY_CONTRACT_STORAGE_PATH)
+    );
+
+    let evm = output
+        .contracts
+        .get(crate::common::TEST_SOLIDITY_CONTRACT_STORAGE_PATH)
+        .expect("Always exists")
+        .get("Storage")
+        .expect("Always exists")
+        .evm
+        .as_ref()
+        .expect("Always exists");
+    let deploy_program = evm
+        .bytecode
+        .as_ref()
+        .expect("Always exists")
+        .ethdebug
+        .as_ref()
+        .expect("Always exists");
+    let runtime_program = evm
+        .deployed_bytecode
+        .as_ref()
+        .expect("Always exists")
+        .ethdebug
+        .as_ref()
+        .expect("Always exists");
+    validate_program(deploy_program);
+    validate_program(runtime_program);
+    assert_eq!(
+        deploy_program["contract"]["name"],
+        serde_json::json!("Storage")
+    );
+    assert_eq!(deploy_program["environment"], serde_json::json!("create"));
+    assert_eq!(runtime_program["environment"], serde_json::json!("call"));
+
+    let contexts = runtime_program["context"]["gather"]
+        .as_array()
+        .expect("Program contexts are missing");
+    assert_eq!(
+        contexts[1],
+        serde_json::json!({ "remark": Program::REMARK_LOCAL_VARIABLES }),
+        "The remark on local variables is missing"
+    );
+    let variables = contexts[0]["variables"]
+        .as_array()
+        .expect("Storage variables are missing");
+    let identifiers = variables
+        .iter()
+        .map(|variable| variable["identifier"].as_str().expect("Always exists"))
+        .collect::<Vec<&str>>();
+    assert_eq!(identifiers, vec!["value", "small", "flag", "balances"]);
+    assert_eq!(
+        variables[2]["pointer"],
+        serde_json::json!({
+            "location": "storage",
+            "slot": 1,
+            "offset": 1,
+            "length": 1,
+        })
+    );
+}
+
+#[test]
+fn invoke() {
+    let instructions = [
+        "JUMP",
+        "CALL",
+        "DELEGATECALL",
+        "STATICCALL",
+        "CREATE",
+        "CREATE2",
+    ]
+    .into_iter()
+    .enumerate()
+    .map(|(offset, mnemonic)| {
+        let invoke = match mnemonic {
+            "JUMP" => Program::internal_invoke("fun_f_12", 2),
+            mnemonic => Program::external_invoke(mnemonic).expect("Always exists"),
+        };
+        serde_json::json!({
+            "offset": offset,
+            "operation": { "mnemonic": mnemonic },
+            "context": { "invoke": invoke },
+        })
+    })
+    .collect::<Vec<serde_json::Value>>();
+    let program = serde_json::json!({
+        "contract": { "name": "Test", "definition": { "source": { "id": 0 } } },
+        "environment": "call",
+        "instructions": instructions,
+    });
+    validate_program(&program);
+
+    assert!(Program::external_invoke("CALLCODE").is_none());
+}
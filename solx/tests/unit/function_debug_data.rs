@@ -0,0 +1,59 @@
+//!
+//! Unit tests for the function debug data.
+//!
+
+use std::collections::BTreeMap;
+
+use solx::build::contract::function_debug_data::entry::Entry;
+use solx::build::contract::function_debug_data::FunctionDebugData;
+use solx::build::contract::opcodes::Opcodes;
+
+#[test]
+fn ast_id() {
+    assert_eq!(Entry::ast_id("fun_transfer_42"), Some(42));
+    assert_eq!(Entry::ast_id("modifier_onlyOwner_7"), Some(7));
+    assert_eq!(Entry::ast_id("constructor_Test_3"), Some(3));
+    assert_eq!(Entry::ast_id("abi_decode_tuple_t_uint256"), None);
+    assert_eq!(Entry::ast_id("fun_transfer"), None);
+}
+
+#[test]
+fn from_assembly() {
+    let assembly = r#"
+	.text
+__entry:                                ; @__entry
+	PUSH1 128
+	PUSH1 64
+	MSTORE
+.BB0_1:
+	JUMP
+.Lfun_f_12:                             ; @fun_f_12
+	JUMPDEST
+	ADD
+	JUMP
+"#;
+
+    let mut functions = BTreeMap::new();
+    functions.insert("fun_f_12".to_owned(), Entry::new(Some(12), 2, 1));
+    functions.insert("inlined".to_owned(), Entry::new(None, 0, 0));
+
+    let function_debug_data = FunctionDebugData::from_assembly(assembly, functions);
+    assert_eq!(function_debug_data.entries.len(), 1);
+    let entry = function_debug_data
+        .entries
+        .get("fun_f_12")
+        .expect("Always exists");
+    assert_eq!(entry.instruction_index, Some(4));
+
+    let bytecode = hex::decode("6080604052565b0156").expect("Always valid");
+    let instruction_offsets = Opcodes::instruction_offsets(bytecode.as_slice(), 0);
+    assert_eq!(
+        entry.to_standard_json(instruction_offsets.as_slice()),
+        serde_json::json!({
+            "entryPoint": 6,
+            "id": 12,
+            "parameterSlots": 2,
+            "returnSlots": 1,
+        })
+    );
+}
@@ -0,0 +1,134 @@
+//!
+//! Unit tests for generated sources.
+//!
+
+use std::collections::BTreeSet;
+
+#[test]
+fn utility_code() {
+    let sources =
+        crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_IMMUTABLES_PATH]);
+
+    let output = crate::common::build_solidity_standard_json(
+        sources,
+        era_compiler_common::Libraries::default(),
+        era_compiler_common::EVMMetadataHashType::IPFS,
+        BTreeSet::new(),
+        false,
+        era_compiler_llvm_context::OptimizerSettings::cycles(),
+    )
+    .expect("Test failure");
+
+    let source_count = output.sources.len();
+    let generated_sources = output
+        .contracts
+        .get(crate::common::TEST_SOLIDITY_CONTRACT_IMMUTABLES_PATH)
+        .expect("Always exists")
+        .get("Immutables")
+        .expect("Always exists")
+        .evm
+        .as_ref()
+        .expect("Always exists")
+        .deployed_bytecode
+        .as_ref()
+        .expect("Always exists")
+        .generated_sources
+        .as_ref()
+        .expect("Always exists");
+
+    assert!(
+        !generated_sources.is_empty(),
+        "Generated sources are missing"
+    );
+    for generated_source in generated_sources.iter() {
+        let id = generated_source
+            .get("id")
+            .and_then(serde_json::Value::as_u64)
+            .expect("Always exists") as usize;
+        assert!(
+            id >= source_count,
+            "Generated source ID clashes with the original sources"
+        );
+        assert_eq!(
+            generated_source.get("language"),
+            Some(&serde_json::json!("Yul"))
+        );
+    }
+}
+
+#[test]
+fn utility_code_via_ir() {
+    let sources =
+        crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_IMMUTABLES_PATH]);
+
+    let output = crate::common::build_solidity_standard_json(
+        sources,
+        era_compiler_common::Libraries::default(),
+        era_compiler_common::EVMMetadataHashType::IPFS,
+        BTreeSet::new(),
+        true,
+        era_compiler_llvm_context::OptimizerSettings::cycles(),
+    )
+    .expect("Test failure");
+
+    let source_count = output.sources.len();
+    let generated_sources = output
+        .contracts
+        .get(crate::common::TEST_SOLIDITY_CONTRACT_IMMUTABLES_PATH)
+        .expect("Always exists")
+        .get("Immutables")
+        .expect("Always exists")
+        .evm
+        .as_ref()
+        .expect("Always exists")
+        .deployed_bytecode
+        .as_ref()
+        .expect("Always exists")
+        .generated_sources
+        .as_ref()
+        .expect("Always exists");
+
+    assert_eq!(
+        generated_sources.len(),
+        1,
+        "The utility functions must be collected into a single generated source"
+    );
+    let generated_source = generated_sources.first().expect("Always exists");
+    assert_eq!(
+        generated_source
+            .get("id")
+            .and_then(serde_json::Value::as_u64),
+        Some(source_count as u64),
+        "Generated source ID must follow the original sources"
+    );
+    assert_eq!(
+        generated_source.get("name"),
+        Some(&serde_json::json!("#utility.yul"))
+    );
+    assert_eq!(
+        generated_source.get("language"),
+        Some(&serde_json::json!("Yul"))
+    );
+
+    let contents = generated_source
+        .get("contents")
+        .and_then(serde_json::Value::as_str)
+        .expect("Always exists");
+    assert!(
+        contents.starts_with("function "),
+        "Generated source must start with a function definition"
+    );
+    assert!(
+        contents.contains("function abi_"),
+        "ABI utility functions are missing"
+    );
+    assert!(
+        !contents.contains("function fun_") && !contents.contains("function external_fun_"),
+        "User functions must not be included"
+    );
+    assert_eq!(
+        contents.matches('{').count(),
+        contents.matches('}').count(),
+        "Utility functions are truncated"
+    );
+}
@@ -0,0 +1,117 @@
+//!
+//! Unit tests for immutables.
+//!
+
+use std::collections::BTreeSet;
+use std::path::PathBuf;
+
+use solx_standard_json::output::contract::evm::bytecode::immutable_reference::ImmutableReference;
+use test_case::test_case;
+
+#[test_case(false)]
+#[test_case(true)]
+fn references(via_ir: bool) {
+    let sources =
+        crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_IMMUTABLES_PATH]);
+
+    let output = crate::common::build_solidity_standard_json(
+        sources,
+        era_compiler_common::Libraries::default(),
+        era_compiler_common::EVMMetadataHashType::IPFS,
+        BTreeSet::new(),
+        via_ir,
+        era_compiler_llvm_context::OptimizerSettings::cycles(),
+    )
+    .expect("Test failure");
+
+    let deployed_bytecode = output
+        .contracts
+        .get(crate::common::TEST_SOLIDITY_CONTRACT_IMMUTABLES_PATH)
+        .expect("Always exists")
+        .get("Immutables")
+        .expect("Always exists")
+        .evm
+        .as_ref()
+        .expect("Always exists")
+        .deployed_bytecode
+        .as_ref()
+        .expect("Always exists");
+    let bytecode = hex::decode(deployed_bytecode.object.as_deref().expect("Always exists"))
+        .expect("Always valid");
+    let immutable_references = deployed_bytecode
+        .immutable_references
+        .as_ref()
+        .expect("Always exists");
+
+    assert_eq!(immutable_references.len(), 2, "Immutables are missing");
+    for references in immutable_references.values() {
+        assert!(!references.is_empty(), "Immutable references are missing");
+        for reference in references.iter() {
+            check_reference(bytecode.as_slice(), reference);
+        }
+    }
+}
+
+#[test]
+fn references_llvm_ir_deploy_code() {
+    let input = solx_standard_json::Input::try_from(Some(
+        PathBuf::from(crate::common::TEST_LLVM_IR_STANDARD_JSON_IMMUTABLES_PATH).as_path(),
+    ))
+    .expect("Standard JSON reading error");
+    let output = crate::common::build_llvm_ir_standard_json(input).expect("Test failure");
+
+    let contract = output
+        .contracts
+        .get("tests/data/contracts/llvm_ir/Immutables.ll")
+        .expect("Always exists")
+        .values()
+        .next()
+        .expect("Always exists");
+    let deployed_bytecode = contract
+        .evm
+        .as_ref()
+        .expect("Always exists")
+        .deployed_bytecode
+        .as_ref()
+        .expect("Always exists");
+    let bytecode = hex::decode(deployed_bytecode.object.as_deref().expect("Always exists"))
+        .expect("Always valid");
+    let immutable_references = deployed_bytecode
+        .immutable_references
+        .as_ref()
+        .expect("Always exists");
+
+    let references = immutable_references
+        .get("value")
+        .expect("The immutable is missing");
+    assert!(!references.is_empty(), "Immutable references are missing");
+    for reference in references.iter() {
+        check_reference(bytecode.as_slice(), reference);
+    }
+}
+
+///
+/// Checks that the immutable `reference` points at the zeroed argument of a `PUSH32` instruction.
+///
+fn check_reference(bytecode: &[u8], reference: &ImmutableReference) {
+    /// The `PUSH32` instruction opcode.
+    const PUSH32: u8 = 0x7f;
+
+    let start = reference.start as usize;
+    assert_eq!(reference.length, era_compiler_common::BYTE_LENGTH_FIELD);
+    assert!(
+        start + reference.length <= bytecode.len(),
+        "Immutable reference is out of bounds"
+    );
+    assert_eq!(
+        start.checked_sub(1).map(|offset| bytecode[offset]),
+        Some(PUSH32),
+        "Immutable reference at {start} is not a `PUSH32` argument"
+    );
+    assert!(
+        bytecode[start..start + reference.length]
+            .iter()
+            .all(|byte| *byte == 0),
+        "Immutable reference at {start} is not zeroed"
+    );
+}
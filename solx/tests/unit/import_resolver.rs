@@ -0,0 +1,111 @@
+//!
+//! Unit tests for the import resolver.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+
+use solx_standard_json::CollectableError;
+
+///
+/// Compiles the caller project with only the main source in the input, so the callable one
+/// must be requested from `import_resolver`.
+///
+fn compile(
+    import_resolver: &dyn solx_solc::ImportResolver,
+) -> anyhow::Result<solx_standard_json::Output> {
+    let sources =
+        crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_CALLER_MAIN_PATH])
+            .into_iter()
+            .map(|(path, source)| (path, solx_standard_json::InputSource::from(source)))
+            .collect::<BTreeMap<String, solx_standard_json::InputSource>>();
+
+    let mut selectors = BTreeSet::new();
+    selectors.insert(solx_standard_json::InputSelector::ABI);
+    let mut input = solx_standard_json::Input::try_from_solidity_sources(
+        sources,
+        era_compiler_common::Libraries::default(),
+        BTreeSet::new(),
+        solx_standard_json::InputOptimizer::default(),
+        None,
+        false,
+        &solx_standard_json::InputSelection::new(selectors),
+        solx_standard_json::InputMetadata::default(),
+        vec![],
+    )?;
+
+    let _lock = crate::common::UNIT_TEST_LOCK.lock();
+    solx_solc::Compiler::default().standard_json(
+        &mut input,
+        &mut vec![],
+        false,
+        None,
+        vec![],
+        None,
+        Some(import_resolver),
+    )
+}
+
+#[test]
+fn hash_map() {
+    let mut import_resolver = HashMap::new();
+    import_resolver.extend(crate::common::read_sources(&[
+        crate::common::TEST_SOLIDITY_CONTRACT_CALLER_CALLABLE_PATH,
+    ]));
+
+    let output = compile(&import_resolver).expect("Test failure");
+    output.check_errors().expect("Test failure");
+
+    assert!(
+        output
+            .sources
+            .contains_key(crate::common::TEST_SOLIDITY_CONTRACT_CALLER_CALLABLE_PATH),
+        "The imported source is missing"
+    );
+    assert!(
+        output
+            .contracts
+            .get(crate::common::TEST_SOLIDITY_CONTRACT_CALLER_CALLABLE_PATH)
+            .is_some_and(|contracts| contracts.contains_key("Callable")),
+        "The imported contract is missing"
+    );
+}
+
+#[test]
+fn not_found() {
+    let import_resolver: HashMap<String, String> = HashMap::new();
+
+    let output = compile(&import_resolver).expect("Test failure");
+
+    assert!(
+        output.errors.iter().any(|error| error
+            .message
+            .contains(crate::common::TEST_SOLIDITY_CONTRACT_CALLER_CALLABLE_PATH)),
+        "The missing import is not reported"
+    );
+}
+
+#[test]
+fn panic() {
+    ///
+    /// The import resolver that panics on every request.
+    ///
+    struct PanickingResolver;
+
+    impl solx_solc::ImportResolver for PanickingResolver {
+        fn resolve(&self, path: &str) -> anyhow::Result<String> {
+            panic!("Unexpected request for `{path}`");
+        }
+    }
+
+    let output = compile(&PanickingResolver).expect("Test failure");
+
+    assert!(
+        output
+            .errors
+            .iter()
+            .any(|error| error.message.contains("Import resolver panicked")),
+        "The resolver panic is not reported"
+    );
+}
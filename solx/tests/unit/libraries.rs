@@ -2,6 +2,7 @@
 //! Unit tests for libraries.
 //!
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
 use test_case::test_case;
@@ -88,3 +89,100 @@ fn specified(via_ir: bool) {
         "The list of unlinked libraries must be empty"
     );
 }
+
+#[test_case(false)]
+#[test_case(true)]
+fn link_placeholders(via_ir: bool) {
+    let sources =
+        crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH]);
+
+    let output = crate::common::build_solidity_standard_json(
+        sources,
+        era_compiler_common::Libraries::default(),
+        era_compiler_common::EVMMetadataHashType::IPFS,
+        BTreeSet::new(),
+        via_ir,
+        era_compiler_llvm_context::OptimizerSettings::cycles(),
+    )
+    .expect("Test failure");
+    let bytecode_hex = output
+        .contracts
+        .get(crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH)
+        .expect("Always exists")
+        .get("SimpleContract")
+        .expect("Always exists")
+        .evm
+        .as_ref()
+        .expect("Always exists")
+        .deployed_bytecode
+        .as_ref()
+        .expect("Always exists")
+        .object
+        .as_deref()
+        .expect("Always exists");
+
+    let (unresolved_bytecode_hex, unresolved_placeholders) =
+        solx::EVMContractObject::link_placeholders(bytecode_hex, &BTreeMap::new())
+            .expect("Test failure");
+    assert_eq!(unresolved_bytecode_hex, bytecode_hex);
+    assert_eq!(unresolved_placeholders.len(), 1);
+
+    let mut libraries = era_compiler_common::Libraries::default();
+    libraries
+        .as_inner_mut()
+        .entry(crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH.to_string())
+        .or_default()
+        .entry("SimpleLibrary".to_string())
+        .or_insert("0x00000000000000000000000000000000deadbeef".to_string());
+    let linker_symbols = libraries.as_linker_symbols().expect("Always valid");
+
+    let (linked_bytecode_hex, unresolved_placeholders) =
+        solx::EVMContractObject::link_placeholders(bytecode_hex, &linker_symbols)
+            .expect("Test failure");
+    assert!(unresolved_placeholders.is_empty());
+    assert!(!linked_bytecode_hex.contains(solx::EVMContractObject::LIBRARY_PLACEHOLDER_PREFIX));
+    assert!(linked_bytecode_hex.contains("7300000000000000000000000000000000deadbeef"));
+}
+
+#[test]
+fn link_placeholders_not_push20() {
+    let placeholder = solx::EVMContractObject::library_placeholder("Test.sol:Test");
+    let bytecode_hex = format!("60{placeholder}00");
+
+    let result =
+        solx::EVMContractObject::link_placeholders(bytecode_hex.as_str(), &BTreeMap::new());
+    assert!(result
+        .expect_err("Test failure")
+        .to_string()
+        .contains("is not a `PUSH20` immediate"));
+}
+
+#[test]
+fn link_placeholders_prefix_and_line_breaks() {
+    let placeholder = solx::EVMContractObject::library_placeholder("Test.sol:Test");
+    let (placeholder_start, placeholder_end) = placeholder.split_at(placeholder.len() / 2);
+    let bytecode_hex = format!("0x73{placeholder_start}\n{placeholder_end}00\n");
+
+    let mut address = [0u8; era_compiler_common::BYTE_LENGTH_ETH_ADDRESS];
+    address[era_compiler_common::BYTE_LENGTH_ETH_ADDRESS - 1] = 0xef;
+    let mut linker_symbols = BTreeMap::new();
+    linker_symbols.insert("Test.sol:Test".to_owned(), address);
+
+    let (linked_bytecode_hex, unresolved_placeholders) =
+        solx::EVMContractObject::link_placeholders(bytecode_hex.as_str(), &linker_symbols)
+            .expect("Test failure");
+    assert!(unresolved_placeholders.is_empty());
+    assert_eq!(
+        linked_bytecode_hex,
+        format!("0x73{}00", hex::encode(address))
+    );
+}
+
+#[test]
+fn link_placeholders_raw_bytes() {
+    let result = solx::EVMContractObject::link_placeholders("\u{60}\u{01}\u{00}", &BTreeMap::new());
+    assert!(result
+        .expect_err("Test failure")
+        .to_string()
+        .contains("Raw binary bytecode is not supported"));
+}
@@ -2,8 +2,17 @@
 //! The unit tests entry module.
 //!
 
+mod ethdebug;
+mod function_debug_data;
+mod generated_sources;
+mod immutables;
+mod import_resolver;
 mod ir_artifacts;
 mod libraries;
+mod opcodes;
 mod optimizer;
+mod process_failure;
 mod remappings;
+mod source_location;
+mod source_map;
 mod standard_json;
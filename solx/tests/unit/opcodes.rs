@@ -0,0 +1,77 @@
+//!
+//! Unit tests for the EVM bytecode disassembler.
+//!
+
+use std::collections::BTreeMap;
+
+use solx::build::contract::opcodes::Opcodes;
+
+#[test]
+fn push_immediates() {
+    let bytecode = hex::decode("6080604052348015600e575f5ffd5b").expect("Always valid");
+
+    let opcodes = Opcodes::disassemble(bytecode.as_slice(), 0, &BTreeMap::new());
+    assert_eq!(
+        opcodes,
+        "PUSH1 0x80 PUSH1 0x40 MSTORE CALLVALUE DUP1 ISZERO PUSH1 0xE JUMPI PUSH0 PUSH0 REVERT JUMPDEST "
+    );
+}
+
+#[test]
+fn truncated_push_and_unknown_opcode() {
+    let bytecode = hex::decode("0c61ff").expect("Always valid");
+
+    let opcodes = Opcodes::disassemble(bytecode.as_slice(), 0, &BTreeMap::new());
+    assert_eq!(opcodes, "0xC PUSH2 0xFF00 ");
+}
+
+#[test]
+fn library_placeholder() {
+    let mut bytecode = vec![0x73];
+    bytecode.extend_from_slice(&[0u8; era_compiler_common::BYTE_LENGTH_ETH_ADDRESS]);
+    bytecode.push(0x56);
+
+    let placeholder =
+        solx::build::contract::object::Object::library_placeholder("tests/Library.sol:Library");
+    let mut placeholders = BTreeMap::new();
+    placeholders.insert(1, placeholder.clone());
+
+    let opcodes = Opcodes::disassemble(bytecode.as_slice(), 0, &placeholders);
+    assert_eq!(opcodes, format!("PUSH20 {placeholder} JUMP "));
+}
+
+#[test]
+fn immutable() {
+    let mut bytecode = vec![0x7f];
+    bytecode.extend_from_slice(&[0u8; era_compiler_common::BYTE_LENGTH_FIELD]);
+    bytecode.push(0x00);
+
+    let opcodes = Opcodes::disassemble(bytecode.as_slice(), 0, &BTreeMap::new());
+    assert_eq!(opcodes, "PUSH32 0x0 STOP ");
+}
+
+#[test]
+fn cbor_metadata_is_skipped() {
+    let mut bytecode = hex::decode("6001600055").expect("Always valid");
+    let metadata = hex::decode("a164736f6c7843000100").expect("Always valid");
+    bytecode.extend_from_slice(metadata.as_slice());
+    bytecode.extend_from_slice((metadata.len() as u16).to_be_bytes().as_slice());
+
+    let metadata_length = Opcodes::cbor_metadata_length(bytecode.as_slice());
+    assert_eq!(metadata_length, metadata.len() + Opcodes::CBOR_LENGTH_SIZE);
+    let opcodes = Opcodes::disassemble(bytecode.as_slice(), metadata_length, &BTreeMap::new());
+    assert_eq!(opcodes, "PUSH1 0x1 PUSH1 0x0 SSTORE ");
+}
+
+#[test]
+fn code_resembling_cbor_metadata_is_kept() {
+    // Ends with a map header and a plausible length suffix, but no metadata has been appended.
+    let bytecode = hex::decode("60a16000555b0005").expect("Always valid");
+
+    let opcodes = Opcodes::disassemble(bytecode.as_slice(), 0, &BTreeMap::new());
+    assert_eq!(opcodes, "PUSH1 0xA1 PUSH1 0x0 SSTORE JUMPDEST STOP SDIV ");
+    assert_eq!(
+        Opcodes::instruction_offsets(bytecode.as_slice(), 0),
+        vec![0, 2, 4, 5, 6, 7]
+    );
+}
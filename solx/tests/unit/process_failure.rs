@@ -0,0 +1,68 @@
+//!
+//! Unit tests for the recursive process failure classification.
+//!
+
+#![cfg(unix)]
+
+use std::os::unix::process::ExitStatusExt;
+use std::process::ExitStatus;
+
+use solx::process::failure::Failure;
+
+#[test]
+fn llvm_assertion() {
+    let stderr = r#"
+solx: llvm/lib/CodeGen/MachineInstr.cpp:42: Assertion `isReg() && "Wrong operand type"' failed.
+PLEASE submit a bug report
+"#;
+
+    assert_eq!(
+        Failure::classify(ExitStatus::from_raw(6), stderr),
+        Failure::LLVMAssertion(
+            r#"solx: llvm/lib/CodeGen/MachineInstr.cpp:42: Assertion `isReg() && "Wrong operand type"' failed."#
+                .to_owned()
+        )
+    );
+}
+
+#[test]
+fn llvm_fatal_error() {
+    assert_eq!(
+        Failure::classify(
+            ExitStatus::from_raw(1 << 8),
+            "LLVM ERROR: Cannot select: intrinsic\n"
+        ),
+        Failure::LLVMAssertion("LLVM ERROR: Cannot select: intrinsic".to_owned())
+    );
+}
+
+#[test]
+fn out_of_memory() {
+    assert_eq!(
+        Failure::classify(
+            ExitStatus::from_raw(6),
+            "memory allocation of 1073741824 bytes failed\n"
+        ),
+        Failure::OutOfMemory
+    );
+    assert_eq!(
+        Failure::classify(ExitStatus::from_raw(Failure::SIGNAL_KILL), ""),
+        Failure::OutOfMemory
+    );
+}
+
+#[test]
+fn signal() {
+    assert_eq!(
+        Failure::classify(ExitStatus::from_raw(11), ""),
+        Failure::Signal(11)
+    );
+}
+
+#[test]
+fn exit_code() {
+    assert_eq!(
+        Failure::classify(ExitStatus::from_raw(3 << 8), "error"),
+        Failure::ExitCode(Some(3))
+    );
+}
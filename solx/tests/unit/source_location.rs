@@ -0,0 +1,37 @@
+//!
+//! Unit tests for the original source locations of Yul translation errors.
+//!
+
+use std::collections::BTreeSet;
+
+#[test]
+fn yul_error() {
+    let sources =
+        crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_SELF_DESTRUCT_PATH]);
+
+    let error = crate::common::build_solidity_standard_json(
+        sources,
+        era_compiler_common::Libraries::default(),
+        era_compiler_common::EVMMetadataHashType::IPFS,
+        BTreeSet::new(),
+        true,
+        era_compiler_llvm_context::OptimizerSettings::cycles(),
+    )
+    .expect_err("Test failure")
+    .to_string();
+
+    assert!(
+        error.contains("The `SELFDESTRUCT` instruction is not supported"),
+        "Unexpected error: {error}"
+    );
+    assert!(
+        error.contains(
+            format!(
+                "{}:7:",
+                crate::common::TEST_SOLIDITY_CONTRACT_SELF_DESTRUCT_PATH
+            )
+            .as_str()
+        ),
+        "The Solidity source location is missing: {error}"
+    );
+}
@@ -0,0 +1,158 @@
+//!
+//! Unit tests for the EVM source map.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use solx_yul::yul::lexer::token::annotation::source::Source;
+
+use solx::build::contract::source_map::entry::Entry;
+use solx::build::contract::source_map::jump::Jump;
+use solx::build::contract::source_map::SourceMap;
+
+#[test]
+fn compression() {
+    let source_map = SourceMap {
+        entries: vec![
+            Entry::new(0, 10, 0, Jump::Regular, 0),
+            Entry::new(0, 10, 0, Jump::Regular, 0),
+            Entry::new(5, 10, 0, Jump::Regular, 0),
+            Entry::new(5, 3, 0, Jump::Into, 0),
+            Entry::unknown(),
+        ],
+    };
+
+    assert_eq!(source_map.to_string(), "0:10:0:-:0;;5;:3::i;-1:-1:-1:-");
+}
+
+#[test]
+fn from_assembly() {
+    let assembly = r#"
+	.text
+f:                                      ; @f
+	JUMPDEST
+	.loc	1 2 3 prologue_end
+	PUSH1 128
+	PUSH1 64
+	.loc	1 4 5
+	MSTORE
+	; comment
+	STOP
+"#;
+
+    let source_map = SourceMap::from_assembly(assembly, |line, column| {
+        Some(Entry::new(
+            line as isize,
+            column as isize,
+            0,
+            Jump::Regular,
+            0,
+        ))
+    });
+    assert_eq!(source_map.entries.len(), 5);
+    assert_eq!(source_map.to_string(), "-1:-1:-1:-:0;2:3:0;;4:5;");
+}
+
+#[test]
+fn from_yul_assembly() {
+    let assembly = r#"
+	.text
+f:                                      ; @f
+	JUMPDEST
+	.loc	1 1 0 prologue_end
+	PUSH1 128
+	.loc	1 2 0
+	PUSH1 64
+	.loc	1 3 0
+	PUSH1 0
+	.loc	1 100 0
+	MSTORE
+"#;
+
+    let ranges = BTreeMap::from([
+        (0, Source::new(0, 57, 126)),
+        (1, Source::new(0, 57, 60)),
+        (2, Source::new(1, 10, 20)),
+    ]);
+    let source_map = SourceMap::from_yul_assembly(assembly, &ranges);
+    assert_eq!(
+        source_map.to_string(),
+        "-1:-1:-1:-:0;57:69:0;:3;10:10:1;-1:-1:-1"
+    );
+}
+
+#[test]
+fn legacy_assembly_locations() {
+    let sources = crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_PATH]);
+
+    let output = crate::common::build_solidity_standard_json(
+        sources,
+        era_compiler_common::Libraries::default(),
+        era_compiler_common::EVMMetadataHashType::IPFS,
+        BTreeSet::new(),
+        false,
+        era_compiler_llvm_context::OptimizerSettings::cycles(),
+    )
+    .expect("Test failure");
+
+    for (name, contract) in output.contracts.values().flat_map(|file| file.iter()) {
+        let evm = contract.evm.as_ref().expect("Always exists");
+        let deploy_code = evm.legacy_assembly.as_ref().expect("Always exists");
+        let runtime_code = deploy_code.runtime_code().expect("Always exists");
+
+        for (code, bytecode) in [
+            (deploy_code, evm.bytecode.as_ref()),
+            (runtime_code, evm.deployed_bytecode.as_ref()),
+        ] {
+            let locations: BTreeSet<(isize, isize, isize)> = code
+                .code
+                .as_deref()
+                .expect("Always exists")
+                .iter()
+                .map(Entry::from)
+                .map(|entry| (entry.start, entry.length, entry.source_index))
+                .collect();
+            let source_map = bytecode
+                .and_then(|bytecode| bytecode.source_map.as_deref())
+                .expect("Always exists")
+                .parse::<SourceMap>()
+                .expect("Always valid");
+
+            let known_entries = source_map
+                .entries
+                .iter()
+                .filter(|entry| entry.start >= 0)
+                .collect::<Vec<_>>();
+            assert!(
+                !known_entries.is_empty(),
+                "Contract `{name}` source map has no known locations"
+            );
+            for entry in known_entries.into_iter() {
+                assert!(
+                    locations.contains(&(entry.start, entry.length, entry.source_index)),
+                    "Contract `{name}` source map entry {entry:?} is not a legacy assembly location"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn parse() {
+    let source_map = "0:10:0:-:0;;5;:3::i;-1:-1:-1:-"
+        .parse::<SourceMap>()
+        .expect("Always valid");
+
+    assert_eq!(
+        source_map.entries,
+        vec![
+            Entry::new(0, 10, 0, Jump::Regular, 0),
+            Entry::new(0, 10, 0, Jump::Regular, 0),
+            Entry::new(5, 10, 0, Jump::Regular, 0),
+            Entry::new(5, 3, 0, Jump::Into, 0),
+            Entry::unknown(),
+        ]
+    );
+    assert_eq!(source_map.to_string(), "0:10:0:-:0;;5;:3::i;-1:-1:-1:-");
+}